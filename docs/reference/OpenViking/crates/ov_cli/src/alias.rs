@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+
+/// Expand a user-defined alias in the command position, after any leading global flags.
+///
+/// `args` includes the program name; `takes_value` tells which flags (as written, e.g.
+/// `--output` or `-o`) consume the next argument. Aliases may expand to other aliases; a
+/// chain that revisits an alias is reported as a loop. Names of built-in commands are
+/// never expanded, so an alias cannot shadow them.
+pub fn expand_aliases(
+    args: Vec<String>,
+    aliases: &HashMap<String, String>,
+    is_builtin: impl Fn(&str) -> bool,
+    takes_value: impl Fn(&str) -> bool,
+) -> Result<Vec<String>> {
+    let mut args = args;
+    let mut chain: Vec<String> = Vec::new();
+    let Some(at) = command_index(&args, takes_value) else {
+        return Ok(args);
+    };
+
+    while let Some(name) = args.get(at).cloned() {
+        if is_builtin(&name) {
+            break;
+        }
+        let Some(expansion) = aliases.get(&name) else {
+            break;
+        };
+        if chain.contains(&name) {
+            chain.push(name);
+            return Err(Error::Config(format!(
+                "Alias loop detected: {}",
+                chain.join(" -> ")
+            )));
+        }
+        chain.push(name);
+
+        let expanded = split_args(expansion)?;
+        if expanded.is_empty() {
+            return Err(Error::Config(format!("Alias '{}' is empty", chain.last().unwrap())));
+        }
+        args.splice(at..at + 1, expanded);
+    }
+
+    Ok(args)
+}
+
+/// Index of the first argument that is not a flag or a flag's value, i.e. the command
+pub fn command_index(args: &[String], takes_value: impl Fn(&str) -> bool) -> Option<usize> {
+    let mut i = 1;
+    while let Some(arg) = args.get(i) {
+        if arg == "--" {
+            return None;
+        }
+        if let Some(long) = arg.strip_prefix("--") {
+            // `--flag=value` carries its value; `--flag value` uses the next argument
+            if !long.contains('=') && takes_value(arg) {
+                i += 1;
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // In a cluster such as `-co json` the first flag taking a value ends it
+            for (pos, c) in shorts.char_indices() {
+                if takes_value(&format!("-{}", c)) {
+                    if pos + c.len_utf8() == shorts.len() {
+                        i += 1;
+                    }
+                    break;
+                }
+            }
+        } else {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split an alias string into arguments, honoring single and double quotes
+fn split_args(s: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+
+    for c in s.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            None if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err(Error::Config(format!("Unterminated quote in alias: {}", s)));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn aliases(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_expands_alias_and_keeps_trailing_args() {
+        let map = aliases(&[("recent", "ls viking://logs --sort mtime")]);
+        let expanded = expand_aliases(args(&["ov", "recent", "-o", "json"]), &map, |_| false, |_| false).unwrap();
+        assert_eq!(
+            expanded,
+            args(&["ov", "ls", "viking://logs", "--sort", "mtime", "-o", "json"])
+        );
+    }
+
+    #[test]
+    fn test_nested_aliases() {
+        let map = aliases(&[("r", "recent"), ("recent", "ls viking://logs")]);
+        let expanded = expand_aliases(args(&["ov", "r"]), &map, |_| false, |_| false).unwrap();
+        assert_eq!(expanded, args(&["ov", "ls", "viking://logs"]));
+    }
+
+    #[test]
+    fn test_alias_loop_is_an_error() {
+        let map = aliases(&[("a", "b"), ("b", "a --x")]);
+        let err = expand_aliases(args(&["ov", "a"]), &map, |_| false, |_| false).unwrap_err();
+        assert!(err.to_string().contains("a -> b -> a"));
+    }
+
+    #[test]
+    fn test_builtin_commands_are_not_shadowed() {
+        let map = aliases(&[("ls", "rm -r viking://")]);
+        let expanded = expand_aliases(args(&["ov", "ls"]), &map, |name| name == "ls", |_| false).unwrap();
+        assert_eq!(expanded, args(&["ov", "ls"]));
+    }
+
+    #[test]
+    fn test_alias_after_global_flags() {
+        let map = aliases(&[("recent", "ls viking://logs")]);
+        let takes_value = |flag: &str| matches!(flag, "-o" | "--output");
+        for (given, expected) in [
+            (&["ov", "-o", "json", "recent"][..], &["ov", "-o", "json", "ls", "viking://logs"][..]),
+            (&["ov", "--output=json", "recent"], &["ov", "--output=json", "ls", "viking://logs"]),
+            (&["ov", "--compact", "-co", "json", "recent"], &["ov", "--compact", "-co", "json", "ls", "viking://logs"]),
+        ] {
+            assert_eq!(expand_aliases(args(given), &map, |_| false, takes_value).unwrap(), args(expected));
+        }
+        // The value of a flag is not a command
+        let expanded = expand_aliases(args(&["ov", "-o", "recent"]), &map, |_| false, takes_value).unwrap();
+        assert_eq!(expanded, args(&["ov", "-o", "recent"]));
+    }
+
+    #[test]
+    fn test_split_args_quotes() {
+        assert_eq!(
+            split_args(r#"find "what is openviking" -u 'viking://a b'"#).unwrap(),
+            args(&["find", "what is openviking", "-u", "viking://a b"])
+        );
+        assert!(split_args("find \"unterminated").is_err());
+    }
+}
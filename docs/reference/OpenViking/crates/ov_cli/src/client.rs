@@ -1,8 +1,289 @@
+use bytes::Bytes;
 use reqwest::{Client as ReqwestClient, StatusCode};
 use serde::de::DeserializeOwned;
+use futures_util::StreamExt;
 use serde_json::Value;
+use std::io::Write;
+use std::time::Duration;
 
+use crate::config::AuthScheme;
 use crate::error::{Error, Result};
+use crate::io::{ResultStream, Throttle};
+
+/// Retry policy for transient failures: network errors, HTTP 429 and 5xx responses
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Total tries per request, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each one after
+    pub initial_delay: Duration,
+    /// Upper bound for any single delay
+    pub max_delay: Duration,
+    /// Randomize each delay between half and all of its nominal value
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_RETRIES + 1,
+            initial_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Backoff before retry number `retry` (1-based)
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.initial_delay.saturating_mul(factor).min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        // Cheap entropy is enough to keep concurrent clients from retrying in lockstep
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        delay.mul_f64(0.5 + (nanos % 1000) as f64 / 2000.0)
+    }
+}
+
+/// Default per-request timeout
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+/// Time allowed to establish a connection, so a dead host fails fast even when the
+/// overall timeout is long or disabled
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP timeouts by request category; `None` waits indefinitely
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeoutConfig {
+    /// Time allowed to establish a connection
+    pub connect_timeout: Option<Duration>,
+    /// Whole-request limit for GET, HEAD and DELETE
+    pub read_timeout: Option<Duration>,
+    /// Whole-request limit for POST, PUT and PATCH, which may carry large uploads
+    pub write_timeout: Option<Duration>,
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Some(CONNECT_TIMEOUT),
+            read_timeout: Some(DEFAULT_TIMEOUT),
+            write_timeout: Some(DEFAULT_TIMEOUT),
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /// Timeout for a request with this method
+    pub fn for_method(&self, method: &reqwest::Method) -> Option<Duration> {
+        match *method {
+            reqwest::Method::POST | reqwest::Method::PUT | reqwest::Method::PATCH => self.write_timeout,
+            _ => self.read_timeout,
+        }
+    }
+}
+
+/// Validate a timeout in seconds; fractions allow sub-second timeouts and 0 means none
+pub fn timeout_from_secs(secs: f64) -> Result<Option<Duration>> {
+    if !secs.is_finite() || secs < 0.0 {
+        return Err(Error::Config(format!(
+            "Timeout must be a non-negative number of seconds (0 for none), got {}",
+            secs
+        )));
+    }
+    if secs == 0.0 {
+        return Ok(None);
+    }
+    Duration::try_from_secs_f64(secs)
+        .map(Some)
+        .map_err(|e| Error::Config(format!("Invalid timeout {}: {}", secs, e)))
+}
+
+/// A mutating request described without sending it, so `--dry-run` can preview it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRequest {
+    pub method: reqwest::Method,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub body: Option<Value>,
+}
+
+impl PlannedRequest {
+    /// `{dry_run, method, path, query, body}`, with `query`/`body` null when there are none
+    pub fn preview(&self) -> Value {
+        let query = (!self.query.is_empty()).then(|| {
+            self.query
+                .iter()
+                .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                .collect::<serde_json::Map<_, _>>()
+        });
+        serde_json::json!({
+            "dry_run": true,
+            "method": self.method.as_str(),
+            "path": self.path,
+            "query": query,
+            "body": self.body,
+        })
+    }
+}
+
+/// Value returned for a request skipped by `--dry-run`: whatever `T` deserializes from
+/// `null` (e.g. `Value::Null` or `None`), or `Error::DryRun` if it has no such value
+fn dry_run_value<T: DeserializeOwned>() -> Result<T> {
+    serde_json::from_value(Value::Null).map_err(|_| Error::DryRun)
+}
+
+/// Unwrap a response body: HTTP and API errors become `Error::Api`, otherwise the
+/// `result` field (or the whole body when there is none) is deserialized
+fn parse_envelope<T: DeserializeOwned>(status: StatusCode, body: &[u8]) -> Result<T> {
+    let json: Value = serde_json::from_slice(body)
+        .map_err(|e| Error::Network(format!("Failed to parse JSON response: {}", e)))?;
+
+    // Handle HTTP errors
+    if !status.is_success() {
+        let error_msg = json
+            .get("error")
+            .and_then(|e| {
+                let message = e.get("message")?.as_str()?;
+                Some(match e.get("code").and_then(|c| c.as_str()) {
+                    Some(code) => format!("[{}] {}", code, message),
+                    None => message.to_string(),
+                })
+            })
+            .or_else(|| json.get("detail").and_then(|d| d.as_str()).map(|s| s.to_string()))
+            .unwrap_or_else(|| format!("HTTP error {}", status));
+        // Tag bare 404s so they map to the not-found exit code
+        if status == StatusCode::NOT_FOUND && !error_msg.starts_with('[') {
+            return Err(Error::Api(format!("[NOT_FOUND] {}", error_msg)));
+        }
+        return Err(Error::Api(error_msg));
+    }
+
+    // Handle API errors (status == success but body has error)
+    if let Some(error) = json.get("error") {
+        if !error.is_null() {
+            let code = error
+                .get("code")
+                .and_then(|c| c.as_str())
+                .unwrap_or("UNKNOWN");
+            let message = error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("Unknown error");
+            return Err(Error::Api(format!("[{}] {}", code, message)));
+        }
+    }
+
+    // Extract result from wrapped response or use the whole response
+    let result = if let Some(result) = json.get("result") {
+        result.clone()
+    } else {
+        json
+    };
+
+    serde_json::from_value(result)
+        .map_err(|e| Error::Parse(format!("Failed to deserialize response: {}", e)))
+}
+
+/// `--dry-run` rendering of a request
+fn describe_request(request: &reqwest::Request) -> String {
+    let mut text = format!("{} {}", request.method(), request.url());
+    for (name, value) in request.headers() {
+        let value = if name == "x-api-key" || name == reqwest::header::AUTHORIZATION {
+            "[redacted]".to_string()
+        } else {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        };
+        text.push_str(&format!("\n{}: {}", name, value));
+    }
+    if let Some(body) = request.body().and_then(|b| b.as_bytes()) {
+        text.push_str(&format!("\n\n{}", redact_body(body)));
+    }
+    text
+}
+
+/// Body text for `--dry-run`/`--debug`, with any JSON `api_key` (or `apiKey`/`x-api-key`)
+/// field redacted at any depth
+fn redact_body(body: &[u8]) -> String {
+    fn redact(value: &mut Value) {
+        match value {
+            Value::Object(obj) => {
+                for (key, value) in obj.iter_mut() {
+                    if ["api_key", "apikey", "x-api-key"].contains(&key.to_ascii_lowercase().as_str()) {
+                        *value = Value::String("[redacted]".to_string());
+                    } else {
+                        redact(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(redact),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Request timeouts are applied per request by method, so only the connect timeout
+/// lives on the client
+fn build_http(connect_timeout: Option<Duration>) -> ReqwestClient {
+    let builder = ReqwestClient::builder();
+    let builder = match connect_timeout {
+        Some(timeout) => builder.connect_timeout(timeout),
+        None => builder,
+    };
+    builder
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// Retries after the first attempt unless `--retries` says otherwise
+pub const DEFAULT_RETRIES: u32 = 3;
+
+/// Most items `get_paginated` collects before it stops following cursors
+pub const MAX_PAGINATED_ITEMS: usize = 10_000;
+/// Default for `HttpClient::with_max_pages`
+pub const DEFAULT_MAX_PAGES: usize = 100;
+
+/// Items collected by `get_paginated`
+#[derive(Debug)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    /// A cap stopped collection while the server still had more
+    pub truncated: bool,
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// One page of an `ls` listing: skip `offset` entries and return at most `limit`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Page {
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl Page {
+    /// Page `number` (1-based) of `size` entries each
+    pub fn numbered(number: u64, size: u64) -> Self {
+        Self {
+            offset: number.saturating_sub(1) * size,
+            limit: size,
+        }
+    }
+}
 
 /// High-level HTTP client for OpenViking API
 #[derive(Clone)]
@@ -10,21 +291,102 @@ pub struct HttpClient {
     http: ReqwestClient,
     base_url: String,
     api_key: Option<String>,
+    auth_scheme: AuthScheme,
+    user: Option<(String, String)>,
+    rate_limit: Option<u64>,
+    retry: RetryConfig,
+    timeouts: TimeoutConfig,
+    max_pages: usize,
+    dry_run: bool,
+    debug: bool,
 }
 
 impl HttpClient {
     /// Create a new HTTP client
     pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Self {
-        let http = ReqwestClient::builder()
-            .timeout(std::time::Duration::from_secs(60))
-            .build()
-            .expect("Failed to build HTTP client");
-
         Self {
-            http,
+            http: build_http(Some(CONNECT_TIMEOUT)),
+            timeouts: TimeoutConfig::default(),
             base_url: base_url.into().trim_end_matches('/').to_string(),
             api_key,
+            auth_scheme: AuthScheme::default(),
+            user: None,
+            rate_limit: None,
+            retry: RetryConfig::default(),
+            max_pages: DEFAULT_MAX_PAGES,
+            dry_run: false,
+            debug: false,
+        }
+    }
+
+    /// Print requests to stderr instead of sending them
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Whether requests are only printed (`--dry-run`)
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Log every request and response to stderr, with secrets redacted
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Abort any single request that takes longer than `timeout`; `None` waits indefinitely.
+    /// Sets both the read and write timeouts, keeping the connect timeout
+    pub fn with_timeout(self, timeout: Option<Duration>) -> Self {
+        let timeouts = TimeoutConfig { read_timeout: timeout, write_timeout: timeout, ..self.timeouts };
+        self.with_timeout_config(timeouts)
+    }
+
+    /// Set the connect, read and write timeouts separately
+    pub fn with_timeout_config(mut self, timeouts: TimeoutConfig) -> Self {
+        if timeouts.connect_timeout != self.timeouts.connect_timeout {
+            self.http = build_http(timeouts.connect_timeout);
         }
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Replace the default retry policy
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    /// Retry a failed request up to `retries` more times, keeping the default backoff
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retry.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// Stop `get_paginated` after `max_pages` requests
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Cap response body downloads at `rate` bytes per second
+    pub fn with_rate_limit(mut self, rate: Option<u64>) -> Self {
+        self.rate_limit = rate;
+        self
+    }
+
+    /// Send the API key as `X-API-Key` or as an `Authorization: Bearer` token
+    pub fn with_auth_scheme(mut self, scheme: AuthScheme) -> Self {
+        self.auth_scheme = scheme;
+        self
+    }
+
+    /// Attribute every request to `user` by sending it in the `header` header
+    pub fn with_user(mut self, user: Option<String>, header: impl Into<String>) -> Self {
+        let header = header.into();
+        self.user = user.map(|u| (header, u));
+        self
     }
 
     fn build_headers(&self) -> reqwest::header::HeaderMap {
@@ -34,8 +396,25 @@ impl HttpClient {
             reqwest::header::HeaderValue::from_static("application/json"),
         );
         if let Some(api_key) = &self.api_key {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(api_key) {
-                headers.insert("X-API-Key", value);
+            match self.auth_scheme {
+                AuthScheme::ApiKey => {
+                    if let Ok(value) = reqwest::header::HeaderValue::from_str(api_key) {
+                        headers.insert("X-API-Key", value);
+                    }
+                }
+                AuthScheme::Bearer => {
+                    if let Ok(value) = reqwest::header::HeaderValue::from_str(&format!("Bearer {}", api_key)) {
+                        headers.insert(reqwest::header::AUTHORIZATION, value);
+                    }
+                }
+            }
+        }
+        if let Some((header, user)) = &self.user {
+            if let (Ok(name), Ok(value)) = (
+                reqwest::header::HeaderName::from_bytes(header.as_bytes()),
+                reqwest::header::HeaderValue::from_str(user),
+            ) {
+                headers.insert(name, value);
             }
         }
         headers
@@ -48,18 +427,73 @@ impl HttpClient {
         params: &[(String, String)],
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .get(&url)
-            .headers(self.build_headers())
-            .query(params)
-            .send()
+        let response = match self
+            .send_with_retry(true, || self.http.get(&url).headers(self.build_headers()).query(params))
             .await
-            .map_err(|e| Error::Network(format!("HTTP request failed: {}", e)))?;
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
 
         self.handle_response(response).await
     }
 
+    /// GET a listing page by page. A page is either a bare array (the last page) or an
+    /// object with `items` and an optional `next_cursor`; while a cursor is returned the
+    /// request is repeated with `cursor=<value>`. Stops early after `max_pages` pages or
+    /// `MAX_PAGINATED_ITEMS` items, returning what was collected so far and marking it
+    /// `truncated` if the server had more.
+    pub async fn get_paginated<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(String, String)],
+    ) -> Result<Paginated<T>> {
+        let mut items: Vec<T> = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..self.max_pages {
+            let mut page_params = params.to_vec();
+            if let Some(cursor) = cursor.take() {
+                page_params.push(("cursor".to_string(), cursor));
+            }
+            let page: Value = self.get(path, &page_params).await?;
+            let (page_items, next) = match page {
+                Value::Null => (Vec::new(), None),
+                Value::Array(page_items) => (page_items, None),
+                Value::Object(mut obj) => {
+                    let page_items = match obj.remove("items") {
+                        Some(Value::Array(page_items)) => page_items,
+                        _ => {
+                            return Err(Error::Parse(format!(
+                                "Expected a list or an object with `items` from {}",
+                                path
+                            )))
+                        }
+                    };
+                    let next = obj
+                        .get("next_cursor")
+                        .and_then(Value::as_str)
+                        .filter(|c| !c.is_empty())
+                        .map(str::to_string);
+                    (page_items, next)
+                }
+                _ => return Err(Error::Parse(format!("Expected a list from {}", path))),
+            };
+            for item in page_items {
+                if items.len() >= MAX_PAGINATED_ITEMS {
+                    return Ok(Paginated { items, truncated: true });
+                }
+                items.push(serde_json::from_value(item).map_err(|e| {
+                    Error::Parse(format!("Failed to parse item from {}: {}", path, e))
+                })?);
+            }
+            match next {
+                Some(next) => cursor = Some(next),
+                None => return Ok(Paginated { items, truncated: false }),
+            }
+        }
+        Ok(Paginated { items, truncated: cursor.is_some() })
+    }
+
     /// Make a POST request
     pub async fn post<B: serde::Serialize, T: DeserializeOwned>(
         &self,
@@ -67,18 +501,69 @@ impl HttpClient {
         body: &B,
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .post(&url)
-            .headers(self.build_headers())
-            .json(body)
-            .send()
+        let response = match self
+            .send_with_retry(false, || self.http.post(&url).headers(self.build_headers()).json(body))
             .await
-            .map_err(|e| Error::Network(format!("HTTP request failed: {}", e)))?;
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
 
         self.handle_response(response).await
     }
 
+    /// Make a PUT request, conditional on the resource version when `if_match` is set
+    pub async fn put<B: serde::Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+        if_match: Option<&str>,
+    ) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let if_match = if_match
+            .map(reqwest::header::HeaderValue::from_str)
+            .transpose()
+            .map_err(|e| Error::Client(format!("Invalid version tag: {}", e)))?;
+        let response = match self
+            .send_with_retry(true, || {
+                let mut headers = self.build_headers();
+                if let Some(version) = &if_match {
+                    headers.insert(reqwest::header::IF_MATCH, version.clone());
+                }
+                self.http.put(&url).headers(headers).json(body)
+            })
+            .await
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
+
+        if response.status() == StatusCode::PRECONDITION_FAILED {
+            return Err(Error::Conflict(
+                "resource changed since you read it".to_string(),
+            ));
+        }
+        self.handle_update_response(response).await
+    }
+
+    /// Make a PATCH request; like POST it is not retried once the server has seen it
+    pub async fn patch<B: serde::Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = match self
+            .send_with_retry(false, || self.http.patch(&url).headers(self.build_headers()).json(body))
+            .await
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
+
+        self.handle_update_response(response).await
+    }
+
     /// Make a DELETE request
     pub async fn delete<T: DeserializeOwned>(
         &self,
@@ -86,14 +571,13 @@ impl HttpClient {
         params: &[(String, String)],
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .delete(&url)
-            .headers(self.build_headers())
-            .query(params)
-            .send()
+        let response = match self
+            .send_with_retry(true, || self.http.delete(&url).headers(self.build_headers()).query(params))
             .await
-            .map_err(|e| Error::Network(format!("HTTP request failed: {}", e)))?;
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
 
         self.handle_response(response).await
     }
@@ -105,18 +589,110 @@ impl HttpClient {
         body: &B,
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .delete(&url)
-            .headers(self.build_headers())
-            .json(body)
-            .send()
+        let response = match self
+            .send_with_retry(true, || self.http.delete(&url).headers(self.build_headers()).json(body))
             .await
-            .map_err(|e| Error::Network(format!("HTTP request failed: {}", e)))?;
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
 
         self.handle_response(response).await
     }
 
+    /// Send a request, retrying transient failures per the retry policy.
+    ///
+    /// Idempotent requests retry on any transport error (including timeouts) and on
+    /// HTTP 429/5xx. Others, i.e. POSTs, retry only when the connection could not be
+    /// made, since a request that reached the server may already have taken effect.
+    /// `build` is called once per attempt. After the last attempt a retryable status is
+    /// returned as-is so `handle_response` reports it like any other HTTP error.
+    async fn send_with_retry(
+        &self,
+        idempotent: bool,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        if self.dry_run {
+            self.print_request(build());
+            return Err(Error::DryRun);
+        }
+        let max_attempts = self.retry.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            let last = attempt >= max_attempts;
+            let sent = match build().build() {
+                Ok(mut request) => {
+                    if let Some(timeout) = self.timeouts.for_method(request.method()) {
+                        *request.timeout_mut() = Some(timeout);
+                    }
+                    if self.debug {
+                        eprintln!("[debug] > {}", describe_request(&request).replace('\n', "\n[debug] > "));
+                    }
+                    self.http.execute(request).await
+                }
+                Err(e) => Err(e),
+            };
+            if let (true, Ok(response)) = (self.debug, &sent) {
+                eprintln!("[debug] < {} {}", response.status(), response.url());
+            }
+            match sent {
+                Ok(response) if last || !idempotent || !is_retryable_status(response.status()) => {
+                    return Ok(response)
+                }
+                Err(e) if last || !(idempotent || e.is_connect()) => {
+                    return Err(Error::Network(format!("HTTP request failed: {}", e)))
+                }
+                _ => {}
+            }
+            tokio::time::sleep(self.retry.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Describe a request on stderr for `--dry-run`: method, URL, headers and body,
+    /// with the API key redacted
+    fn print_request(&self, request: reqwest::RequestBuilder) {
+        let request = match request.build() {
+            Ok(request) => request,
+            Err(e) => {
+                eprintln!("(dry-run) invalid request: {}", e);
+                return;
+            }
+        };
+        eprintln!("{}", describe_request(&request));
+    }
+
+    async fn read_body(&self, mut response: reqwest::Response) -> Result<Vec<u8>> {
+        let read_err = |e: reqwest::Error| Error::Network(format!("Failed to read response: {}", e));
+        let body = match self.rate_limit {
+            None => response.bytes().await.map_err(read_err)?.to_vec(),
+            Some(rate) => {
+                let mut throttle = Throttle::new(rate);
+                let mut body = Vec::new();
+                while let Some(chunk) = response.chunk().await.map_err(read_err)? {
+                    for piece in chunk.chunks(throttle.chunk_size()) {
+                        body.extend_from_slice(piece);
+                        throttle.pace(piece.len()).await;
+                    }
+                }
+                body
+            }
+        };
+        if self.debug {
+            eprintln!("[debug] < {}", redact_body(&body));
+        }
+        Ok(body)
+    }
+
+    /// `handle_response` for updates, reporting HTTP 409 as `Error::Conflict`
+    async fn handle_update_response<T: DeserializeOwned>(&self, response: reqwest::Response) -> Result<T> {
+        let status = response.status();
+        match self.handle_response(response).await {
+            Err(Error::Api(msg)) if status == StatusCode::CONFLICT => Err(Error::Conflict(msg)),
+            other => other,
+        }
+    }
+
     async fn handle_response<T: DeserializeOwned>(
         &self,
         response: reqwest::Response,
@@ -129,54 +705,107 @@ impl HttpClient {
                 .map_err(|e| Error::Parse(format!("Failed to parse empty response: {}", e)));
         }
 
-        let json: Value = response
-            .json()
-            .await
-            .map_err(|e| Error::Network(format!("Failed to parse JSON response: {}", e)))?;
+        let body = self.read_body(response).await?;
+        parse_envelope(status, &body)
+    }
 
-        // Handle HTTP errors
-        if !status.is_success() {
-            let error_msg = json
-                .get("error")
-                .and_then(|e| e.get("message"))
-                .and_then(|m| m.as_str())
-                .map(|s| s.to_string())
-                .or_else(|| json.get("detail").and_then(|d| d.as_str()).map(|s| s.to_string()))
-                .unwrap_or_else(|| format!("HTTP error {}", status));
-            return Err(Error::Api(error_msg));
-        }
-
-        // Handle API errors (status == success but body has error)
-        if let Some(error) = json.get("error") {
-            if !error.is_null() {
-                let code = error
-                    .get("code")
-                    .and_then(|c| c.as_str())
-                    .unwrap_or("UNKNOWN");
-                let message = error
-                    .get("message")
-                    .and_then(|m| m.as_str())
-                    .unwrap_or("Unknown error");
-                return Err(Error::Api(format!("[{}] {}", code, message)));
+    // ============ Content Methods ============
+
+    pub async fn read(&self, uri: &str) -> Result<String> {
+        let params = vec![("uri".to_string(), uri.to_string())];
+        self.get("/api/v1/content/read", &params).await
+    }
+
+    /// Stream L2 content into `w` as it arrives rather than buffering the whole body.
+    ///
+    /// The `result` string is decoded out of the JSON envelope chunk by chunk; error
+    /// statuses and envelopes without a string result are parsed whole as usual.
+    /// A connection that fails mid-body surfaces as `Error::Network`.
+    pub async fn read_stream(&self, uri: &str, w: &mut dyn Write) -> Result<()> {
+        let url = format!("{}/api/v1/content/read", self.base_url);
+        let response = self
+            .send_with_retry(true, || self.http.get(&url).headers(self.build_headers()).query(&[("uri", uri)]))
+            .await?;
+        let status = response.status();
+        if !status.is_success() || matches!(status, StatusCode::NO_CONTENT | StatusCode::ACCEPTED) {
+            let content: String = self.handle_response(response).await?;
+            w.write_all(content.as_bytes())?;
+            return Ok(());
+        }
+
+        let mut throttle = self.rate_limit.map(Throttle::new);
+        let mut decoder = ResultStream::default();
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| Error::Network(format!("Failed to read response: {}", e)))?;
+            match throttle.as_mut() {
+                Some(throttle) => {
+                    for piece in chunk.chunks(throttle.chunk_size()) {
+                        decoder.feed(piece, w)?;
+                        throttle.pace(piece.len()).await;
+                    }
+                }
+                None => decoder.feed(&chunk, w)?,
             }
         }
+        if let Some(envelope) = decoder.finish()? {
+            if self.debug {
+                eprintln!("[debug] < {}", redact_body(&envelope));
+            }
+            let content: String = parse_envelope(status, &envelope)?;
+            w.write_all(content.as_bytes())?;
+        }
+        w.flush()?;
+        Ok(())
+    }
 
-        // Extract result from wrapped response or use the whole response
-        let result = if let Some(result) = json.get("result") {
-            result.clone()
-        } else {
-            json
-        };
+    /// Read content along with its version tag (the `ETag` header), if the server sends one
+    pub async fn read_versioned(&self, uri: &str) -> Result<(String, Option<String>)> {
+        let url = format!("{}/api/v1/content/read", self.base_url);
+        let response = self
+            .send_with_retry(true, || self.http.get(&url).headers(self.build_headers()).query(&[("uri", uri)]))
+            .await?;
+        let version = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        let content = self.handle_response(response).await?;
+        Ok((content, version))
+    }
 
-        serde_json::from_value(result)
-            .map_err(|e| Error::Parse(format!("Failed to deserialize response: {}", e)))
+    /// Whether the server's OpenAPI document lists `method` (lowercase) on `path`; `None`
+    /// when it publishes no document, so only the request itself can tell
+    pub async fn has_endpoint(&self, method: &str, path: &str) -> Option<bool> {
+        let openapi: serde_json::Value = self.get("/openapi.json", &[]).await.ok()?;
+        let paths = openapi.get("paths")?.as_object()?;
+        Some(paths.get(path).and_then(|p| p.get(method)).is_some())
     }
 
-    // ============ Content Methods ============
+    /// Replace content; with `if_match`, fails with `Error::Conflict` if it changed meanwhile
+    pub async fn write_content(&self, uri: &str, content: &str, if_match: Option<&str>) -> Result<serde_json::Value> {
+        let body = serde_json::json!({
+            "uri": uri,
+            "content": content,
+        });
+        self.put("/api/v1/content", &body, if_match).await
+    }
 
-    pub async fn read(&self, uri: &str) -> Result<String> {
-        let params = vec![("uri".to_string(), uri.to_string())];
-        self.get("/api/v1/content/read", &params).await
+    /// Update a resource's `reason`/`instruction` metadata via `PATCH /api/v1/resources`
+    pub async fn update_resource(
+        &self,
+        uri: &str,
+        reason: Option<&str>,
+        instruction: Option<&str>,
+    ) -> Result<serde_json::Value> {
+        let mut body = serde_json::json!({ "uri": uri });
+        if let Some(reason) = reason {
+            body["reason"] = reason.into();
+        }
+        if let Some(instruction) = instruction {
+            body["instruction"] = instruction.into();
+        }
+        self.patch("/api/v1/resources", &body).await
     }
 
     pub async fn abstract_content(&self, uri: &str) -> Result<String> {
@@ -191,8 +820,9 @@ impl HttpClient {
 
     // ============ Filesystem Methods ============
 
-    pub async fn ls(&self, uri: &str, simple: bool, recursive: bool, output: &str, abs_limit: i32, show_all_hidden: bool, node_limit: i32) -> Result<serde_json::Value> {
-        let params = vec![
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ls(&self, uri: &str, simple: bool, recursive: bool, output: &str, abs_limit: i32, show_all_hidden: bool, node_limit: i32, page: Option<Page>) -> Result<serde_json::Value> {
+        let mut params = vec![
             ("uri".to_string(), uri.to_string()),
             ("simple".to_string(), simple.to_string()),
             ("recursive".to_string(), recursive.to_string()),
@@ -201,6 +831,10 @@ impl HttpClient {
             ("show_all_hidden".to_string(), show_all_hidden.to_string()),
             ("node_limit".to_string(), node_limit.to_string()),
         ];
+        if let Some(page) = page {
+            params.push(("offset".to_string(), page.offset.to_string()));
+            params.push(("limit".to_string(), page.limit.to_string()));
+        }
         self.get("/api/v1/fs/ls", &params).await
     }
 
@@ -215,50 +849,147 @@ impl HttpClient {
         self.get("/api/v1/fs/tree", &params).await
     }
 
+    pub fn mkdir_request(uri: &str) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::POST,
+            path: "/api/v1/fs/mkdir".to_string(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({ "uri": uri })),
+        }
+    }
+
     pub async fn mkdir(&self, uri: &str) -> Result<()> {
+        self.send_planned(&Self::mkdir_request(uri)).await?;
+        Ok(())
+    }
+
+    pub async fn touch(&self, uri: &str) -> Result<()> {
         let body = serde_json::json!({ "uri": uri });
-        let _: serde_json::Value = self.post("/api/v1/fs/mkdir", &body).await?;
+        let _: serde_json::Value = self.post("/api/v1/fs/touch", &body).await?;
         Ok(())
     }
 
+    /// Send a request built by one of the `*_request` planners
+    async fn send_planned(&self, plan: &PlannedRequest) -> Result<Value> {
+        match (&plan.method, &plan.body) {
+            (&reqwest::Method::POST, body) => self.post(&plan.path, body.as_ref().unwrap_or(&Value::Null)).await,
+            (_, Some(body)) => self.delete_with_body(&plan.path, body).await,
+            (_, None) => self.delete(&plan.path, &plan.query).await,
+        }
+    }
+
+    pub fn rm_request(uri: &str, recursive: bool) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::DELETE,
+            path: "/api/v1/fs".to_string(),
+            query: vec![
+                ("uri".to_string(), uri.to_string()),
+                ("recursive".to_string(), recursive.to_string()),
+            ],
+            body: None,
+        }
+    }
+
     pub async fn rm(&self, uri: &str, recursive: bool) -> Result<()> {
-        let params = vec![
-            ("uri".to_string(), uri.to_string()),
-            ("recursive".to_string(), recursive.to_string()),
-        ];
-        let _: serde_json::Value = self.delete("/api/v1/fs", &params).await?;
+        self.send_planned(&Self::rm_request(uri, recursive)).await?;
         Ok(())
     }
 
+    pub fn mv_request(from_uri: &str, to_uri: &str) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::POST,
+            path: "/api/v1/fs/mv".to_string(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({
+                "from_uri": from_uri,
+                "to_uri": to_uri,
+            })),
+        }
+    }
+
     pub async fn mv(&self, from_uri: &str, to_uri: &str) -> Result<()> {
-        let body = serde_json::json!({
-            "from_uri": from_uri,
-            "to_uri": to_uri,
-        });
-        let _: serde_json::Value = self.post("/api/v1/fs/mv", &body).await?;
+        self.send_planned(&Self::mv_request(from_uri, to_uri)).await?;
         Ok(())
     }
 
+    pub fn cp_request(from_uri: &str, to_uri: &str, recursive: bool, vectorize: bool, force: bool) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::POST,
+            path: "/api/v1/fs/cp".to_string(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({
+                "from_uri": from_uri,
+                "to_uri": to_uri,
+                "recursive": recursive,
+                "vectorize": vectorize,
+                "force": force,
+            })),
+        }
+    }
+
+    /// Copy `from_uri` to `to_uri` via `POST /api/v1/fs/cp`; `force` lets it replace an existing target
+    pub async fn cp(&self, from_uri: &str, to_uri: &str, recursive: bool, vectorize: bool, force: bool) -> Result<serde_json::Value> {
+        self.send_planned(&Self::cp_request(from_uri, to_uri, recursive, vectorize, force)).await
+    }
+
+    /// Aggregate sizes under `uri` via `GET /api/v1/fs/du`
+    pub async fn du(&self, uri: &str, recursive: bool, all: bool) -> Result<serde_json::Value> {
+        let params = vec![
+            ("uri".to_string(), uri.to_string()),
+            ("recursive".to_string(), recursive.to_string()),
+            ("all".to_string(), all.to_string()),
+        ];
+        self.get("/api/v1/fs/du", &params).await
+    }
+
     pub async fn stat(&self, uri: &str) -> Result<serde_json::Value> {
         let params = vec![("uri".to_string(), uri.to_string())];
         self.get("/api/v1/fs/stat", &params).await
     }
 
+    /// Stat several URIs in one `POST /api/v1/fs/stat`, in request order.
+    ///
+    /// A server without the batch form (HTTP 404 or 405) is asked once per URI instead.
+    pub async fn stat_many(&self, uris: &[String]) -> Result<Vec<serde_json::Value>> {
+        let url = format!("{}/api/v1/fs/stat", self.base_url);
+        let body = serde_json::json!({ "uris": uris });
+        let response = match self
+            .send_with_retry(false, || self.http.post(&url).headers(self.build_headers()).json(&body))
+            .await
+        {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
+        if !matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED) {
+            return self.handle_response(response).await;
+        }
+        let mut results = Vec::with_capacity(uris.len());
+        for uri in uris {
+            results.push(self.stat(uri).await?);
+        }
+        Ok(results)
+    }
+
     // ============ Search Methods ============
 
+    /// Semantic retrieval; `explain` asks the server for scores and a `debug` breakdown
     pub async fn find(
         &self,
         query: String,
         uri: String,
         limit: i32,
         threshold: Option<f64>,
+        explain: bool,
     ) -> Result<serde_json::Value> {
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "query": query,
             "target_uri": uri,
             "limit": limit,
             "score_threshold": threshold,
         });
+        if explain {
+            body["explain"] = Value::Bool(true);
+        }
         self.post("/api/v1/search/find", &body).await
     }
 
@@ -319,6 +1050,98 @@ impl HttpClient {
         self.post("/api/v1/resources", &body).await
     }
 
+    /// Upload a local file's bytes as a new resource via multipart
+    /// `POST /api/v1/resources/upload`, for servers that cannot read the client's disk.
+    ///
+    /// A server without that endpoint (HTTP 404 or 405) yields an `[UNSUPPORTED]` API error.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upload_resource(
+        &self,
+        data: Vec<u8>,
+        filename: &str,
+        target: Option<String>,
+        reason: &str,
+        instruction: &str,
+        wait: bool,
+        timeout: Option<f64>,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}/api/v1/resources/upload", self.base_url);
+        let mime = mime_guess::from_path(filename).first_or_octet_stream();
+        let build = || {
+            let file = reqwest::multipart::Part::bytes(data.clone())
+                .file_name(filename.to_string())
+                .mime_str(mime.essence_str())
+                .unwrap_or_else(|_| reqwest::multipart::Part::bytes(data.clone()).file_name(filename.to_string()));
+            let mut form = reqwest::multipart::Form::new()
+                .part("file", file)
+                .text("reason", reason.to_string())
+                .text("instruction", instruction.to_string())
+                .text("wait", wait.to_string());
+            if let Some(target) = &target {
+                form = form.text("target", target.clone());
+            }
+            if let Some(timeout) = timeout {
+                form = form.text("timeout", timeout.to_string());
+            }
+            // The multipart boundary goes in Content-Type, so drop the JSON default
+            let mut headers = self.build_headers();
+            headers.remove(reqwest::header::CONTENT_TYPE);
+            self.http.post(&url).headers(headers).multipart(form)
+        };
+        let response = match self.send_with_retry(false, build).await {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
+        let status = response.status();
+        if matches!(status, StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED) {
+            return Err(Error::Api(format!("[UNSUPPORTED] server does not accept uploads (HTTP {})", status)));
+        }
+        self.handle_response(response).await
+    }
+
+    /// Multipart POST of text `fields` plus an optional `(filename, bytes)` part named
+    /// `file`, returning the unwrapped result.
+    ///
+    /// A server without the endpoint (HTTP 404 or 405) yields an `[UNSUPPORTED]` API error.
+    pub async fn post_multipart(
+        &self,
+        path: &str,
+        fields: &[(&str, &str)],
+        file: Option<(&str, Bytes)>,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}{}", self.base_url, path);
+        let build = || {
+            let mut form = reqwest::multipart::Form::new();
+            for (name, value) in fields {
+                form = form.text(name.to_string(), value.to_string());
+            }
+            if let Some((filename, data)) = &file {
+                let part = reqwest::multipart::Part::stream_with_length(data.clone(), data.len() as u64)
+                    .file_name(filename.to_string());
+                let mime = mime_guess::from_path(filename).first_or_octet_stream();
+                let part = match part.mime_str(mime.essence_str()) {
+                    Ok(part) => part,
+                    Err(_) => reqwest::multipart::Part::stream_with_length(data.clone(), data.len() as u64)
+                        .file_name(filename.to_string()),
+                };
+                form = form.part("file", part);
+            }
+            // The multipart boundary goes in Content-Type, so drop the JSON default
+            let mut headers = self.build_headers();
+            headers.remove(reqwest::header::CONTENT_TYPE);
+            self.http.post(&url).headers(headers).multipart(form)
+        };
+        let response = match self.send_with_retry(false, build).await {
+            Err(Error::DryRun) => return dry_run_value(),
+            response => response?,
+        };
+        let status = response.status();
+        if matches!(status, StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED) {
+            return Err(Error::Api(format!("[UNSUPPORTED] server does not accept uploads at {} (HTTP {})", path, status)));
+        }
+        self.handle_response(response).await
+    }
+
     pub async fn add_skill(
         &self,
         data: &str,
@@ -354,12 +1177,31 @@ impl HttpClient {
         self.post("/api/v1/relations/link", &body).await
     }
 
+    pub fn unlink_request(from_uri: &str, to_uri: &str) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::DELETE,
+            path: "/api/v1/relations/link".to_string(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({
+                "from_uri": from_uri,
+                "to_uri": to_uri,
+            })),
+        }
+    }
+
     pub async fn unlink(&self, from_uri: &str, to_uri: &str) -> Result<serde_json::Value> {
+        self.send_planned(&Self::unlink_request(from_uri, to_uri)).await
+    }
+
+    // ============ Observer Methods ============
+
+    pub async fn vlm_probe(&self, image_base64: &str, mime_type: &str, prompt: &str) -> Result<serde_json::Value> {
         let body = serde_json::json!({
-            "from_uri": from_uri,
-            "to_uri": to_uri,
+            "image": image_base64,
+            "mime_type": mime_type,
+            "prompt": prompt,
         });
-        self.delete_with_body("/api/v1/relations/link", &body).await
+        self.post("/api/v1/observer/vlm/probe", &body).await
     }
 
     // ============ Pack Methods ============
@@ -372,6 +1214,20 @@ impl HttpClient {
         self.post("/api/v1/pack/export", &body).await
     }
 
+    pub fn import_ovpack_request(file_path: &str, parent: &str, force: bool, vectorize: bool) -> PlannedRequest {
+        PlannedRequest {
+            method: reqwest::Method::POST,
+            path: "/api/v1/pack/import".to_string(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({
+                "file_path": file_path,
+                "parent": parent,
+                "force": force,
+                "vectorize": vectorize,
+            })),
+        }
+    }
+
     pub async fn import_ovpack(
         &self,
         file_path: &str,
@@ -379,12 +1235,547 @@ impl HttpClient {
         force: bool,
         vectorize: bool,
     ) -> Result<serde_json::Value> {
-        let body = serde_json::json!({
-            "file_path": file_path,
-            "parent": parent,
-            "force": force,
-            "vectorize": vectorize,
+        self.send_planned(&Self::import_ovpack_request(file_path, parent, force, vectorize)).await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_header_sent_when_configured() {
+        let client = HttpClient::new("http://localhost:1933", None)
+            .with_user(Some("alice".to_string()), "X-User");
+        let headers = client.build_headers();
+        assert_eq!(headers.get("X-User").unwrap(), "alice");
+    }
+
+    #[test]
+    fn test_custom_user_header_name() {
+        let client = HttpClient::new("http://localhost:1933", None)
+            .with_user(Some("bob".to_string()), "X-Remote-User");
+        let headers = client.build_headers();
+        assert_eq!(headers.get("X-Remote-User").unwrap(), "bob");
+        assert!(headers.get("X-User").is_none());
+    }
+
+    #[test]
+    fn test_bearer_scheme_sends_authorization() {
+        let client = HttpClient::new("http://localhost:1933", Some("token".to_string()));
+        assert_eq!(client.build_headers().get("X-API-Key").unwrap(), "token");
+        assert!(client.build_headers().get("Authorization").is_none());
+
+        let headers = client.with_auth_scheme(AuthScheme::Bearer).build_headers();
+        assert_eq!(headers.get("Authorization").unwrap(), "Bearer token");
+        assert!(headers.get("X-API-Key").is_none());
+    }
+
+    #[test]
+    fn test_no_user_header_without_user() {
+        let client = HttpClient::new("http://localhost:1933", None).with_user(None, "X-User");
+        assert!(client.build_headers().get("X-User").is_none());
+    }
+
+    /// Raw text (request line, headers and body) of each request a mock server received
+    pub(crate) type Requests = std::sync::Arc<std::sync::Mutex<Vec<String>>>;
+
+    /// Serve one canned `(status, body)` response per connection, in order, recording requests
+    pub(crate) async fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Requests) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Requests = Default::default();
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                loop {
+                    if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&request[..end]).to_ascii_lowercase();
+                        let length = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                recorded.lock().unwrap().push(String::from_utf8_lossy(&request).into_owned());
+                let content_type = if body.starts_with('{') { "application/json" } else { "text/plain" };
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    content_type,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.ok();
+            }
+        });
+        (url, requests)
+    }
+
+    /// Serve one chunked 200 response, pausing between chunks so each arrives on its own.
+    /// Without `complete` the connection drops before the terminating chunk.
+    pub(crate) async fn mock_chunked(chunks: Vec<&'static str>, complete: bool) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    return;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let head = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n";
+            socket.write_all(head.as_bytes()).await.unwrap();
+            for chunk in chunks {
+                socket.write_all(format!("{:x}\r\n{}\r\n", chunk.len(), chunk).as_bytes()).await.unwrap();
+                socket.flush().await.unwrap();
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            if complete {
+                socket.write_all(b"0\r\n\r\n").await.unwrap();
+            }
+            socket.shutdown().await.ok();
+        });
+        url
+    }
+
+    /// JSON body of a recorded request
+    pub(crate) fn request_body(request: &str) -> Value {
+        let body = request.split_once("\r\n\r\n").map(|(_, b)| b).unwrap_or("");
+        serde_json::from_str(body).unwrap_or(Value::Null)
+    }
+
+    fn fast_retry(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_status_until_success() {
+        let ok = r#"{"status":"ok","result":{"value":1}}"#;
+        let (url, requests) = mock_server(vec![(503, "{}"), (429, "{}"), (200, ok)]).await;
+        let client = HttpClient::new(url, None).with_retry(fast_retry(3));
+        let result: Value = client.get("/api/v1/test", &[]).await.unwrap();
+        assert_eq!(result["value"], 1);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let (url, requests) = mock_server(vec![(502, "{}"), (500, "{}")]).await;
+        let client = HttpClient::new(url, None).with_retry(fast_retry(2));
+        let result: Result<Value> = client.delete("/api/v1/test", &[]).await;
+        assert!(matches!(result, Err(Error::Api(_))));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried_after_server_error() {
+        let (url, requests) = mock_server(vec![(503, "{}"), (200, "{}")]).await;
+        let client = HttpClient::new(url, None).with_retry(fast_retry(3));
+        let result: Result<Value> = client.post("/api/v1/test", &serde_json::json!({})).await;
+        assert!(matches!(result, Err(Error::Api(_))));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_planned_request_preview() {
+        assert_eq!(
+            HttpClient::rm_request("viking://a", true).preview(),
+            serde_json::json!({
+                "dry_run": true,
+                "method": "DELETE",
+                "path": "/api/v1/fs",
+                "query": {"uri": "viking://a", "recursive": "true"},
+                "body": null,
+            })
+        );
+        let mv = HttpClient::mv_request("viking://a", "viking://b").preview();
+        assert_eq!(mv["method"], "POST");
+        assert_eq!(mv["query"], Value::Null);
+        assert_eq!(mv["body"], serde_json::json!({"from_uri": "viking://a", "to_uri": "viking://b"}));
+    }
+
+    #[tokio::test]
+    async fn test_bare_404_is_tagged_not_found() {
+        let (url, _) = mock_server(vec![(404, r#"{"detail":"Not Found"}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let err = client.get::<Value>("/api/v1/fs/stat", &[]).await.unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg == "[NOT_FOUND] Not Found"));
+    }
+
+    /// Records every write so a test can see the content arrive in pieces
+    #[derive(Default)]
+    struct Writes(Vec<Vec<u8>>);
+
+    impl std::io::Write for Writes {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if !buf.is_empty() {
+                self.0.push(buf.to_vec());
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_stream_writes_chunks_as_they_arrive() {
+        let url = mock_chunked(vec![r#"{"status":"ok","res"#, r#"ult":"line one\n"#, r#"line two\u00"#, r#"e9"}"#], true).await;
+        let client = HttpClient::new(url, None);
+        let mut out = Writes::default();
+        client.read_stream("viking://resources/a.md", &mut out).await.unwrap();
+        assert!(out.0.len() > 1, "content was written in one piece");
+        assert_eq!(out.0.concat(), "line one\nline twoé".as_bytes());
+
+        // Error envelopes are still parsed whole
+        let (url, _) = mock_server(vec![(404, r#"{"status":"error","error":{"code":"NOT_FOUND","message":"gone"}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let err = client.read_stream("viking://resources/b.md", &mut Vec::new()).await.unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.starts_with("[NOT_FOUND]")));
+    }
+
+    #[tokio::test]
+    async fn test_read_stream_reports_a_dropped_connection() {
+        let url = mock_chunked(vec![r#"{"status":"ok","result":"partial"#], false).await;
+        let client = HttpClient::new(url, None);
+        let mut out = Vec::new();
+        let err = client.read_stream("viking://resources/a.md", &mut out).await.unwrap_err();
+        assert!(matches!(err, Error::Network(_)));
+        assert_eq!(out, b"partial");
+    }
+
+    #[tokio::test]
+    async fn test_put_and_patch_success() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"uri":"viking://r/a"}}"#),
+            (200, r#"{"status":"ok","result":{"uri":"viking://r/a"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let body = serde_json::json!({"uri": "viking://r/a"});
+        let put: Value = client.put("/api/v1/test", &body, None).await.unwrap();
+        let patch: Value = client.patch("/api/v1/test", &body).await.unwrap();
+        assert_eq!(put, patch);
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("PUT /api/v1/test "));
+        assert!(requests[1].starts_with("PATCH /api/v1/test "));
+        assert_eq!(request_body(&requests[1]), body);
+    }
+
+    #[tokio::test]
+    async fn test_put_and_patch_conflict() {
+        let conflict = r#"{"status":"error","error":{"code":"CONFLICT","message":"busy"}}"#;
+        let (url, requests) = mock_server(vec![(409, conflict), (409, conflict)]).await;
+        let client = HttpClient::new(url, None);
+        let body = serde_json::json!({});
+        let put: Result<Value> = client.put("/api/v1/test", &body, None).await;
+        assert!(matches!(put, Err(Error::Conflict(msg)) if msg == "[CONFLICT] busy"));
+        let patch: Result<Value> = client.patch("/api/v1/test", &body).await;
+        assert!(matches!(patch, Err(Error::Conflict(_))));
+        // A conflict is an answer, not a transient failure
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_write_content_precondition_failed_is_conflict() {
+        let (url, requests) = mock_server(vec![(412, "")]).await;
+        let client = HttpClient::new(url, None);
+        let err = client.write_content("viking://resources/a.md", "x", Some("\"v1\"")).await.unwrap_err();
+        assert!(matches!(err, Error::Conflict(_)));
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].to_ascii_lowercase().contains("if-match: \"v1\""));
+    }
+
+    #[tokio::test]
+    async fn test_read_versioned_is_retried() {
+        let (url, requests) = mock_server(vec![(503, "{}"), (200, r#"{"status":"ok","result":"hello"}"#)]).await;
+        let client = HttpClient::new(url, None).with_retry(fast_retry(2));
+        let (content, version) = client.read_versioned("viking://r/a").await.unwrap();
+        assert_eq!(content, "hello");
+        assert_eq!(version, None);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_put_is_retried_with_its_version_tag() {
+        let (url, requests) = mock_server(vec![(503, "{}"), (200, r#"{"status":"ok","result":{"uri":"viking://r/a"}}"#)]).await;
+        let client = HttpClient::new(url, None).with_retry(fast_retry(2));
+        client.write_content("viking://r/a", "x", Some("\"v1\"")).await.unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|r| r.starts_with("PUT /api/v1/content ") && r.to_ascii_lowercase().contains("if-match: \"v1\"")));
+    }
+
+    #[tokio::test]
+    async fn test_stat_many_batches_and_falls_back() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":[{"uri":"viking://a"},{"uri":"viking://b"}]}"#),
+            (405, r#"{"detail":"Method Not Allowed"}"#),
+            (200, r#"{"status":"ok","result":{"uri":"viking://a"}}"#),
+            (200, r#"{"status":"ok","result":{"uri":"viking://b"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let uris = vec!["viking://a".to_string(), "viking://b".to_string()];
+        let batched = client.stat_many(&uris).await.unwrap();
+        let fallback = client.stat_many(&uris).await.unwrap();
+        assert_eq!(batched, fallback);
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /api/v1/fs/stat "));
+        assert_eq!(request_body(&requests[0]), serde_json::json!({"uris": ["viking://a", "viking://b"]}));
+        assert!(requests[2].starts_with("GET /api/v1/fs/stat?uri="));
+        assert_eq!(requests.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_upload_resource_sends_multipart() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"root_uri":"viking://resources/a"}}"#),
+            (404, r#"{"detail":"Not Found"}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let bytes = b"%PDF-1.4 binary".to_vec();
+        client
+            .upload_resource(bytes.clone(), "a.pdf", Some("viking://resources/a".to_string()), "r", "", false, None)
+            .await
+            .unwrap();
+        let err = client.upload_resource(bytes, "a.pdf", None, "r", "", false, None).await.unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.starts_with("[UNSUPPORTED]")));
+
+        let requests = requests.lock().unwrap();
+        let upload = requests[0].to_ascii_lowercase();
+        assert!(upload.starts_with("post /api/v1/resources/upload "));
+        assert!(upload.contains("content-type: multipart/form-data; boundary="));
+        assert!(requests[0].contains("filename=\"a.pdf\""));
+        assert!(requests[0].contains("Content-Type: application/pdf"));
+        assert!(requests[0].contains("%PDF-1.4 binary"));
+        assert!(requests[0].contains("viking://resources/a"));
+    }
+
+    #[tokio::test]
+    async fn test_get_paginated_follows_cursor() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"items":[{"id":"a"},{"id":"b"}],"next_cursor":"c2"}}"#),
+            (200, r#"{"status":"ok","result":{"items":[{"id":"c"}],"next_cursor":null}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let listing: Paginated<Value> = client
+            .get_paginated("/api/v1/sessions", &[("limit".to_string(), "2".to_string())])
+            .await
+            .unwrap();
+        let ids: Vec<&str> = listing.items.iter().filter_map(|i| i["id"].as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert!(!listing.truncated);
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /api/v1/sessions?limit=2 "));
+        assert!(requests[1].starts_with("GET /api/v1/sessions?limit=2&cursor=c2 "));
+    }
+
+    #[tokio::test]
+    async fn test_get_paginated_plain_list_and_page_cap() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":[{"id":"a"}]}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let listing: Paginated<Value> = client.get_paginated("/api/v1/sessions", &[]).await.unwrap();
+        assert_eq!(listing.items.len(), 1);
+        assert!(!listing.truncated);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let page = r#"{"status":"ok","result":{"items":[{"id":"a"}],"next_cursor":"more"}}"#;
+        let (url, requests) = mock_server(vec![(200, page), (200, page), (200, page)]).await;
+        let client = HttpClient::new(url, None).with_max_pages(2);
+        let listing: Paginated<Value> = client.get_paginated("/api/v1/sessions", &[]).await.unwrap();
+        assert_eq!(listing.items.len(), 2);
+        assert!(listing.truncated);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_error_message_includes_server_code() {
+        let (url, _requests) = mock_server(vec![
+            (400, r#"{"status":"error","error":{"code":"INVALID_URI","message":"bad uri"}}"#),
+            (400, r#"{"status":"error","error":{"message":"bad uri"}}"#),
+            (422, r#"{"detail":"field required"}"#),
+            (404, r#"{}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let mut messages = Vec::new();
+        for _ in 0..4 {
+            match client.get::<Value>("/api/v1/fs/stat", &[]).await {
+                Err(Error::Api(msg)) => messages.push(msg),
+                other => panic!("expected an API error, got {:?}", other),
+            }
+        }
+        assert_eq!(messages, vec!["[INVALID_URI] bad uri", "bad uri", "field required", "[NOT_FOUND] HTTP error 404 Not Found"]);
+    }
+
+    #[tokio::test]
+    async fn test_touch_posts_uri_and_passes_errors_through() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":null}"#),
+            (409, r#"{"status":"error","error":{"code":"ALREADY_EXISTS","message":"viking://resources/a exists"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        client.touch("viking://resources/a").await.unwrap();
+        let err = client.touch("viking://resources/a").await.unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg == "[ALREADY_EXISTS] viking://resources/a exists"));
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /api/v1/fs/touch "));
+        assert_eq!(request_body(&requests[0]), serde_json::json!({"uri": "viking://resources/a"}));
+    }
+
+    #[tokio::test]
+    async fn test_cp_sends_json_body() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        client.cp("viking://resources/a", "viking://resources/b", true, false, false).await.unwrap();
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /api/v1/fs/cp "));
+        assert_eq!(
+            request_body(&requests[0]),
+            serde_json::json!({
+                "from_uri": "viking://resources/a",
+                "to_uri": "viking://resources/b",
+                "recursive": true,
+                "vectorize": false,
+                "force": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_describe_request_redacts_api_key() {
+        let client = HttpClient::new("http://localhost:1933", Some("secret".to_string()));
+        let request = client
+            .http
+            .post("http://localhost:1933/api/v1/fs/mv")
+            .headers(client.build_headers())
+            .json(&serde_json::json!({"from_uri": "a"}))
+            .build()
+            .unwrap();
+        let text = describe_request(&request);
+        assert!(text.starts_with("POST http://localhost:1933/api/v1/fs/mv\n"));
+        assert!(text.contains("x-api-key: [redacted]"));
+        assert!(!text.contains("secret"));
+        assert!(text.ends_with("\n\n{\"from_uri\":\"a\"}"));
+    }
+
+    #[test]
+    fn test_redact_body_hides_nested_api_keys() {
+        let body = br#"{"url":"http://x","api_key":"secret","profiles":[{"apiKey":"other"}]}"#;
+        let text = redact_body(body);
+        assert!(!text.contains("secret") && !text.contains("other"));
+        assert_eq!(text.matches("[redacted]").count(), 2);
+        assert!(text.contains("http://x"));
+        assert_eq!(redact_body(b"plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_describe_request_redacts_bearer_token() {
+        let client = HttpClient::new("http://localhost:1933", Some("secret".to_string()))
+            .with_auth_scheme(AuthScheme::Bearer);
+        let request = client.http.get("http://localhost:1933/health").headers(client.build_headers()).build().unwrap();
+        let text = describe_request(&request);
+        assert!(text.contains("authorization: [redacted]"));
+        assert!(!text.contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_sends_nothing() {
+        let (url, requests) = mock_server(vec![]).await;
+        let client = HttpClient::new(url, None).with_dry_run(true);
+        let value: Value = client.get("/api/v1/fs/ls", &[]).await.unwrap();
+        assert!(value.is_null());
+        let text: Result<String> = client.get("/api/v1/content/read", &[]).await;
+        assert!(matches!(text, Err(Error::DryRun)));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_timeout_fails_with_network_error() {
+        // Accept connections but never answer
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
         });
-        self.post("/api/v1/pack/import", &body).await
+        let client = HttpClient::new(url, None).with_retries(0).with_timeout_config(TimeoutConfig {
+            read_timeout: Some(Duration::from_millis(50)),
+            write_timeout: None,
+            ..TimeoutConfig::default()
+        });
+        let started = std::time::Instant::now();
+        let result: Result<Value> = client.get("/api/v1/system/status", &[]).await;
+        assert!(matches!(result, Err(Error::Network(_))), "{:?}", result);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_timeout_for_method() {
+        let timeouts = TimeoutConfig {
+            connect_timeout: None,
+            read_timeout: Some(Duration::from_secs(1)),
+            write_timeout: Some(Duration::from_secs(2)),
+        };
+        assert_eq!(timeouts.for_method(&reqwest::Method::GET), Some(Duration::from_secs(1)));
+        assert_eq!(timeouts.for_method(&reqwest::Method::DELETE), Some(Duration::from_secs(1)));
+        assert_eq!(timeouts.for_method(&reqwest::Method::POST), Some(Duration::from_secs(2)));
+        assert_eq!(timeouts.for_method(&reqwest::Method::PUT), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_timeout_from_secs() {
+        assert_eq!(timeout_from_secs(0.5).unwrap(), Some(Duration::from_millis(500)));
+        assert_eq!(timeout_from_secs(30.0).unwrap(), Some(Duration::from_secs(30)));
+        assert_eq!(timeout_from_secs(0.0).unwrap(), None);
+        assert!(matches!(timeout_from_secs(-1.0), Err(Error::Config(_))));
+        assert!(matches!(timeout_from_secs(f64::NAN), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: false,
+        };
+        assert_eq!(config.delay(1), Duration::from_millis(100));
+        assert_eq!(config.delay(2), Duration::from_millis(200));
+        assert_eq!(config.delay(3), Duration::from_millis(300));
     }
 }
@@ -0,0 +1,88 @@
+use serde_json::Value;
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::output::{output_success, Output};
+
+/// HTTP method for `api`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ApiMethod {
+    Get,
+    Post,
+    Delete,
+}
+
+/// Send a raw request to `path` and render the response like any other command.
+///
+/// `body` must be JSON; POST without one sends `{}`, and GET takes none.
+pub async fn call(
+    client: &HttpClient,
+    method: ApiMethod,
+    path: &str,
+    body: Option<&str>,
+    out: &Output,
+) -> Result<()> {
+    if !path.starts_with('/') {
+        return Err(Error::Client(format!(
+            "API path must start with '/', e.g. /api/v1/system/status (got {})",
+            path
+        )));
+    }
+    let body = body
+        .map(|body| {
+            serde_json::from_str::<Value>(body)
+                .map_err(|e| Error::Client(format!("Request body is not valid JSON: {}", e)))
+        })
+        .transpose()?;
+
+    let response: Value = match (method, body) {
+        (ApiMethod::Get, None) => client.get(path, &[]).await?,
+        (ApiMethod::Get, Some(_)) => {
+            return Err(Error::Client("GET requests take no --body".to_string()))
+        }
+        (ApiMethod::Post, body) => {
+            client.post(path, &body.unwrap_or_else(|| Value::Object(Default::default()))).await?
+        }
+        (ApiMethod::Delete, None) => client.delete(path, &[]).await?,
+        (ApiMethod::Delete, Some(body)) => client.delete_with_body(path, &body).await?,
+    };
+    output_success(&response, out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::output_for;
+    use crate::output::OutputFormat;
+    use crate::client::tests::{mock_server, request_body};
+
+    #[tokio::test]
+    async fn test_call_sends_method_path_and_body() {
+        let ok = r#"{"status":"ok","result":{"done":true}}"#;
+        let (url, requests) = mock_server(vec![(200, ok), (200, ok)]).await;
+        let client = HttpClient::new(url, None);
+        call(&client, ApiMethod::Get, "/api/v1/whatever?x=1", None, &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        call(&client, ApiMethod::Post, "/api/v1/whatever", Some(r#"{"a":1}"#), &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /api/v1/whatever?x=1 "));
+        assert!(requests[1].starts_with("POST /api/v1/whatever "));
+        assert_eq!(request_body(&requests[1]), serde_json::json!({"a": 1}));
+    }
+
+    #[tokio::test]
+    async fn test_call_rejects_bad_input_locally() {
+        let client = HttpClient::new("http://127.0.0.1:1", None);
+        let bad_json = call(&client, ApiMethod::Post, "/api/v1/x", Some("{a"), &output_for(OutputFormat::Json)).await;
+        assert!(matches!(bad_json, Err(Error::Client(msg)) if msg.contains("not valid JSON")));
+        let get_body = call(&client, ApiMethod::Get, "/api/v1/x", Some("{}"), &output_for(OutputFormat::Json)).await;
+        assert!(matches!(get_body, Err(Error::Client(_))));
+        let relative = call(&client, ApiMethod::Get, "api/v1/x", None, &output_for(OutputFormat::Json)).await;
+        assert!(matches!(relative, Err(Error::Client(_))));
+    }
+}
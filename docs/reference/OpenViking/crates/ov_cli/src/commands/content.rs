@@ -1,36 +1,278 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::OutputFormat;
+use crate::error::{Error, Result};
+use crate::output::{content_is_verbatim, output_content, output_success, Output};
 
+/// Print L2 content, streaming it straight to the results when it needs no post-processing
 pub async fn read(
     client: &HttpClient,
     uri: &str,
-    _output_format: OutputFormat,
-    _compact: bool,
+    out: &Output,
 ) -> Result<()> {
-    let content = client.read(uri).await?;
-    println!("{}", content);
+    if !content_is_verbatim(out) {
+        let content = client.read(uri).await?;
+        return output_content(&content, out);
+    }
+    let mut w = out.writer();
+    client.read_stream(uri, &mut w).await?;
+    w.flush()?;
     Ok(())
 }
 
 pub async fn abstract_content(
     client: &HttpClient,
     uri: &str,
-    _output_format: OutputFormat,
-    _compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let content = client.abstract_content(uri).await?;
-    println!("{}", content);
-    Ok(())
+    output_content(&content, out)
 }
 
 pub async fn overview(
     client: &HttpClient,
     uri: &str,
-    _output_format: OutputFormat,
-    _compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let content = client.overview(uri).await?;
-    println!("{}", content);
+    output_content(&content, out)
+}
+
+/// Open the content of `uri` in `$VISUAL`/`$EDITOR` and write it back if it changed.
+///
+/// The write is conditional on the version (ETag) read, so a concurrent change is never
+/// clobbered; on a conflict the user may re-read the latest content and edit again. A
+/// server that sends no ETag gives nothing to check against, so that is refused unless
+/// `force`. Writing needs `PUT /api/v1/content`: a server whose OpenAPI document lacks it is
+/// refused before the editor opens, and if the write fails anyway the edited file is
+/// kept and its path printed.
+pub async fn edit(
+    client: &HttpClient,
+    uri: &str,
+    force: bool,
+    out: &Output,
+) -> Result<()> {
+    edit_with(client, uri, force, out, run_editor).await
+}
+
+async fn edit_with(
+    client: &HttpClient,
+    uri: &str,
+    force: bool,
+    out: &Output,
+    editor: impl Fn(&Path) -> Result<()>,
+) -> Result<()> {
+    loop {
+        let (original, version) = client.read_versioned(uri).await?;
+        if client.has_endpoint("put", "/api/v1/content").await == Some(false) {
+            return Err(Error::Api(
+                "[UNSUPPORTED] server has no PUT /api/v1/content, so edits cannot be saved".to_string(),
+            ));
+        }
+        if version.is_none() && !force {
+            return Err(Error::Client(format!(
+                "server sent no version (ETag) for {}, so a concurrent change could be overwritten; pass --force to edit anyway",
+                uri
+            )));
+        }
+        let (file, edited) = edit_in_editor(uri, &original, &editor)?;
+
+        if content_hash(&edited) == content_hash(&original) {
+            output_success(serde_json::json!({ "uri": uri, "changed": false }), out);
+            return Ok(());
+        }
+
+        match client.write_content(uri, &edited, version.as_deref()).await {
+            Ok(result) => {
+                let result = if result.is_null() {
+                    serde_json::json!({ "uri": uri, "changed": true })
+                } else {
+                    result
+                };
+                output_success(&result, out);
+                return Ok(());
+            }
+            Err(Error::Conflict(msg)) => {
+                eprintln!("{} changed since you read it; your edit was not saved.", uri);
+                if !confirm("Re-read the latest content and edit again? [y/N] ") {
+                    keep_edit(file);
+                    return Err(Error::Conflict(msg));
+                }
+            }
+            Err(e) => {
+                keep_edit(file);
+                return Err(e);
+            }
+        }
+    }
+}
+
+/// Write `content` to a new temp file with a random name, run `editor` on it and return
+/// the file with its edited content. The file is deleted when dropped.
+fn edit_in_editor(
+    uri: &str,
+    content: &str,
+    editor: impl Fn(&Path) -> Result<()>,
+) -> Result<(tempfile::TempPath, String)> {
+    let extension = uri
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext)
+        .unwrap_or("txt");
+    let mut file = tempfile::Builder::new()
+        .prefix("ov-edit-")
+        .suffix(&format!(".{}", extension))
+        .tempfile()?;
+    file.write_all(content.as_bytes())?;
+    let path = file.into_temp_path();
+
+    editor(&path)?;
+    let edited = std::fs::read_to_string(&path)?;
+    Ok((path, edited))
+}
+
+/// Keep an edit that could not be saved, so it is not lost
+fn keep_edit(file: tempfile::TempPath) {
+    if let Ok(path) = file.keep() {
+        eprintln!("Your edit is kept in {}", path.display());
+    }
+}
+
+/// Ask a yes/no question on the terminal; anything but "y"/"yes" (or no TTY) is no
+pub(crate) fn confirm(prompt: &str) -> bool {
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+    eprint!("{}", prompt);
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+fn run_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    // Allow editors with arguments, e.g. `code --wait`
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .map_err(|e| Error::Client(format!("Failed to launch editor '{}': {}", editor, e)))?;
+    if !status.success() {
+        return Err(Error::Client(format!("Editor '{}' exited with {}; nothing written", editor, status)));
+    }
     Ok(())
 }
+
+fn content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{mock_server, request_body};
+    use crate::output::tests::output_for;
+    use crate::output::{OutputFormat, ResultSink};
+
+    #[tokio::test]
+    async fn test_read_writes_enveloped_content_verbatim() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":"line one\nline two"}"#),
+            (404, r#"{"status":"error","error":{"code":"NOT_FOUND","message":"gone"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None).with_rate_limit(Some(1024));
+        let path = std::env::temp_dir().join(format!("ov-read-{}.txt", std::process::id()));
+        let out = output_for(OutputFormat::Table).with_sink(ResultSink::file(&path).unwrap());
+        read(&client, "viking://resources/a.md", &out).await.unwrap();
+        out.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "line one\nline two");
+        std::fs::remove_file(&path).unwrap();
+        assert!(requests.lock().unwrap()[0].starts_with("GET /api/v1/content/read?uri=viking%3A%2F%2Fresources%2Fa.md "));
+
+        let err = read(&client, "viking://resources/b.md", &output_for(OutputFormat::Table)).await.unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.starts_with("[NOT_FOUND]")));
+    }
+
+    const OPENAPI_WITH_PUT: &str = r#"{"openapi":"3.1.0","paths":{"/api/v1/content":{"put":{}}}}"#;
+
+    #[tokio::test]
+    async fn test_edit_saves_changed_content() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":"hello"}"#),
+            (200, OPENAPI_WITH_PUT),
+            (200, r#"{"status":"ok","result":null}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let append = |path: &Path| -> Result<()> {
+            let mut file = std::fs::OpenOptions::new().append(true).open(path)?;
+            Ok(file.write_all(b" world")?)
+        };
+        edit_with(&client, "viking://resources/a.md", true, &output_for(OutputFormat::Table), append)
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[2].starts_with("PUT /api/v1/content "));
+        assert_eq!(
+            request_body(&requests[2]),
+            serde_json::json!({ "uri": "viking://resources/a.md", "content": "hello world" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_refuses_before_editing_without_write_endpoint() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":"hello"}"#),
+            (200, r#"{"openapi":"3.1.0","paths":{"/api/v1/content/read":{"get":{}}}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let err = edit_with(&client, "viking://resources/a.md", true, &output_for(OutputFormat::Table), |_: &Path| {
+            panic!("the editor must not open")
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.starts_with("[UNSUPPORTED]")));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_edit_without_version_needs_force() {
+        let (url, _requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":"hello"}"#),
+            (200, OPENAPI_WITH_PUT),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let err = edit_with(&client, "viking://resources/a.md", false, &output_for(OutputFormat::Table), |_: &Path| {
+            panic!("the editor must not open")
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg.contains("--force")));
+    }
+
+    #[test]
+    fn test_edit_temp_file_is_unique() {
+        let (a, _) = edit_in_editor("viking://resources/a.md", "x", |_: &Path| Ok(())).unwrap();
+        let (b, _) = edit_in_editor("viking://resources/a.md", "x", |_: &Path| Ok(())).unwrap();
+        assert_ne!(a.to_path_buf(), b.to_path_buf());
+        assert!(a.to_string_lossy().ends_with(".md"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::Write;
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::output::{output_success, Output};
+use serde_json::{json, Value};
+use similar::{DiffOp, TextDiff};
+
+/// Compare the content of two URIs.
+///
+/// Both are read concurrently. Machine formats get `{added, removed, changed}` line
+/// arrays; otherwise a unified diff with `context` lines around each change is printed,
+/// and nothing at all when the contents match.
+pub async fn diff(
+    client: &HttpClient,
+    left_uri: &str,
+    right_uri: &str,
+    context: usize,
+    out: &Output,
+) -> Result<()> {
+    let (left, right) = tokio::join!(read_named(client, left_uri), read_named(client, right_uri));
+    let (left, right) = (left?, right?);
+    if out.format.is_json() {
+        output_success(line_changes(&left, &right), out);
+    } else {
+        write!(out.writer(), "{}", unified_diff(&left, &right, left_uri, right_uri, context))?;
+    }
+    Ok(())
+}
+
+/// Read `uri`, naming it in the error so it is clear which side failed
+async fn read_named(client: &HttpClient, uri: &str) -> Result<String> {
+    client.read(uri).await.map_err(|e| match e {
+        Error::Api(msg) => Error::Api(format!("{}: {}", uri, msg)),
+        other => other,
+    })
+}
+
+fn unified_diff(left: &str, right: &str, left_name: &str, right_name: &str, context: usize) -> String {
+    TextDiff::from_lines(left, right)
+        .unified_diff()
+        .context_radius(context)
+        .header(left_name, right_name)
+        .to_string()
+}
+
+/// Added, removed and changed lines; a replaced block pairs old and new lines up as
+/// `{old, new}` changes and lists any surplus as plain additions or removals
+fn line_changes(left: &str, right: &str) -> Value {
+    let old: Vec<&str> = left.lines().collect();
+    let new: Vec<&str> = right.lines().collect();
+    let (mut added, mut removed, mut changed) = (Vec::new(), Vec::new(), Vec::new());
+    for op in similar::capture_diff_slices(similar::Algorithm::Myers, &old, &new) {
+        match op {
+            DiffOp::Equal { .. } => {}
+            DiffOp::Delete { old_index, old_len, .. } => {
+                removed.extend_from_slice(&old[old_index..old_index + old_len]);
+            }
+            DiffOp::Insert { new_index, new_len, .. } => {
+                added.extend_from_slice(&new[new_index..new_index + new_len]);
+            }
+            DiffOp::Replace { old_index, old_len, new_index, new_len } => {
+                let paired = old_len.min(new_len);
+                for i in 0..paired {
+                    changed.push(json!({ "old": old[old_index + i], "new": new[new_index + i] }));
+                }
+                removed.extend_from_slice(&old[old_index + paired..old_index + old_len]);
+                added.extend_from_slice(&new[new_index + paired..new_index + new_len]);
+            }
+        }
+    }
+    json!({ "added": added, "removed": removed, "changed": changed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_changes() {
+        let left = "a\nb\nc\nd\n";
+        let right = "a\nB\nc\ne\nf\n";
+        assert_eq!(
+            line_changes(left, right),
+            json!({
+                "added": ["f"],
+                "removed": [],
+                "changed": [{"old": "b", "new": "B"}, {"old": "d", "new": "e"}]
+            })
+        );
+        assert_eq!(
+            line_changes("same\n", "same\n"),
+            json!({ "added": [], "removed": [], "changed": [] })
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_context() {
+        let left = "1\n2\n3\n4\n5\n";
+        let right = "1\n2\nthree\n4\n5\n";
+        assert_eq!(
+            unified_diff(left, right, "viking://a", "viking://b", 0),
+            "--- viking://a\n+++ viking://b\n@@ -3 +3 @@\n-3\n+three\n"
+        );
+        assert!(unified_diff(left, right, "a", "b", 3).contains("\n 1\n 2\n-3\n+three\n 4\n 5\n"));
+        assert_eq!(unified_diff(left, left, "a", "b", 3), "");
+    }
+}
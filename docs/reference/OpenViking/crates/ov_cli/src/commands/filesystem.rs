@@ -1,7 +1,32 @@
-use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use std::io::IsTerminal;
+use std::sync::Arc;
 
+use crate::client::{HttpClient, Page};
+use crate::commands::content::confirm;
+use crate::error::{Error, Result};
+use crate::index::Index;
+use crate::output::{human_size, output_line, output_preview, output_success, sort_listing, Output, OutputFormat, SortKey, SortSpec};
+use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// How `ls` pages through a listing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Paging {
+    /// One request capped at `node_limit`
+    Off,
+    /// A single 1-based page
+    Page { number: u64, size: u64 },
+    /// Every page, until one comes back short (at most `MAX_PAGES`)
+    All { size: u64 },
+    /// Skip the first `offset` entries client-side, then show up to `node_limit`
+    Offset { offset: u64 },
+}
+
+/// Most pages `Paging::All` fetches before giving up on reaching the end
+const MAX_PAGES: u64 = 1000;
+
+#[allow(clippy::too_many_arguments)]
 pub async fn ls(
     client: &HttpClient,
     uri: &str,
@@ -11,14 +36,141 @@ pub async fn ls(
     abs_limit: i32,
     show_all_hidden: bool,
     node_limit: i32,
-    output_format: OutputFormat,
-    compact: bool,
+    paging: Paging,
+    sort: Option<SortSpec>,
+    reverse: bool,
+    natural: bool,
+    offline: bool,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.ls(uri, simple, recursive, output, abs_limit, show_all_hidden, node_limit).await?;
-    output_success(&result, output_format, compact);
+    let fetch = |node_limit: i32, page: Option<Page>| {
+        client.ls(uri, simple, recursive, output, abs_limit, show_all_hidden, node_limit, page)
+    };
+    let mut result = if offline {
+        let index = Index::load()?;
+        index.warn_if_stale(out);
+        serde_json::Value::Array(index.ls(uri, recursive)?)
+    } else {
+        match paging {
+            Paging::Off => {
+                let result = fetch(node_limit, None).await?;
+                if result.as_array().is_some_and(|items| node_limit > 0 && items.len() >= node_limit as usize) {
+                    out.warn(format!(
+                        "results truncated at {} entries; use --offset {} to continue",
+                        node_limit, node_limit
+                    ));
+                }
+                result
+            }
+            Paging::Page { number, size } => {
+                let result = fetch(node_limit, Some(Page::numbered(number, size))).await?;
+                if result.as_array().is_some_and(|items| items.len() as u64 > size) {
+                    out.warn("server ignored the page offset and size; showing its whole listing".to_string());
+                }
+                result
+            }
+            Paging::All { size } => {
+                let mut all = Vec::new();
+                let mut previous: Option<Vec<Value>> = None;
+                for number in 1..=MAX_PAGES {
+                    let page = fetch(node_limit, Some(Page::numbered(number, size))).await?;
+                    let items = page.as_array().cloned().unwrap_or_default();
+                    // A server without paging returns the same entries for every page
+                    if !items.is_empty() && previous.as_ref() == Some(&items) {
+                        out.warn(format!("server ignored the page offset (page {} repeats page {})", number, number - 1));
+                        break;
+                    }
+                    if items.len() as u64 > size {
+                        out.warn("server ignored the page size; showing its whole listing".to_string());
+                        all.extend(items);
+                        break;
+                    }
+                    let done = (items.len() as u64) < size;
+                    all.extend(items.iter().cloned());
+                    if done {
+                        break;
+                    }
+                    if number == MAX_PAGES {
+                        out.warn(format!("stopped after {} pages; the listing may continue", MAX_PAGES));
+                    }
+                    previous = Some(items);
+                }
+                Value::Array(all)
+            }
+            Paging::Offset { offset } => {
+                // Ask for everything up to the end of the window, then slice it below
+                let wanted = offset.saturating_add(node_limit.max(0) as u64);
+                let result = fetch(i32::try_from(wanted).unwrap_or(i32::MAX), None).await?;
+                if result.as_array().is_some_and(|items| items.len() as u64 >= wanted) {
+                    out.warn(format!(
+                        "server returned its limit of {} entries, so offset paging may be incomplete",
+                        wanted
+                    ));
+                }
+                result
+            }
+        }
+    };
+    if let Some(items) = result.as_array_mut() {
+        normalize_types(items);
+        if sort.is_some() || natural || reverse {
+            sort_listing(items, sort.as_ref().unwrap_or(&SortSpec::Key(SortKey::Path)), natural, reverse);
+        }
+    }
+    let mut footer = None;
+    if let (Paging::Offset { offset }, Some(items)) = (paging, result.as_array_mut()) {
+        footer = Some(offset_window(items, offset, node_limit.max(0) as usize));
+    }
+    // JSON lines keep one entry per line, so only the single-document format is wrapped
+    if let (Paging::Page { number, size }, OutputFormat::Json, false) = (paging, out.format, offline) {
+        result = json!({ "page": number, "page_size": size, "items": result });
+    }
+    output_success(&result, out);
+    if let (Some(footer), OutputFormat::Table) = (footer, out.format) {
+        output_line(&footer, out);
+    }
     Ok(())
 }
 
+/// Keep `limit` entries starting at `offset`, returning a "showing 101-200 of N" footer
+fn offset_window(items: &mut Vec<Value>, offset: u64, limit: usize) -> String {
+    let total = items.len();
+    let start = usize::try_from(offset).unwrap_or(usize::MAX).min(total);
+    items.drain(..start);
+    items.truncate(limit);
+    if items.is_empty() {
+        format!("showing 0 of {}", total)
+    } else {
+        format!("showing {}-{} of {}", start + 1, start + items.len(), total)
+    }
+}
+
+/// Give every entry object a `type` of `"dir"` or `"file"`.
+///
+/// The server only includes `type` in some output modes, so when it is missing it is
+/// derived from `isDir`, `is_leaf`, a `children` list, or a trailing slash on the URI.
+pub fn normalize_types(items: &mut [Value]) {
+    for item in items {
+        let Some(obj) = item.as_object_mut() else {
+            continue;
+        };
+        if obj.get("type").is_some_and(|t| t.is_string()) {
+            continue;
+        }
+        let is_dir = obj
+            .get("isDir")
+            .and_then(|v| v.as_bool())
+            .or_else(|| obj.get("is_leaf").and_then(|v| v.as_bool()).map(|leaf| !leaf))
+            .unwrap_or_else(|| {
+                obj.get("children").is_some_and(|c| c.is_array())
+                    || obj.get("uri").and_then(|u| u.as_str()).is_some_and(|u| u.ends_with('/'))
+            });
+        let kind = if is_dir { "dir" } else { "file" };
+        obj.insert("type".to_string(), Value::String(kind.to_string()));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn tree(
     client: &HttpClient,
     uri: &str,
@@ -26,56 +178,610 @@ pub async fn tree(
     abs_limit: i32,
     show_all_hidden: bool,
     node_limit: i32,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let result = client.tree(uri, output, abs_limit, show_all_hidden, node_limit).await?;
-    output_success(&result, output_format, compact);
+    output_success(&result, out);
     Ok(())
 }
 
 pub async fn mkdir(
     client: &HttpClient,
     uri: &str,
-    _output_format: OutputFormat,
-    _compact: bool,
+    out: &Output,
 ) -> Result<()> {
     client.mkdir(uri).await?;
-    println!("Directory created: {}", uri);
+    output_line(&format!("Directory created: {}", uri), out);
     Ok(())
 }
 
+pub async fn touch(
+    client: &HttpClient,
+    uri: &str,
+    out: &Output,
+) -> Result<()> {
+    client.touch(uri).await?;
+    output_line(&format!("Created: {}", uri), out);
+    Ok(())
+}
+
+/// Remove `uri`. A recursive removal is confirmed on the terminal unless `yes`, and
+/// refused without `yes` when stdout is not a terminal.
 pub async fn rm(
     client: &HttpClient,
     uri: &str,
     recursive: bool,
-    _output_format: OutputFormat,
-    _compact: bool,
+    yes: bool,
+    out: &Output,
 ) -> Result<()> {
+    if client.is_dry_run() {
+        output_preview(HttpClient::rm_request(uri, recursive).preview(), out);
+        return Ok(());
+    }
+    if recursive && !yes {
+        confirm_recursive(uri, std::io::stdout().is_terminal(), confirm)?;
+    }
     client.rm(uri, recursive).await?;
-    println!("Removed: {}", uri);
+    output_line(&format!("Removed: {}", uri), out);
     Ok(())
 }
 
+fn confirm_recursive(uri: &str, terminal: bool, ask: impl FnOnce(&str) -> bool) -> Result<()> {
+    if !terminal {
+        return Err(Error::Client(format!(
+            "Not removing {} recursively without a terminal to confirm; pass --yes",
+            uri
+        )));
+    }
+    if !ask(&format!("Recursively remove {}? [y/N] ", uri)) {
+        return Err(Error::Client(format!(
+            "Not removing {} without confirmation; pass --yes to skip it",
+            uri
+        )));
+    }
+    Ok(())
+}
+
+/// Remove every URI under `uri` matching `pattern`, `concurrency` requests at a time.
+///
+/// The matches are listed and confirmed first unless `force`. Each removal is reported
+/// on its own and a failure does not stop the rest; the command fails if any did.
+#[allow(clippy::too_many_arguments)]
+pub async fn rm_glob(
+    client: &HttpClient,
+    uri: &str,
+    pattern: &str,
+    recursive: bool,
+    force: bool,
+    concurrency: usize,
+    out: &Output,
+) -> Result<()> {
+    // The glob lookup only reads, so a dry run still sends it to know what would be removed
+    let result = if client.is_dry_run() {
+        client.clone().with_dry_run(false).glob(pattern, uri).await?
+    } else {
+        client.glob(pattern, uri).await?
+    };
+    let targets: Vec<String> = result
+        .get("matches")
+        .and_then(|m| m.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|m| m.as_str().or_else(|| m.get("uri").and_then(|u| u.as_str())))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    if targets.is_empty() {
+        eprintln!("No resources under {} match {}", uri, pattern);
+        return Ok(());
+    }
+    if client.is_dry_run() {
+        let plan: Vec<Value> = targets
+            .iter()
+            .map(|target| HttpClient::rm_request(target, recursive).preview())
+            .collect();
+        output_preview(Value::Array(plan), out);
+        return Ok(());
+    }
+    if !force {
+        for target in &targets {
+            eprintln!("  {}", target);
+        }
+        if !confirm(&format!("Remove these {} resources? [y/N] ", targets.len())) {
+            return Err(Error::Client(format!(
+                "Not removing {} resources without confirmation; pass --force to skip it",
+                targets.len()
+            )));
+        }
+    }
+
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    for (index, target) in targets.iter().cloned().enumerate() {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            (index, client.rm(&target, recursive).await)
+        });
+    }
+    let mut outcomes: Vec<Option<Result<()>>> = targets.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, outcome)) = joined {
+            outcomes[index] = Some(outcome);
+        }
+    }
+
+    let mut failed = 0;
+    let rows: Vec<Value> = targets
+        .iter()
+        .zip(outcomes)
+        .map(|(target, outcome)| match outcome {
+            Some(Ok(())) => json!({ "uri": target, "status": "removed", "error": null }),
+            Some(Err(e)) => {
+                failed += 1;
+                json!({ "uri": target, "status": "failed", "error": e.to_string() })
+            }
+            None => {
+                failed += 1;
+                json!({ "uri": target, "status": "failed", "error": "task did not complete" })
+            }
+        })
+        .collect();
+    output_success(&rows, out);
+    if failed > 0 {
+        return Err(Error::Client(format!("{} of {} removals failed", failed, targets.len())));
+    }
+    Ok(())
+}
+
+/// Move `from_uri` to `to_uri`. With `no_overwrite` an existing target aborts the move;
+/// with `interactive` it asks first. The existence check and the move are separate
+/// requests, so this is not atomic: a target created in between is still overwritten.
 pub async fn mv(
     client: &HttpClient,
     from_uri: &str,
     to_uri: &str,
-    _output_format: OutputFormat,
-    _compact: bool,
+    no_overwrite: bool,
+    interactive: bool,
+    out: &Output,
 ) -> Result<()> {
+    // The target check is a request too, so a dry run previews the move without it
+    if client.is_dry_run() {
+        output_preview(HttpClient::mv_request(from_uri, to_uri).preview(), out);
+        return Ok(());
+    }
+    if (no_overwrite || interactive) && target_exists(client, to_uri).await? {
+        if no_overwrite {
+            return Err(Error::Conflict(format!(
+                "{} already exists; not moving {} (--no-overwrite)",
+                to_uri, from_uri
+            )));
+        }
+        if !confirm_overwrite(to_uri, std::io::stdin().is_terminal(), confirm)? {
+            output_line(&format!("Not moved: {}", from_uri), out);
+            return Ok(());
+        }
+    }
     client.mv(from_uri, to_uri).await?;
-    println!("Moved: {} -> {}", from_uri, to_uri);
+    output_line(&format!("Moved: {} -> {}", from_uri, to_uri), out);
     Ok(())
 }
 
-pub async fn stat(
+/// Ask before `mv -i` replaces `to_uri`; without a terminal to ask on, refuse instead of
+/// silently moving nothing
+fn confirm_overwrite(to_uri: &str, terminal: bool, ask: impl FnOnce(&str) -> bool) -> Result<bool> {
+    if !terminal {
+        return Err(Error::Client(format!(
+            "{} already exists and there is no terminal to confirm overwriting it; pass --no-overwrite or drop -i",
+            to_uri
+        )));
+    }
+    Ok(ask(&format!("Overwrite {}? [y/N] ", to_uri)))
+}
+
+/// Copy `from_uri` to `to_uri`, refusing to overwrite an existing target unless `force`
+#[allow(clippy::too_many_arguments)]
+pub async fn cp(
+    client: &HttpClient,
+    from_uri: &str,
+    to_uri: &str,
+    recursive: bool,
+    vectorize: bool,
+    force: bool,
+    out: &Output,
+) -> Result<()> {
+    // The target and source checks are requests too, so a dry run previews the copy without them
+    if client.is_dry_run() {
+        output_preview(HttpClient::cp_request(from_uri, to_uri, recursive, vectorize, force).preview(), out);
+        return Ok(());
+    }
+    if !force && target_exists(client, to_uri).await? {
+        return Err(Error::Conflict(format!(
+            "{} already exists; pass --force to overwrite it",
+            to_uri
+        )));
+    }
+    if !recursive {
+        let source = client.stat(from_uri).await?;
+        if source.get("isDir").and_then(|d| d.as_bool()).unwrap_or(false) {
+            return Err(Error::Api(format!(
+                "{} is a directory; pass --recursive to copy it",
+                from_uri
+            )));
+        }
+    }
+    let result = client.cp(from_uri, to_uri, recursive, vectorize, force).await?;
+    // If the server returns null/empty, show a confirmation summary
+    if result.is_null() || result.as_object().is_some_and(|o| o.is_empty()) {
+        output_success(json!({ "from": from_uri, "to": to_uri }), out);
+    } else {
+        output_success(&result, out);
+    }
+    Ok(())
+}
+
+/// Whether `uri` exists, by `stat`; NOT_FOUND means no, any other error is passed on
+pub(crate) async fn target_exists(client: &HttpClient, uri: &str) -> Result<bool> {
+    match client.stat(uri).await {
+        Ok(_) => Ok(true),
+        Err(Error::Api(msg)) if msg.starts_with("[NOT_FOUND]") => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Report sizes under `uri`: one `uri`/`size` row per entry plus a total.
+///
+/// Table output shows sizes in KB/MB/GB with a final `total` row; other formats get
+/// `{entries, total}` with raw byte counts.
+pub async fn du(
     client: &HttpClient,
     uri: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    recursive: bool,
+    all: bool,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.stat(uri).await?;
-    output_success(&result, output_format, compact);
+    let result = client.du(uri, recursive, all).await?;
+    let (entries, total) = du_entries(uri, &result);
+    if out.format == OutputFormat::Table {
+        let mut rows: Vec<Value> = entries
+            .iter()
+            .map(|e| json!({ "uri": e["uri"], "size": human_size(e["size"].as_u64().unwrap_or(0)) }))
+            .collect();
+        rows.push(json!({ "uri": "total", "size": human_size(total) }));
+        output_success(&rows, out);
+    } else {
+        output_success(json!({ "entries": entries, "total": total }), out);
+    }
     Ok(())
 }
+
+/// `{uri, size}` rows and the total from a du response.
+///
+/// Accepts a bare list of entries, a list under `entries`, or a single `{uri, size}`
+/// object; the total is the server's `total` when present, else the sum of the rows.
+fn du_entries(uri: &str, result: &Value) -> (Vec<Value>, u64) {
+    let items = result
+        .as_array()
+        .or_else(|| result.get("entries").and_then(|e| e.as_array()))
+        .cloned()
+        .unwrap_or_else(|| vec![result.clone()]);
+    let entries: Vec<Value> = items
+        .iter()
+        .filter_map(|item| {
+            let size = item.get("size").and_then(|s| s.as_u64())?;
+            let entry_uri = item.get("uri").and_then(|u| u.as_str()).unwrap_or(uri);
+            Some(json!({ "uri": entry_uri, "size": size }))
+        })
+        .collect();
+    let total = result
+        .get("total")
+        .and_then(|t| t.as_u64())
+        .unwrap_or_else(|| entries.iter().filter_map(|e| e["size"].as_u64()).sum());
+    (entries, total)
+}
+
+/// Stat one URI, or several in a single batch request rendered one row per URI
+pub async fn stat(
+    client: &HttpClient,
+    uris: &[String],
+    out: &Output,
+) -> Result<()> {
+    if let [uri] = uris {
+        let result = client.stat(uri).await?;
+        output_success(&result, out);
+        return Ok(());
+    }
+    let mut results = client.stat_many(uris).await?;
+    for (result, uri) in results.iter_mut().zip(uris) {
+        if let Some(obj) = result.as_object_mut() {
+            if !obj.contains_key("uri") {
+                obj.insert("uri".to_string(), Value::String(uri.clone()));
+            }
+        }
+    }
+    output_success(&results, out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::output_for;
+    use crate::output::ResultSink;
+    use crate::client::tests::mock_server;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_cp_directory_requires_recursive() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{"isDir":true}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let err = cp(&client, "viking://resources/dir", "viking://resources/copy", false, true, true, &output_for(OutputFormat::Table))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.contains("--recursive")));
+        // Only the stat went out; no copy was attempted
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recursive_rm_needs_confirmation() {
+        assert!(matches!(
+            confirm_recursive("viking://r", false, |_| true),
+            Err(Error::Client(msg)) if msg.contains("--yes")
+        ));
+        assert!(confirm_recursive("viking://r", true, |prompt| prompt == "Recursively remove viking://r? [y/N] ").is_ok());
+        assert!(confirm_recursive("viking://r", true, |_| false).is_err());
+
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        rm(&client, "viking://r", true, true, &output_for(OutputFormat::Json)).await.unwrap();
+        assert!(requests.lock().unwrap()[0].starts_with("DELETE /api/v1/fs"));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_rm_and_mv_send_nothing() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{}}"#)]).await;
+        let client = HttpClient::new(url, None).with_dry_run(true);
+        rm(&client, "viking://resources/a", true, false, &output_for(OutputFormat::Json)).await.unwrap();
+        mv(&client, "viking://resources/a", "viking://resources/b", true, false, &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_cp_previews_the_copy() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{}}"#)]).await;
+        let client = HttpClient::new(url, None).with_dry_run(true);
+        let path = std::env::temp_dir().join(format!("ov-cp-dry-run-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        cp(&client, "viking://resources/a", "viking://resources/b", false, true, false, &out).await.unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(requests.lock().unwrap().is_empty());
+        assert_eq!(written["result"]["method"], "POST");
+        assert_eq!(written["result"]["path"], "/api/v1/fs/cp");
+        assert_eq!(written["result"]["body"]["to_uri"], "viking://resources/b");
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_rm_glob_previews_each_removal() {
+        let (url, requests) = mock_server(vec![(
+            200,
+            r#"{"status":"ok","result":{"matches":["viking://r/a.md","viking://r/b.md"],"count":2}}"#,
+        )])
+        .await;
+        let client = HttpClient::new(url, None).with_dry_run(true);
+        let path = std::env::temp_dir().join(format!("ov-rm-glob-dry-run-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        rm_glob(&client, "viking://r", "*.md", false, false, 4, &out).await.unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /api/v1/search/glob "));
+        let plan = written["result"].as_array().unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0]["method"], "DELETE");
+        assert_eq!(plan[1]["query"]["uri"], "viking://r/b.md");
+    }
+
+    #[tokio::test]
+    async fn test_rm_glob_reports_each_failure() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"matches":["viking://r/a.md","viking://r/b.md","viking://r/c.md"],"count":3}}"#),
+            (200, r#"{"status":"ok","result":{}}"#),
+            (404, r#"{"status":"error","error":{"code":"NOT_FOUND","message":"gone"}}"#),
+            (200, r#"{"status":"ok","result":{}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let err = rm_glob(&client, "viking://r", "*.md", false, true, 1, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg == "1 of 3 removals failed"));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[1..].iter().all(|r| r.starts_with("DELETE /api/v1/fs?")));
+    }
+
+    #[tokio::test]
+    async fn test_ls_all_pages_stops_on_short_page() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":[{"uri":"viking://r/a"},{"uri":"viking://r/b"}]}"#),
+            (200, r#"{"status":"ok","result":[{"uri":"viking://r/c"}]}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        ls(&client, "viking://r", false, false, "original", 256, false, 1000, Paging::All { size: 2 }, None, false, false, false, &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("offset=0&limit=2"));
+        assert!(requests[1].contains("offset=2&limit=2"));
+    }
+
+    #[tokio::test]
+    async fn test_ls_all_pages_stops_when_server_ignores_paging() {
+        let page = r#"{"status":"ok","result":[{"uri":"viking://r/a"},{"uri":"viking://r/b"}]}"#;
+        let (url, requests) = mock_server(vec![(200, page), (200, page), (200, page)]).await;
+        let client = HttpClient::new(url, None);
+        let out = output_for(OutputFormat::Json);
+        ls(&client, "viking://r", false, false, "original", 256, false, 1000, Paging::All { size: 2 }, None, false, false, false, &out)
+            .await
+            .unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert!(out.opts.warnings.emitted());
+
+        let whole = r#"{"status":"ok","result":[{"uri":"viking://r/a"},{"uri":"viking://r/b"},{"uri":"viking://r/c"}]}"#;
+        let (url, requests) = mock_server(vec![(200, whole), (200, whole)]).await;
+        let client = HttpClient::new(url, None);
+        let out = output_for(OutputFormat::Json);
+        ls(&client, "viking://r", false, false, "original", 256, false, 1000, Paging::All { size: 2 }, None, false, false, false, &out)
+            .await
+            .unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert!(out.opts.warnings.emitted());
+    }
+
+    #[test]
+    fn test_offset_window_slices_and_describes() {
+        let mut items: Vec<Value> = (0..5).map(|i| json!({"uri": format!("viking://r/{}", i)})).collect();
+        assert_eq!(offset_window(&mut items, 1, 2), "showing 2-3 of 5");
+        assert_eq!(items, vec![json!({"uri": "viking://r/1"}), json!({"uri": "viking://r/2"})]);
+
+        let mut items = vec![json!("viking://r/a")];
+        assert_eq!(offset_window(&mut items, 5, 10), "showing 0 of 1");
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ls_offset_requests_whole_window() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":[{"uri":"viking://r/a"}]}"#)]).await;
+        let client = HttpClient::new(url, None);
+        ls(&client, "viking://r", false, false, "original", 256, false, 20, Paging::Offset { offset: 100 }, None, false, false, false, &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        assert!(requests.lock().unwrap()[0].contains("node_limit=120"));
+    }
+
+    #[test]
+    fn test_du_entries_shapes() {
+        let listed = json!([{"uri": "viking://r/a", "size": 10}, {"uri": "viking://r/b", "size": 5}]);
+        let (entries, total) = du_entries("viking://r", &listed);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(total, 15);
+
+        let wrapped = json!({"entries": [{"uri": "viking://r/a", "size": 10}], "total": 99});
+        assert_eq!(du_entries("viking://r", &wrapped).1, 99);
+
+        let single = json!({"size": 7});
+        let (entries, total) = du_entries("viking://r", &single);
+        assert_eq!(entries, vec![json!({"uri": "viking://r", "size": 7})]);
+        assert_eq!(total, 7);
+    }
+
+    #[tokio::test]
+    async fn test_cp_existing_target_requires_force() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{"isDir":false}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let err = cp(&client, "viking://resources/a.md", "viking://resources/b.md", false, true, false, &output_for(OutputFormat::Table))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Conflict(msg) if msg.contains("--force")));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mv_no_overwrite_checks_target() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{"isDir":false}}"#)]).await;
+        let client = HttpClient::new(url, None);
+        let err = mv(&client, "viking://resources/a.md", "viking://resources/b.md", true, false, &output_for(OutputFormat::Table))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Conflict(msg) if msg.contains("--no-overwrite")));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let (url, requests) = mock_server(vec![
+            (404, r#"{"status":"error","error":{"code":"NOT_FOUND","message":"not found"}}"#),
+            (200, r#"{"status":"ok","result":{}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        mv(&client, "viking://resources/a.md", "viking://resources/b.md", true, false, &output_for(OutputFormat::Table))
+            .await
+            .unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].starts_with("POST /api/v1/fs/mv "));
+    }
+
+    #[test]
+    fn test_mv_interactive_needs_a_terminal() {
+        assert!(matches!(
+            confirm_overwrite("viking://b", false, |_| true),
+            Err(Error::Client(msg)) if msg.contains("--no-overwrite") && msg.contains("-i")
+        ));
+        assert!(confirm_overwrite("viking://b", true, |prompt| prompt == "Overwrite viking://b? [y/N] ").unwrap());
+        assert!(!confirm_overwrite("viking://b", true, |_| false).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cp_missing_target_proceeds() {
+        let (url, requests) = mock_server(vec![
+            (404, r#"{"status":"error","error":{"code":"NOT_FOUND","message":"not found"}}"#),
+            (200, r#"{"status":"ok","result":{"isDir":false}}"#),
+            (200, r#"{"status":"ok","result":{}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        cp(&client, "viking://resources/a.md", "viking://resources/b.md", false, true, false, &output_for(OutputFormat::Table))
+            .await
+            .unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_cp_json_output_is_a_result() {
+        let (url, _requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"isDir":false}}"#),
+            (200, r#"{"status":"ok","result":{}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let path = std::env::temp_dir().join(format!("ov-cp-json-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        cp(&client, "viking://resources/a.md", "viking://resources/b.md", false, true, true, &out)
+            .await
+            .unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written["result"], json!({ "from": "viking://resources/a.md", "to": "viking://resources/b.md" }));
+    }
+
+    #[test]
+    fn test_normalize_types_from_server_signals() {
+        let mut items = vec![
+            json!({"uri": "viking://a", "isDir": true}),
+            json!({"uri": "viking://b.md", "isDir": false}),
+            json!({"uri": "viking://c", "is_leaf": false}),
+            json!({"uri": "viking://d/"}),
+            json!({"uri": "viking://e", "children": []}),
+            json!({"uri": "viking://f"}),
+            json!({"uri": "viking://g", "type": "resource"}),
+            json!("viking://simple"),
+        ];
+        normalize_types(&mut items);
+        let types: Vec<&str> = items.iter().filter_map(|i| i.get("type")?.as_str()).collect();
+        assert_eq!(types, vec!["dir", "file", "dir", "dir", "dir", "file", "resource"]);
+        assert_eq!(items[7], json!("viking://simple"));
+    }
+}
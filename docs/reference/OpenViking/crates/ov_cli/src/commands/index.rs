@@ -0,0 +1,31 @@
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::index::Index;
+use crate::output::{output_success, Output};
+use serde_json::json;
+
+pub async fn refresh(
+    client: &HttpClient,
+    uri: &str,
+    node_limit: i32,
+    out: &Output,
+) -> Result<()> {
+    let result = client.ls(uri, false, true, "original", 256, false, node_limit, None).await?;
+    let entries = result
+        .as_array()
+        .cloned()
+        .ok_or_else(|| Error::Parse("Expected a list of entries from ls".to_string()))?;
+
+    let index = Index::new(uri, entries);
+    let path = index.save()?;
+    output_success(
+        json!({
+            "root": index.root,
+            "entries": index.entries.len(),
+            "refreshed_at": index.refreshed_at,
+            "path": path.to_string_lossy(),
+        }),
+        out,
+    );
+    Ok(())
+}
@@ -1,4 +1,6 @@
+pub mod api;
 pub mod content;
+pub mod diff;
 pub mod search;
 pub mod filesystem;
 pub mod observer;
@@ -7,3 +9,6 @@ pub mod system;
 pub mod resources;
 pub mod relations;
 pub mod pack;
+pub mod index;
+pub mod selftest;
+pub mod watch;
@@ -1,43 +1,239 @@
+use base64::Engine;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use crate::commands::system::queue_totals;
+use crate::error::{Error, Result};
+use crate::output::{output_line, output_success, Output, OutputFormat};
+
+/// 1x1 RGB PNG sent by `observer vlm --probe` when no image is given
+const PROBE_IMAGE: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90, 0x77, 0x53,
+    0xde, 0x00, 0x00, 0x00, 0x0c, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xdf, 0xc0, 0x00,
+    0x00, 0x04, 0x01, 0x01, 0x80, 0xc5, 0x2a, 0x18, 0x5d, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e,
+    0x44, 0xae, 0x42, 0x60, 0x82,
+];
+const PROBE_PROMPT: &str = "Describe this image in one short sentence.";
+/// Queue observer status when nothing has been queued yet
+const IDLE_QUEUE_STATUS: &str = "No queue status data available.";
 
 pub async fn queue(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/api/v1/observer/queue", &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
+/// Poll the queue observer every `interval` seconds, printing one reading per poll until
+/// Ctrl-C or `count` polls (0 for no limit).
+///
+/// Table output rewrites the previous line on a terminal; JSON output is one object per line.
+/// With `threshold`, stops with an error as soon as the queue depth (pending plus in
+/// progress) exceeds it. Network errors are reported as warnings and count as a poll, but
+/// the command fails if no poll reached the server; any other error stops the loop.
+pub async fn poll(
+    client: &HttpClient,
+    interval: f64,
+    count: u64,
+    threshold: Option<u64>,
+    out: &Output,
+) -> Result<()> {
+    let ndjson = out.format.is_json();
+    let lines = if out.format == OutputFormat::JsonlWrapped {
+        out.clone()
+    } else {
+        out.with_format(OutputFormat::Jsonl)
+    };
+    let overwrite = !ndjson && out.to_terminal();
+    let mut ticker = tokio::time::interval(Duration::from_secs_f64(interval));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut polls = 0;
+    let mut last_error: Option<String> = None;
+    let mut reached = false;
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = ticker.tick() => {}
+        }
+        let response: Result<Value> = tokio::select! {
+            _ = &mut ctrl_c => break,
+            result = client.get("/api/v1/observer/queue", &[]) => result,
+        };
+        polls += 1;
+        let response = match response {
+            Err(Error::Network(msg)) => {
+                out.warn(format!("poll {} failed: {}", polls, msg));
+                last_error = Some(msg);
+                if count > 0 && polls >= count {
+                    break;
+                }
+                continue;
+            }
+            other => other?,
+        };
+        reached = true;
+        let reading = queue_reading(&response)?;
+
+        if ndjson {
+            output_success(&reading, &lines);
+        } else {
+            if overwrite && polls > 1 {
+                let _ = write!(out.writer(), "\x1b[1A\x1b[2K");
+            }
+            output_line(&format!(
+                "{}  pending: {}  in progress: {}  depth: {}",
+                reading["timestamp"].as_str().unwrap_or_default(),
+                reading["pending"],
+                reading["in_progress"],
+                reading["depth"]
+            ), out);
+        }
+
+        let depth = reading["depth"].as_u64().unwrap_or(0);
+        if let Some(threshold) = threshold.filter(|&threshold| depth > threshold) {
+            return Err(Error::Client(format!(
+                "Queue depth {} exceeded threshold {}",
+                depth, threshold
+            )));
+        }
+        if count > 0 && polls >= count {
+            break;
+        }
+    }
+    match last_error {
+        Some(msg) if !reached => Err(Error::Network(format!("no poll reached the server: {}", msg))),
+        _ => Ok(()),
+    }
+}
+
+/// Timestamped pending/in-progress counts from a queue observer response; an idle
+/// queue reports no table, which reads as depth 0
+fn queue_reading(response: &Value) -> Result<Value> {
+    let status = response.get("status").and_then(Value::as_str);
+    let (pending, in_progress) = match status {
+        Some(status) if status.trim() == IDLE_QUEUE_STATUS => (0, 0),
+        _ => status
+            .and_then(queue_totals)
+            .ok_or_else(|| Error::Parse("Queue status has no TOTAL row".to_string()))?,
+    };
+    Ok(json!({
+        "timestamp": chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        "pending": pending,
+        "in_progress": in_progress,
+        "depth": pending + in_progress,
+    }))
+}
+
 pub async fn vikingdb(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/api/v1/observer/vikingdb", &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
 pub async fn vlm(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/api/v1/observer/vlm", &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
+    Ok(())
+}
+
+/// Send a sample image to the VLM and report the answer and round-trip latency
+pub async fn vlm_probe(
+    client: &HttpClient,
+    image: Option<&str>,
+    out: &Output,
+) -> Result<()> {
+    let (bytes, mime_type) = match image {
+        Some(path) => {
+            let bytes = std::fs::read(path)
+                .map_err(|e| Error::Client(format!("Failed to read image {}: {}", path, e)))?;
+            let mime = mime_guess::from_path(path).first_or_octet_stream();
+            (bytes, mime.essence_str().to_string())
+        }
+        None => (PROBE_IMAGE.to_vec(), "image/png".to_string()),
+    };
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+
+    let started = Instant::now();
+    let response = client.vlm_probe(&encoded, &mime_type, PROBE_PROMPT).await?;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    output_success(
+        json!({
+            "image": image.unwrap_or("(bundled sample)"),
+            "latency_ms": latency_ms,
+            "response": response,
+        }),
+        out,
+    );
     Ok(())
 }
 
 pub async fn system(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/api/v1/observer/system", &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::output_for;
+    use crate::client::tests::mock_server;
+
+    const QUEUE: &str = r#"{"status":"ok","result":{"name":"queue","status":"| Queue | Pending | In Progress | Processed |\n| TOTAL | 3 | 2 | 9 |"}}"#;
+
+    #[test]
+    fn test_queue_reading() {
+        let response: Value = serde_json::from_str(QUEUE).unwrap();
+        let reading = queue_reading(&response["result"]).unwrap();
+        assert_eq!(reading["pending"], 3);
+        assert_eq!(reading["in_progress"], 2);
+        assert_eq!(reading["depth"], 5);
+        assert!(matches!(queue_reading(&json!({"status": "idle"})), Err(Error::Parse(_))));
+
+        let idle = queue_reading(&json!({"name": "queue", "status": IDLE_QUEUE_STATUS})).unwrap();
+        assert_eq!(idle["depth"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_fails_when_every_poll_fails() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let client = HttpClient::new(url, None).with_retries(0);
+        let out = output_for(OutputFormat::Json);
+        let result = poll(&client, 0.01, 2, Some(5), &out).await;
+        assert!(matches!(result, Err(Error::Network(msg)) if msg.starts_with("no poll reached the server")));
+        assert!(out.opts.warnings.emitted());
+    }
+
+    #[tokio::test]
+    async fn test_poll_stops_after_count_and_on_threshold() {
+        let (url, requests) = mock_server(vec![(200, QUEUE), (200, QUEUE), (200, QUEUE)]).await;
+        let client = HttpClient::new(url, None);
+        poll(&client, 0.01, 2, Some(5), &output_for(OutputFormat::Json)).await.unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        let (url, requests) = mock_server(vec![(200, QUEUE), (200, QUEUE)]).await;
+        let client = HttpClient::new(url, None);
+        let result = poll(&client, 0.01, 0, Some(4), &output_for(OutputFormat::Json)).await;
+        assert!(matches!(result, Err(Error::Client(msg)) if msg.contains("exceeded threshold 4")));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+}
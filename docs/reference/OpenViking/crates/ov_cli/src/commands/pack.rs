@@ -1,32 +1,404 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use crate::commands::filesystem::target_exists;
+use crate::error::{Error, Result};
+use crate::output::{output_preview, output_success, Output};
+
+const GRAPH_VERSION: u32 = 1;
+/// Most nodes listed under the root by `export --relations-only`
+const RELATIONS_NODE_LIMIT: usize = 100_000;
+
+/// Relation graph written by `export --relations-only`
+#[derive(Debug, Serialize, Deserialize)]
+struct Graph {
+    version: u32,
+    /// URI the graph was exported from; edges under it are rebased on import
+    root: String,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Edge {
+    from: String,
+    to: String,
+    #[serde(default)]
+    reason: String,
+}
 
 pub async fn export(
     client: &HttpClient,
     uri: &str,
     to: &str,
-    format: OutputFormat,
-    compact: bool,
+    relations_only: bool,
+    out: &Output,
 ) -> Result<()> {
+    if relations_only {
+        return export_relations(client, uri, to, out).await;
+    }
     let result = client.export_ovpack(uri, to).await?;
-    output_success(&result, format, compact);
+    output_success(&result, out);
+    Ok(())
+}
+
+/// Write every relation whose source lies under `uri` to a local graph file
+async fn export_relations(
+    client: &HttpClient,
+    uri: &str,
+    to: &str,
+    out: &Output,
+) -> Result<()> {
+    let listing = client
+        .ls(uri, false, true, "original", 256, false, RELATIONS_NODE_LIMIT as i32, None)
+        .await?;
+    if listing.as_array().is_some_and(|entries| entries.len() >= RELATIONS_NODE_LIMIT) {
+        out.warn(format!(
+            "{} has at least {} nodes; relations of nodes past the limit were not exported",
+            uri, RELATIONS_NODE_LIMIT
+        ));
+    }
+    let mut sources = vec![uri.to_string()];
+    sources.extend(
+        listing
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.get("uri").and_then(|u| u.as_str()))
+            .map(|u| u.to_string()),
+    );
+
+    let mut edges = Vec::new();
+    for from in &sources {
+        let relations = client.relations(from).await?;
+        for relation in relations.as_array().into_iter().flatten() {
+            if let Some(target) = relation.get("uri").and_then(|u| u.as_str()) {
+                edges.push(Edge {
+                    from: from.clone(),
+                    to: target.to_string(),
+                    reason: relation.get("reason").and_then(|r| r.as_str()).unwrap_or("").to_string(),
+                });
+            }
+        }
+    }
+
+    let graph = Graph {
+        version: GRAPH_VERSION,
+        root: uri.to_string(),
+        edges,
+    };
+    std::fs::write(to, serde_json::to_string_pretty(&graph)?)?;
+    output_success(
+        json!({ "file": to, "nodes": sources.len(), "edges": graph.edges.len() }),
+        out,
+    );
     Ok(())
 }
 
+/// Recreate the links in a graph file, mapping its root onto `target`.
+///
+/// Edges whose endpoints do not exist on the server are skipped and reported; any other
+/// failure to check an endpoint aborts the import.
+async fn import_relations(
+    client: &HttpClient,
+    file_path: &str,
+    target: &str,
+    out: &Output,
+) -> Result<()> {
+    let content = std::fs::read_to_string(file_path)?;
+    let graph: Graph = serde_json::from_str(&content)
+        .map_err(|e| Error::Parse(format!("Invalid relations graph {}: {}", file_path, e)))?;
+    if graph.version > GRAPH_VERSION {
+        return Err(Error::Parse(format!(
+            "Unsupported relations graph version {}",
+            graph.version
+        )));
+    }
+
+    let mut exists: std::collections::HashMap<String, bool> = std::collections::HashMap::new();
+    let mut linked = 0;
+    let mut skipped = Vec::new();
+    for edge in graph.edges {
+        let edge = Edge {
+            from: rebase_uri(&edge.from, &graph.root, target),
+            to: rebase_uri(&edge.to, &graph.root, target),
+            reason: edge.reason,
+        };
+        let mut missing = Vec::new();
+        for uri in [&edge.from, &edge.to] {
+            if !exists.contains_key(uri) {
+                exists.insert(uri.clone(), target_exists(client, uri).await?);
+            }
+            if !exists[uri] {
+                missing.push(uri.clone());
+            }
+        }
+        if !missing.is_empty() {
+            skipped.push(json!({ "from": edge.from, "to": edge.to, "missing": missing }));
+            continue;
+        }
+        client.link(&edge.from, std::slice::from_ref(&edge.to), &edge.reason).await?;
+        linked += 1;
+    }
+
+    output_success(json!({ "linked": linked, "skipped": skipped }), out);
+    Ok(())
+}
+
+/// Move `uri` from under `root` to under `target`; URIs outside `root` are unchanged
+fn rebase_uri(uri: &str, root: &str, target: &str) -> String {
+    let root = root.trim_end_matches('/');
+    let target = target.trim_end_matches('/');
+    match uri.strip_prefix(root) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => format!("{}{}", target, rest),
+        _ => uri.to_string(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn import(
     client: &HttpClient,
     file_path: &str,
     target: &str,
     force: bool,
     no_vectorize: bool,
-    format: OutputFormat,
-    compact: bool,
+    into_new: Option<&str>,
+    relations_only: bool,
+    out: &Output,
 ) -> Result<()> {
+    if relations_only {
+        return import_relations(client, file_path, target, out).await;
+    }
     let vectorize = !no_vectorize;
-    let result = client
-        .import_ovpack(file_path, target, force, vectorize)
-        .await?;
-    output_success(&result, format, compact);
+
+    // --into-new: import into a freshly created child so existing content is never merged
+    let created_uri = match into_new {
+        Some(name) => {
+            let name = if name.is_empty() {
+                default_subtree_name(file_path)
+            } else {
+                name.to_string()
+            };
+            let uri = child_uri(target, &name);
+            // Checking and creating the child are requests too, so a dry run previews the plan
+            if client.is_dry_run() {
+                output_preview(
+                    json!([
+                        HttpClient::mkdir_request(&uri).preview(),
+                        HttpClient::import_ovpack_request(file_path, &uri, force, vectorize).preview(),
+                    ]),
+                    out,
+                );
+                return Ok(());
+            }
+            if target_exists(client, &uri).await? {
+                return Err(Error::Client(format!("Target already exists: {}", uri)));
+            }
+            client.mkdir(&uri).await?;
+            Some(uri)
+        }
+        None => None,
+    };
+    let parent = created_uri.as_deref().unwrap_or(target);
+
+    let result = match client.import_ovpack(file_path, parent, force, vectorize).await {
+        Ok(result) => result,
+        Err(e) => {
+            // Don't leave the empty --into-new directory behind
+            if let Some(uri) = &created_uri {
+                if let Err(cleanup) = client.rm(uri, true).await {
+                    out.warn(format!("could not remove {} after the failed import: {}", uri, cleanup));
+                }
+            }
+            return Err(e);
+        }
+    };
+
+    match created_uri {
+        Some(uri) => {
+            let result = match result {
+                Value::Object(mut obj) => {
+                    obj.insert("created_uri".to_string(), Value::String(uri));
+                    Value::Object(obj)
+                }
+                other => json!({ "created_uri": uri, "result": other }),
+            };
+            output_success(&result, out);
+        }
+        None => output_success(&result, out),
+    }
     Ok(())
 }
+
+/// Name for an `--into-new` subtree: pack file stem plus a timestamp to keep it unique
+fn default_subtree_name(file_path: &str) -> String {
+    let stem = std::path::Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| !s.is_empty())
+        .unwrap_or("import");
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("{}-{}", stem, timestamp)
+}
+
+fn child_uri(parent: &str, name: &str) -> String {
+    if parent.ends_with('/') {
+        format!("{}{}", parent, name)
+    } else {
+        format!("{}/{}", parent, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{mock_server, request_body};
+    use crate::output::tests::output_for;
+    use crate::output::{OutputFormat, ResultSink};
+
+    const NOT_FOUND: &str = r#"{"status":"error","error":{"code":"NOT_FOUND","message":"not found"}}"#;
+
+    #[tokio::test]
+    async fn test_import_into_new_creates_child() {
+        let (url, requests) = mock_server(vec![
+            (404, NOT_FOUND),
+            (200, r#"{"status":"ok","result":null}"#),
+            (200, r#"{"status":"ok","result":{"root_uri":"viking://resources/pack/x"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        import(&client, "/tmp/x.ovpack", "viking://resources", false, false, Some("pack"), false, &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[1].starts_with("POST /api/v1/fs/mkdir "));
+        assert_eq!(request_body(&requests[2])["parent"], "viking://resources/pack");
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_import_into_new_previews_the_plan() {
+        let (url, requests) = mock_server(vec![(200, r#"{"status":"ok","result":{}}"#)]).await;
+        let client = HttpClient::new(url, None).with_dry_run(true);
+        let path = std::env::temp_dir().join(format!("ov-import-dry-run-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        import(&client, "/tmp/x.ovpack", "viking://resources", false, false, Some("pack"), false, &out)
+            .await
+            .unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(requests.lock().unwrap().is_empty());
+        let plan = written["result"].as_array().unwrap();
+        assert_eq!(plan[0]["path"], "/api/v1/fs/mkdir");
+        assert_eq!(plan[0]["body"]["uri"], "viking://resources/pack");
+        assert_eq!(plan[1]["path"], "/api/v1/pack/import");
+        assert_eq!(plan[1]["body"]["parent"], "viking://resources/pack");
+    }
+
+    #[tokio::test]
+    async fn test_import_into_new_removes_child_on_failure() {
+        let (url, requests) = mock_server(vec![
+            (404, NOT_FOUND),
+            (200, r#"{"status":"ok","result":null}"#),
+            (400, r#"{"status":"error","error":{"code":"INVALID_ARGUMENT","message":"bad pack"}}"#),
+            (200, r#"{"status":"ok","result":null}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let err = import(&client, "/tmp/x.ovpack", "viking://resources", false, false, Some("pack"), false, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Api(msg) if msg.contains("bad pack")));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[3].starts_with("DELETE /api/v1/fs?"));
+        assert!(requests[3].contains("recursive=true"));
+    }
+
+    /// Write a two-edge graph rooted at `viking://resources/old` and return its path
+    fn graph_file(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("ov-graph-{}-{}.json", name, std::process::id()));
+        let graph = json!({
+            "version": GRAPH_VERSION,
+            "root": "viking://resources/old",
+            "edges": [
+                { "from": "viking://resources/old/a", "to": "viking://resources/old/b", "reason": "cites" },
+                { "from": "viking://resources/old/a", "to": "viking://resources/old/c", "reason": "" },
+            ],
+        });
+        std::fs::write(&path, graph.to_string()).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_import_relations_links_existing_and_skips_missing() {
+        let path = graph_file("links");
+        let ok = r#"{"status":"ok","result":{}}"#;
+        let (url, requests) = mock_server(vec![(200, ok), (200, ok), (200, ok), (404, NOT_FOUND)]).await;
+        let client = HttpClient::new(url, None);
+        let result_path = std::env::temp_dir().join(format!("ov-graph-result-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&result_path).unwrap());
+        import_relations(&client, &path.to_string_lossy(), "viking://resources/new", &out).await.unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&result_path).unwrap()).unwrap();
+        std::fs::remove_file(&result_path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(written["result"]["linked"], 1);
+        assert_eq!(written["result"]["skipped"][0]["missing"], json!(["viking://resources/new/c"]));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[2].starts_with("POST /api/v1/relations/link "));
+        assert_eq!(request_body(&requests[2])["from_uri"], "viking://resources/new/a");
+        assert_eq!(request_body(&requests[2])["to_uris"], json!(["viking://resources/new/b"]));
+    }
+
+    #[tokio::test]
+    async fn test_import_relations_fails_when_an_endpoint_cannot_be_checked() {
+        let path = graph_file("errors");
+        let (url, requests) = mock_server(vec![(
+            500,
+            r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#,
+        )])
+        .await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let err = import_relations(&client, &path.to_string_lossy(), "viking://resources/new", &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(err, Error::Api(msg) if msg.contains("boom")));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rebase_uri() {
+        assert_eq!(
+            rebase_uri("viking://resources/a/doc.md", "viking://resources/a", "viking://resources/b/"),
+            "viking://resources/b/doc.md"
+        );
+        assert_eq!(rebase_uri("viking://resources/a", "viking://resources/a/", "viking://x"), "viking://x");
+        // Sibling with a shared prefix and URIs outside the root are left alone
+        assert_eq!(rebase_uri("viking://resources/ab", "viking://resources/a", "viking://x"), "viking://resources/ab");
+        assert_eq!(rebase_uri("viking://memories/m", "viking://resources/a", "viking://x"), "viking://memories/m");
+    }
+
+    #[test]
+    fn test_graph_round_trip() {
+        let graph = Graph {
+            version: GRAPH_VERSION,
+            root: "viking://resources".to_string(),
+            edges: vec![Edge {
+                from: "viking://resources/a".to_string(),
+                to: "viking://resources/b".to_string(),
+                reason: "cites".to_string(),
+            }],
+        };
+        let parsed: Graph = serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+        assert_eq!(parsed.edges, graph.edges);
+        assert_eq!(parsed.root, graph.root);
+    }
+}
@@ -1,25 +1,90 @@
+use std::collections::HashSet;
+
 use crate::client::HttpClient;
 use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use crate::output::{output_preview, output_success, Output};
+use serde_json::{json, Value};
 
+/// List the relations of `uri`; with `depth`, follow them that many hops out
 pub async fn list_relations(
     client: &HttpClient,
     uri: &str,
-    format: OutputFormat,
-    compact: bool,
+    depth: Option<u32>,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.relations(uri).await?;
-    output_success(&result, format, compact);
+    if let Some(depth) = depth {
+        let edges = traverse_relations(client, uri, depth).await?;
+        output_success(&edges, out);
+        return Ok(());
+    }
+    let mut result = client.relations(uri).await?;
+    if let Some(rows) = result.as_array_mut() {
+        sort_relations(rows);
+    }
+    output_success(&result, out);
     Ok(())
 }
 
+/// Breadth-first walk of the relation graph from `uri`, as `{hop, from, to, reason}` edges.
+///
+/// The server only reports direct relations, so each hop is one request per newly
+/// reached URI. Every URI is expanded at most once, which also stops cycles; edges
+/// back to an already visited URI are still listed.
+async fn traverse_relations(client: &HttpClient, uri: &str, depth: u32) -> Result<Vec<Value>> {
+    let mut visited: HashSet<String> = HashSet::from([uri.to_string()]);
+    let mut frontier = vec![uri.to_string()];
+    let mut edges = Vec::new();
+    for hop in 1..=depth {
+        let mut next = Vec::new();
+        for from in &frontier {
+            let mut rows = match client.relations(from).await? {
+                Value::Array(rows) => rows,
+                _ => Vec::new(),
+            };
+            sort_relations(&mut rows);
+            for row in rows {
+                let field = |name: &str| row.get(name).and_then(|v| v.as_str());
+                let Some(to) = field("to_uri").or_else(|| field("uri")) else {
+                    continue;
+                };
+                edges.push(json!({
+                    "hop": hop,
+                    "from": from,
+                    "to": to,
+                    "reason": field("reason").unwrap_or(""),
+                }));
+                if visited.insert(to.to_string()) {
+                    next.push(to.to_string());
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+    Ok(edges)
+}
+
+/// Order relation rows by `(direction, target uri, reason)` so repeated runs diff cleanly
+fn sort_relations(rows: &mut [Value]) {
+    fn key(row: &Value) -> (&str, &str, &str) {
+        let field = |name: &str| row.get(name).and_then(|v| v.as_str());
+        (
+            field("direction").unwrap_or(""),
+            field("to_uri").or_else(|| field("uri")).unwrap_or(""),
+            field("reason").unwrap_or(""),
+        )
+    }
+    rows.sort_by(|a, b| key(a).cmp(&key(b)));
+}
+
 pub async fn link(
     client: &HttpClient,
     from_uri: &str,
     to_uris: &Vec<String>,
     reason: &str,
-    format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let result = client.link(from_uri, to_uris, reason).await?;
     // If the server returns null/empty, show a confirmation summary
@@ -29,9 +94,9 @@ pub async fn link(
             "to": to_uris,
             "reason": reason,
         });
-        output_success(&summary, format, compact);
+        output_success(&summary, out);
     } else {
-        output_success(&result, format, compact);
+        output_success(&result, out);
     }
     Ok(())
 }
@@ -40,18 +105,65 @@ pub async fn unlink(
     client: &HttpClient,
     from_uri: &str,
     to_uri: &str,
-    format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
+    if client.is_dry_run() {
+        output_preview(HttpClient::unlink_request(from_uri, to_uri).preview(), out);
+        return Ok(());
+    }
     let result = client.unlink(from_uri, to_uri).await?;
     if result.is_null() {
         let summary = serde_json::json!({
             "from": from_uri,
             "to": to_uri,
         });
-        output_success(&summary, format, compact);
+        output_success(&summary, out);
     } else {
-        output_success(&result, format, compact);
+        output_success(&result, out);
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::mock_server;
+
+    #[tokio::test]
+    async fn test_traverse_relations_stops_at_cycles() {
+        // a -> b, b -> {a, c}, c -> {}
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":[{"uri":"viking://b","reason":"r1"}]}"#),
+            (200, r#"{"status":"ok","result":[{"uri":"viking://a","reason":"back"},{"uri":"viking://c","reason":"r2"}]}"#),
+            (200, r#"{"status":"ok","result":[]}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        let edges = traverse_relations(&client, "viking://a", 5).await.unwrap();
+        assert_eq!(
+            edges,
+            vec![
+                json!({"hop": 1, "from": "viking://a", "to": "viking://b", "reason": "r1"}),
+                json!({"hop": 2, "from": "viking://b", "to": "viking://a", "reason": "back"}),
+                json!({"hop": 2, "from": "viking://b", "to": "viking://c", "reason": "r2"}),
+            ]
+        );
+        // a, b and c are each asked once; the walk ends when nothing new is reached
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_sort_relations_is_stable_across_orderings() {
+        let mut rows = vec![
+            json!({"uri": "viking://b", "reason": "ref"}),
+            json!({"uri": "viking://a", "reason": "see also"}),
+            json!({"uri": "viking://a", "reason": "cites"}),
+        ];
+        let mut reversed: Vec<Value> = rows.iter().rev().cloned().collect();
+        sort_relations(&mut rows);
+        sort_relations(&mut reversed);
+        assert_eq!(rows, reversed);
+        assert_eq!(rows[0], json!({"uri": "viking://a", "reason": "cites"}));
+        assert_eq!(rows[2]["uri"], "viking://b");
+    }
+}
@@ -1,7 +1,19 @@
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use crate::commands::system::{poll_until_complete, DEFAULT_POLL_INTERVAL};
+use crate::error::{Error, Result};
+use crate::output::{output_success, Output};
+use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Path argument that means "read the resource from stdin"
+const STDIN_PATH: &str = "-";
 
+#[allow(clippy::too_many_arguments)]
 pub async fn add_resource(
     client: &HttpClient,
     path: &str,
@@ -10,25 +22,503 @@ pub async fn add_resource(
     instruction: String,
     wait: bool,
     timeout: Option<f64>,
-    format: OutputFormat,
-    compact: bool,
+    poll_interval: Option<f64>,
+    content_type: Option<&str>,
+    stdin_name: Option<&str>,
+    upload: bool,
+    out: &Output,
+) -> Result<()> {
+    // With --poll-interval the CLI does the waiting instead of one long server-side wait
+    let server_wait = wait && poll_interval.is_none();
+    let result = if path != STDIN_PATH {
+        if content_type.is_some() || stdin_name.is_some() {
+            return Err(Error::Client(
+                "--type and --stdin-name only apply to stdin input (path '-')".to_string(),
+            ));
+        }
+        import_path(client, path, to, &reason, &instruction, server_wait, timeout, upload).await?
+    } else {
+        if to.is_none() {
+            return Err(Error::Client("--to is required when reading from stdin".to_string()));
+        }
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        if content.trim().is_empty() {
+            return Err(Error::Client("No input on stdin".to_string()));
+        }
+        if content_type.is_none() && stdin_name.is_none() {
+            client
+                .add_resource(&inline_content(content), to, &reason, &instruction, server_wait, timeout)
+                .await?
+        } else {
+            // The server picks the parser and resource name from a file name, so spool
+            // stdin to a named temp file it can read. Parsing finishes before the request
+            // returns, so the file can go right after.
+            let spool = StdinSpool::create(&content, &spool_name(stdin_name, content_type)?)?;
+            let result = client
+                .add_resource(&spool.path.to_string_lossy(), to, &reason, &instruction, server_wait, timeout)
+                .await;
+            drop(spool);
+            result?
+        }
+    };
+    if let (true, Some(interval)) = (wait, poll_interval) {
+        poll_until_complete(client, interval, timeout, out).await?;
+    }
+    output_success(&result, out);
+    Ok(())
+}
+
+/// Import several local paths or URLs at once, at most `concurrency` at a time.
+///
+/// Each path gets a `{path, status, uri, error}` row; a failure does not stop the others
+/// but makes the command fail once every row is printed. With `wait`, the queue is
+/// waited on once after all imports instead of per path. A `to` target names a single
+/// resource, so it is refused with more than one path.
+#[allow(clippy::too_many_arguments)]
+pub async fn add_resources(
+    client: &HttpClient,
+    paths: &[String],
+    to: Option<String>,
+    reason: String,
+    instruction: String,
+    wait: bool,
+    timeout: Option<f64>,
+    poll_interval: Option<f64>,
+    upload: bool,
+    concurrency: usize,
+    out: &Output,
 ) -> Result<()> {
-    let result = client
-        .add_resource(path, to, &reason, &instruction, wait, timeout)
-        .await?;
-    output_success(&result, format, compact);
+    if paths.iter().any(|p| p == STDIN_PATH) {
+        return Err(Error::Client("stdin ('-') can only be imported on its own".to_string()));
+    }
+    if to.is_some() && paths.len() > 1 {
+        return Err(Error::Client(
+            "--to names a single target; import the paths one at a time to place each".to_string(),
+        ));
+    }
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    for (index, path) in paths.iter().cloned().enumerate() {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        let (to, reason, instruction) = (to.clone(), reason.clone(), instruction.clone());
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let outcome = import_path(&client, &path, to, &reason, &instruction, false, timeout, upload).await;
+            (index, outcome)
+        });
+    }
+    let mut outcomes: Vec<Option<Result<Value>>> = paths.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, outcome)) = joined {
+            outcomes[index] = Some(outcome);
+        }
+    }
+
+    let mut failed = 0;
+    let rows: Vec<Value> = paths
+        .iter()
+        .zip(outcomes)
+        .map(|(path, outcome)| match outcome {
+            Some(Ok(result)) => {
+                let uri = result.get("root_uri").cloned().unwrap_or(Value::Null);
+                json!({ "path": path, "status": "added", "uri": uri, "error": null })
+            }
+            Some(Err(e)) => {
+                failed += 1;
+                json!({ "path": path, "status": "failed", "uri": null, "error": e.to_string() })
+            }
+            None => {
+                failed += 1;
+                json!({ "path": path, "status": "failed", "uri": null, "error": "task did not complete" })
+            }
+        })
+        .collect();
+    if wait && failed < paths.len() {
+        let interval = poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+        poll_until_complete(client, interval, timeout, out).await?;
+    }
+    output_success(&rows, out);
+    if failed > 0 {
+        return Err(Error::Client(format!("{} of {} imports failed", failed, paths.len())));
+    }
     Ok(())
 }
 
+/// Import one local path or URL. Local files are uploaded; when the server has no
+/// upload endpoint (and `--upload` was not forced) the path is sent for it to read.
+#[allow(clippy::too_many_arguments)]
+async fn import_path(
+    client: &HttpClient,
+    path: &str,
+    to: Option<String>,
+    reason: &str,
+    instruction: &str,
+    server_wait: bool,
+    timeout: Option<f64>,
+    upload: bool,
+) -> Result<Value> {
+    let local = Path::new(path);
+    if !(upload || local.is_file()) {
+        return client.add_resource(path, to, reason, instruction, server_wait, timeout).await;
+    }
+    let data = std::fs::read(local)
+        .map_err(|e| Error::Client(format!("Cannot read {} for upload: {}", path, e)))?;
+    let filename = local
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+    // Servers without the upload endpoint can still read a path on their own host
+    match client
+        .upload_resource(data, &filename, to.clone(), reason, instruction, server_wait, timeout)
+        .await
+    {
+        Err(Error::Api(msg)) if msg.starts_with("[UNSUPPORTED]") && !upload => {
+            client.add_resource(path, to, reason, instruction, server_wait, timeout).await
+        }
+        result => result,
+    }
+}
+
+/// Make stdin text unambiguous as inline content: the server treats a `path` value
+/// containing a newline as the resource content rather than a path to open
+fn inline_content(mut content: String) -> String {
+    if !content.contains('\n') {
+        content.push('\n');
+    }
+    content
+}
+
+/// File name for spooled stdin: `--stdin-name` (default `stdin`), with the `--type`
+/// extension appended when the name has none
+fn spool_name(stdin_name: Option<&str>, content_type: Option<&str>) -> Result<String> {
+    let name = stdin_name.unwrap_or("stdin");
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(Error::Client(format!("--stdin-name must be a plain file name, got '{}'", name)));
+    }
+    match content_type {
+        Some(content_type) if std::path::Path::new(name).extension().is_none() => {
+            Ok(format!("{}.{}", name, extension_for(content_type)))
+        }
+        _ => Ok(name.to_string()),
+    }
+}
+
+/// Stdin contents written to a file in a private temp directory, removed on drop
+struct StdinSpool {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl StdinSpool {
+    fn create(content: &str, name: &str) -> Result<Self> {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let dir = std::env::temp_dir().join(format!("ov-stdin-{}-{}", std::process::id(), nanos));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(name);
+        std::fs::write(&path, content)?;
+        Ok(Self { dir, path })
+    }
+}
+
+impl Drop for StdinSpool {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// File extension for `--type`, given either as an extension (`md`) or a MIME type (`text/markdown`)
+fn extension_for(content_type: &str) -> String {
+    let content_type = content_type.trim().trim_start_matches('.');
+    if content_type.contains('/') {
+        return mime_guess::get_mime_extensions_str(content_type)
+            .and_then(|exts| exts.first())
+            .map(|ext| ext.to_string())
+            .unwrap_or_else(|| "txt".to_string());
+    }
+    content_type.to_string()
+}
+
 pub async fn add_skill(
     client: &HttpClient,
     data: &str,
     wait: bool,
     timeout: Option<f64>,
-    format: OutputFormat,
-    compact: bool,
+    poll_interval: Option<f64>,
+    out: &Output,
+) -> Result<()> {
+    let server_wait = wait && poll_interval.is_none();
+    let result = if Path::new(data).is_dir() {
+        upload_skill_dir(client, data, server_wait, timeout).await?
+    } else {
+        client.add_skill(data, server_wait, timeout).await?
+    };
+    if let (true, Some(interval)) = (wait, poll_interval) {
+        poll_until_complete(client, interval, timeout, out).await?;
+    }
+    output_success(&result, out);
+    Ok(())
+}
+
+/// Zip a local skill directory in memory and upload it via multipart
+/// `POST /api/v1/skills/upload`, so the server need not see the client's disk.
+/// A server whose OpenAPI document lacks that endpoint is sent the path without zipping.
+async fn upload_skill_dir(client: &HttpClient, dir: &str, wait: bool, timeout: Option<f64>) -> Result<Value> {
+    if client.has_endpoint("post", "/api/v1/skills/upload").await == Some(false) {
+        return client.add_skill(dir, wait, timeout).await;
+    }
+    let archive = zip_directory(Path::new(dir))?;
+    let name = Path::new(dir)
+        .canonicalize()?
+        .file_name()
+        .map(|name| format!("{}.zip", name.to_string_lossy()))
+        .unwrap_or_else(|| "skill.zip".to_string());
+    let wait_field = wait.to_string();
+    let timeout_field = timeout.map(|t| t.to_string());
+    let mut fields = vec![("wait", wait_field.as_str())];
+    if let Some(timeout) = &timeout_field {
+        fields.push(("timeout", timeout.as_str()));
+    }
+    // Servers without the upload endpoint can still read a path on their own host
+    match client
+        .post_multipart("/api/v1/skills/upload", &fields, Some((&name, archive.into())))
+        .await
+    {
+        Err(Error::Api(msg)) if msg.starts_with("[UNSUPPORTED]") => {
+            client.add_skill(dir, wait, timeout).await
+        }
+        result => result,
+    }
+}
+
+/// Deflate every file under `dir` into a zip archive, with paths relative to `dir`
+fn zip_directory(dir: &Path) -> Result<Vec<u8>> {
+    fn add_dir(zip: &mut zip::ZipWriter<std::io::Cursor<Vec<u8>>>, root: &Path, dir: &Path) -> Result<()> {
+        let mut entries = std::fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let path = entry.path();
+            if path.is_dir() {
+                add_dir(zip, root, &path)?;
+                continue;
+            }
+            let name = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            zip.start_file(name, options).map_err(zip_error)?;
+            zip.write_all(&std::fs::read(&path)?)?;
+        }
+        Ok(())
+    }
+    fn zip_error(e: zip::result::ZipError) -> Error {
+        Error::Client(format!("Failed to zip skill directory: {}", e))
+    }
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    add_dir(&mut zip, dir, dir)?;
+    Ok(zip.finish().map_err(zip_error)?.into_inner())
+}
+
+/// Replace the content of `uri` (from a local file, or `-` for stdin) and/or its
+/// `reason`/`instruction` metadata.
+///
+/// These use `PUT /api/v1/content` and `PATCH /api/v1/resources`, which not every server
+/// has; when its OpenAPI document lacks one that is needed, nothing is sent.
+pub async fn update_resource(
+    client: &HttpClient,
+    uri: &str,
+    file: Option<&str>,
+    reason: Option<&str>,
+    instruction: Option<&str>,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.add_skill(data, wait, timeout).await?;
-    output_success(&result, format, compact);
+    let metadata = reason.is_some() || instruction.is_some();
+    if !client.is_dry_run() {
+        let needed = [
+            (file.is_some(), "put", "/api/v1/content"),
+            (metadata, "patch", "/api/v1/resources"),
+        ];
+        for (_, method, path) in needed.into_iter().filter(|(needed, _, _)| *needed) {
+            if client.has_endpoint(method, path).await == Some(false) {
+                return Err(Error::Api(format!(
+                    "[UNSUPPORTED] server has no {} {}",
+                    method.to_ascii_uppercase(),
+                    path
+                )));
+            }
+        }
+    }
+    let mut updated = Vec::new();
+    if let Some(file) = file {
+        let content = if file == STDIN_PATH {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content)?;
+            content
+        } else {
+            std::fs::read_to_string(file)?
+        };
+        client.write_content(uri, &content, None).await?;
+        updated.push("content");
+    }
+    if metadata {
+        client.update_resource(uri, reason, instruction).await?;
+        updated.extend(reason.map(|_| "reason"));
+        updated.extend(instruction.map(|_| "instruction"));
+    }
+    output_success(json!({ "uri": uri, "updated": updated }), out);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::output_for;
+    use crate::output::OutputFormat;
+    use crate::client::tests::mock_server;
+
+    #[tokio::test]
+    async fn test_add_resources_reports_each_path() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"root_uri":"viking://resources/a"}}"#),
+            (500, r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let paths = vec!["https://example.com/a".to_string(), "https://example.com/b".to_string()];
+        let err = add_resources(&client, &paths, None, String::new(), String::new(), false, None, None, false, 1, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg == "1 of 2 imports failed"));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|r| r.starts_with("POST /api/v1/resources ")));
+    }
+
+    #[tokio::test]
+    async fn test_update_resource_checks_endpoints_first() {
+        let openapi = r#"{"openapi":"3.1.0","paths":{"/api/v1/content":{"put":{}}}}"#;
+        let (url, requests) = mock_server(vec![(200, openapi), (200, openapi)]).await;
+        let client = HttpClient::new(url, None);
+        let file = std::env::temp_dir().join(format!("ov-update-{}.md", std::process::id()));
+        std::fs::write(&file, "new").unwrap();
+        let err = update_resource(&client, "viking://resources/a.md", file.to_str(), Some("why"), None, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&file).unwrap();
+        assert!(matches!(err, Error::Api(msg) if msg == "[UNSUPPORTED] server has no PATCH /api/v1/resources"));
+        assert!(requests.lock().unwrap().iter().all(|r| r.starts_with("GET /openapi.json ")));
+    }
+
+    #[tokio::test]
+    async fn test_add_resources_rejects_stdin() {
+        let client = HttpClient::new("http://127.0.0.1:1", None);
+        let paths = vec!["-".to_string(), "https://example.com/a".to_string()];
+        let err = add_resources(&client, &paths, None, String::new(), String::new(), false, None, None, false, 4, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg.contains("stdin")));
+    }
+
+    #[tokio::test]
+    async fn test_add_resources_rejects_shared_target() {
+        let (url, requests) = mock_server(vec![]).await;
+        let client = HttpClient::new(url, None);
+        let paths = vec!["https://example.com/a".to_string(), "https://example.com/b".to_string()];
+        let to = Some("viking://resources/x".to_string());
+        let err = add_resources(&client, &paths, to, String::new(), String::new(), false, None, None, false, 4, &output_for(OutputFormat::Json))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg.contains("--to")));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    fn skill_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ov-skill-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(dir.join("scripts")).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "# Skill\n").unwrap();
+        std::fs::write(dir.join("scripts").join("run.sh"), "echo hi\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_zip_directory_keeps_relative_paths() {
+        let dir = skill_dir("zip");
+        let bytes = zip_directory(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        let names: Vec<String> = archive.file_names().map(str::to_string).collect();
+        assert_eq!(names, vec!["SKILL.md", "scripts/run.sh"]);
+        let mut content = String::new();
+        archive.by_name("scripts/run.sh").unwrap().read_to_string(&mut content).unwrap();
+        assert_eq!(content, "echo hi\n");
+    }
+
+    #[tokio::test]
+    async fn test_add_skill_uploads_directory_as_zip() {
+        let dir = skill_dir("upload");
+        let added = r#"{"status":"ok","result":{"uri":"viking://agent/skills/x"}}"#;
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"paths":{"/api/v1/skills":{"post":{}}}}"#),
+            (200, added),
+            (404, r#"{"detail":"Not Found"}"#),
+            (404, r#"{"detail":"Not Found"}"#),
+            (200, added),
+            (404, r#"{"detail":"Not Found"}"#),
+            (200, added),
+        ])
+        .await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let data = dir.to_string_lossy().into_owned();
+        // The first server's OpenAPI document has no upload endpoint, so the path is sent
+        // without zipping; the second publishes no document and rejects the upload
+        add_skill(&client, &data, false, None, None, &output_for(OutputFormat::Json)).await.unwrap();
+        add_skill(&client, &data, false, None, None, &output_for(OutputFormat::Json)).await.unwrap();
+        add_skill(&client, &data, false, None, None, &output_for(OutputFormat::Json)).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /openapi.json "));
+        assert!(requests[1].starts_with("POST /api/v1/skills "));
+        assert!(requests[3].starts_with("POST /api/v1/skills/upload "));
+        assert!(requests[4].starts_with("POST /api/v1/skills "));
+        assert!(requests[6].starts_with("POST /api/v1/skills/upload "));
+        let zip_name = format!("filename=\"{}.zip\"", dir.file_name().unwrap().to_string_lossy());
+        assert!(requests[6].contains(&zip_name));
+        assert!(requests[6].contains("PK\u{3}\u{4}"));
+        assert!(requests[6].contains("SKILL.md"));
+    }
+
+    #[test]
+    fn test_extension_for_content_type() {
+        assert_eq!(extension_for("md"), "md");
+        assert_eq!(extension_for(".html"), "html");
+        assert_eq!(extension_for("application/json"), "json");
+        assert_eq!(extension_for("application/x-unknown-thing"), "txt");
+    }
+
+    #[test]
+    fn test_spool_name() {
+        assert_eq!(spool_name(Some("notes"), Some("application/json")).unwrap(), "notes.json");
+        assert_eq!(spool_name(None, Some("md")).unwrap(), "stdin.md");
+        assert_eq!(spool_name(Some("notes.md"), Some("html")).unwrap(), "notes.md");
+        assert_eq!(spool_name(Some("notes.md"), None).unwrap(), "notes.md");
+        assert!(spool_name(Some("../etc/passwd"), None).is_err());
+    }
+
+    #[test]
+    fn test_inline_content_always_has_newline() {
+        assert_eq!(inline_content("one line".to_string()), "one line\n");
+        assert_eq!(inline_content("a\nb".to_string()), "a\nb");
+    }
+}
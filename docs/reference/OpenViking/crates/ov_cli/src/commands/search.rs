@@ -1,57 +1,512 @@
+use std::sync::Arc;
+
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
+use crate::commands::filesystem::normalize_types;
+use crate::commands::session::url_encode;
+use crate::error::{Error, Result};
+use crate::index::Index;
+use crate::output::{output_line, output_success, sort_by_field, sort_entries, Output, OutputFormat, ProgressReporter, SortKey};
+use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Maximum number of hits enriched by `--with-relations`
+const RELATIONS_HIT_LIMIT: usize = 10;
+/// Concurrent relation lookups for `--with-relations`
+const RELATIONS_CONCURRENCY: usize = 4;
+/// Hit categories returned by find/search
+const HIT_KEYS: [&str; 3] = ["memories", "resources", "skills"];
+/// Concurrent per-file requests for `grep --recursive`
+const GREP_CONCURRENCY: usize = 8;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn find(
     client: &HttpClient,
     query: &str,
     uri: &str,
     limit: i32,
     threshold: Option<f64>,
-    output_format: OutputFormat,
-    compact: bool,
+    with_relations: bool,
+    sort: Option<&str>,
+    desc: bool,
+    explain: bool,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.find(query.to_string(), uri.to_string(), limit, threshold).await?;
-    output_success(&result, output_format, compact);
+    let mut result = client.find(query.to_string(), uri.to_string(), limit, threshold, explain).await?;
+    normalize_hit_types(&mut result);
+    explain_hits(&mut result, explain, out.format.is_json());
+    if let Some(field) = sort {
+        sort_hits(&mut result, field, desc);
+    }
+    if with_relations {
+        attach_relations(client, &mut result, out).await;
+    }
+    output_success(&result, out);
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn search(
     client: &HttpClient,
     query: &str,
     uri: &str,
     session_id: Option<String>,
+    session_auto: bool,
+    context: Option<&str>,
     limit: i32,
     threshold: Option<f64>,
-    output_format: OutputFormat,
-    compact: bool,
+    with_relations: bool,
+    sort: Option<&str>,
+    desc: bool,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.search(query.to_string(), uri.to_string(), session_id, limit, threshold).await?;
-    output_success(&result, output_format, compact);
+    let mut result = if session_auto {
+        search_in_ephemeral_session(client, query, uri, context, limit, threshold, out).await?
+    } else {
+        client.search(query.to_string(), uri.to_string(), session_id, limit, threshold).await?
+    };
+    normalize_hit_types(&mut result);
+    if let Some(field) = sort {
+        sort_hits(&mut result, field, desc);
+    }
+    if with_relations {
+        attach_relations(client, &mut result, out).await;
+    }
+    output_success(&result, out);
     Ok(())
 }
 
+/// Run a context-aware search in a throwaway session, optionally seeded with `context`.
+///
+/// The session is deleted afterwards whether or not the search succeeded.
+async fn search_in_ephemeral_session(
+    client: &HttpClient,
+    query: &str,
+    uri: &str,
+    context: Option<&str>,
+    limit: i32,
+    threshold: Option<f64>,
+    out: &Output,
+) -> Result<Value> {
+    let session: Value = client.post("/api/v1/sessions", &json!({})).await?;
+    let session_id = session
+        .get("session_id")
+        .and_then(|id| id.as_str())
+        .ok_or_else(|| Error::Parse("Session response has no session_id".to_string()))?
+        .to_string();
+    let session_path = format!("/api/v1/sessions/{}", url_encode(&session_id));
+
+    let result = async {
+        if let Some(context) = context {
+            let message = json!({ "role": "user", "content": context });
+            let _: Value = client.post(&format!("{}/messages", session_path), &message).await?;
+        }
+        client
+            .search(query.to_string(), uri.to_string(), Some(session_id.clone()), limit, threshold)
+            .await
+    }
+    .await;
+
+    if let Err(e) = client.delete::<Value>(&session_path, &[]).await {
+        out.warn(format!("failed to delete session {}: {}", session_id, e));
+    }
+    result
+}
+
+fn normalize_hit_types(result: &mut Value) {
+    for key in HIT_KEYS {
+        if let Some(items) = result.get_mut(key).and_then(|v| v.as_array_mut()) {
+            normalize_types(items);
+        }
+    }
+}
+
+/// `find --explain`: make `score` the first column of every hit (null when the server
+/// sent none). The server's `debug` field is kept only for explained JSON output.
+fn explain_hits(result: &mut Value, explain: bool, json: bool) {
+    if !(explain && json) {
+        if let Some(obj) = result.as_object_mut() {
+            obj.shift_remove("debug");
+        }
+    }
+    if !explain {
+        return;
+    }
+    for key in HIT_KEYS {
+        if let Some(items) = result.get_mut(key).and_then(|v| v.as_array_mut()) {
+            for item in items.iter_mut() {
+                if let Some(obj) = item.as_object_mut() {
+                    let score = obj.shift_remove("score").unwrap_or(Value::Null);
+                    obj.shift_insert(0, "score".to_string(), score);
+                }
+            }
+        }
+    }
+}
+
+/// Order each hit category client-side by `field` (`--sort`/`--desc`)
+fn sort_hits(result: &mut Value, field: &str, desc: bool) {
+    for key in HIT_KEYS {
+        if let Some(items) = result.get_mut(key).and_then(|v| v.as_array_mut()) {
+            sort_by_field(items, field, desc);
+        }
+    }
+}
+
+/// Fetch relations for the top hits and attach them as a `relations` field.
+///
+/// Table output gets a comma-separated URI summary; JSON keeps the full relation objects.
+/// A failed lookup is reported on stderr and leaves that hit unchanged.
+async fn attach_relations(client: &HttpClient, result: &mut Value, out: &Output) {
+    let mut hits: Vec<(&'static str, usize, String)> = Vec::new();
+    for key in HIT_KEYS {
+        if let Some(items) = result.get(key).and_then(|v| v.as_array()) {
+            for (index, item) in items.iter().enumerate() {
+                if let Some(uri) = item.get("uri").and_then(|u| u.as_str()) {
+                    hits.push((key, index, uri.to_string()));
+                }
+            }
+        }
+    }
+    hits.truncate(RELATIONS_HIT_LIMIT);
+
+    let semaphore = Arc::new(Semaphore::new(RELATIONS_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    for (key, index, uri) in hits {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let relations = client.relations(&uri).await;
+            (key, index, uri, relations)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((key, index, uri, relations)) = joined else {
+            continue;
+        };
+        let relations = match relations {
+            Ok(relations) => relations,
+            Err(e) => {
+                out.warn(format!("failed to fetch relations for {}: {}", uri, e));
+                continue;
+            }
+        };
+        let summary = if out.format.is_json() {
+            relations
+        } else {
+            let uris: Vec<&str> = relations
+                .as_array()
+                .map(|items| items.iter().filter_map(|r| r.get("uri").and_then(|u| u.as_str())).collect())
+                .unwrap_or_default();
+            Value::String(uris.join(", "))
+        };
+        if let Some(hit) = result
+            .get_mut(key)
+            .and_then(|v| v.as_array_mut())
+            .and_then(|items| items.get_mut(index))
+            .and_then(|hit| hit.as_object_mut())
+        {
+            hit.insert("relations".to_string(), summary);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn grep(
     client: &HttpClient,
     uri: &str,
     pattern: &str,
     ignore_case: bool,
-    output_format: OutputFormat,
-    compact: bool,
+    fixed_strings: bool,
+    recursive: bool,
+    node_limit: i32,
+    out: &Output,
 ) -> Result<()> {
+    let pattern = &grep_pattern(pattern, fixed_strings)?;
+    if recursive {
+        return grep_recursive(client, uri, pattern, ignore_case, node_limit, out).await;
+    }
     let result = client.grep(uri, pattern, ignore_case).await?;
-    output_success(&result, output_format, compact);
+    output_success(&result, out);
+    Ok(())
+}
+
+/// The pattern to send: escaped for `--fixed-strings`, otherwise checked locally so a
+/// malformed regex fails with a clear message instead of an opaque server error.
+///
+/// The server uses Python's `re`, which supports look-around and backreferences that the
+/// `regex` crate does not, so patterns rejected only for those are passed through.
+fn grep_pattern(pattern: &str, fixed_strings: bool) -> Result<String> {
+    if fixed_strings {
+        return Ok(regex::escape(pattern));
+    }
+    match regex::Regex::new(pattern) {
+        Ok(_) => Ok(pattern.to_string()),
+        Err(regex::Error::Syntax(msg)) if msg.contains("not supported") => Ok(pattern.to_string()),
+        Err(e) => Err(Error::Client(format!("Invalid regex: {}", e))),
+    }
+}
+
+/// List the subtree under `uri` and grep each file with a bounded worker pool.
+///
+/// Table output streams `uri:line:text` as files complete, in listing order; other formats
+/// collect all matches into a single `{matches, count}` result. A file that cannot be
+/// grepped is warned about and makes the command fail once the rest are printed.
+async fn grep_recursive(
+    client: &HttpClient,
+    uri: &str,
+    pattern: &str,
+    ignore_case: bool,
+    node_limit: i32,
+    out: &Output,
+) -> Result<()> {
+    let entries = client.ls(uri, false, true, "original", 0, false, node_limit, None).await?;
+    let files: Vec<String> = entries
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter(|e| !e.get("isDir").and_then(|d| d.as_bool()).unwrap_or(false))
+                .filter_map(|e| e.get("uri").and_then(|u| u.as_str()).map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let progress = ProgressReporter::new("grep", Some(files.len() as u64), out);
+    let semaphore = Arc::new(Semaphore::new(GREP_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    for (index, file) in files.iter().cloned().enumerate() {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        let pattern = pattern.to_string();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let result = client.grep(&file, &pattern, ignore_case).await;
+            (index, result)
+        });
+    }
+
+    let stream = out.format == OutputFormat::Table;
+    let mut all_matches: Vec<Value> = Vec::new();
+    // Results wait here until every file listed before them is done
+    let mut outcomes: Vec<Option<Result<Value>>> = files.iter().map(|_| None).collect();
+    let mut next = 0;
+    let mut failed = 0;
+    let mut done = 0;
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, result)) = joined else {
+            continue;
+        };
+        done += 1;
+        progress.update(done, &files[index]);
+        outcomes[index] = Some(result);
+        while let Some(result) = outcomes.get_mut(next).and_then(Option::take) {
+            let file = &files[next];
+            next += 1;
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    failed += 1;
+                    out.warn(format!("grep failed for {}: {}", file, e));
+                    continue;
+                }
+            };
+            let matches = result.get("matches").and_then(|m| m.as_array()).cloned().unwrap_or_default();
+            for m in matches {
+                if stream {
+                    let match_uri = m.get("uri").and_then(|u| u.as_str()).unwrap_or(file);
+                    let line = m.get("line").map(|l| l.to_string()).unwrap_or_default();
+                    let content = m.get("content").and_then(|c| c.as_str()).unwrap_or("");
+                    output_line(&format!("{}:{}:{}", match_uri, line, content), out);
+                } else {
+                    all_matches.push(m);
+                }
+            }
+        }
+    }
+    // A task that panicked never reports back
+    failed += files.len() - next;
+
+    if !stream {
+        let count = all_matches.len();
+        output_success(
+            serde_json::json!({ "matches": all_matches, "count": count }),
+            out,
+        );
+    }
+    if failed > 0 {
+        return Err(Error::Client(format!("grep failed for {} of {} files", failed, files.len())));
+    }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn glob(
     client: &HttpClient,
     pattern: &str,
     uri: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    sort: Option<SortKey>,
+    natural: bool,
+    offline: bool,
+    out: &Output,
 ) -> Result<()> {
-    let result = client.glob(pattern, uri).await?;
-    output_success(&result, output_format, compact);
+    let mut result = if offline {
+        let index = Index::load()?;
+        index.warn_if_stale(out);
+        let matches = index.glob(pattern, uri)?;
+        serde_json::json!({ "count": matches.len(), "matches": matches })
+    } else {
+        client.glob(pattern, uri).await?
+    };
+    if sort.is_some() || natural {
+        if let Some(matches) = result.get_mut("matches").and_then(|m| m.as_array_mut()) {
+            sort_entries(matches, sort.unwrap_or(SortKey::Path), natural);
+        }
+    }
+    output_success(&result, out);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{mock_server, request_body};
+    use crate::output::tests::output_for;
+    use crate::output::ResultSink;
+
+    #[tokio::test]
+    async fn test_grep_recursive_keeps_listing_order_and_fails_on_errors() {
+        let listing = r#"{"status":"ok","result":[{"uri":"viking://r/a.md"},{"uri":"viking://r/b.md"},{"uri":"viking://r/c.md"}]}"#;
+        let hit = r#"{"status":"ok","result":{"matches":[{"line":1,"content":"x"}]}}"#;
+        let (url, _requests) = mock_server(vec![(200, listing), (200, hit), (200, hit), (200, hit)]).await;
+        let client = HttpClient::new(url, None);
+        let path = std::env::temp_dir().join(format!("ov-grep-order-{}.txt", std::process::id()));
+        let out = output_for(OutputFormat::Table).with_sink(ResultSink::file(&path).unwrap());
+        grep(&client, "viking://r", "x", false, false, true, 100, &out).await.unwrap();
+        out.finish().unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, "viking://r/a.md:1:x\nviking://r/b.md:1:x\nviking://r/c.md:1:x\n");
+
+        let failure = r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#;
+        let (url, _requests) = mock_server(vec![(200, listing), (200, hit), (500, failure), (200, hit)]).await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let out = output_for(OutputFormat::Json);
+        let err = grep(&client, "viking://r", "x", false, false, true, 100, &out).await.unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg == "grep failed for 1 of 3 files"));
+    }
+
+    #[tokio::test]
+    async fn test_session_auto_seeds_searches_and_deletes_the_session() {
+        let created = r#"{"status":"ok","result":{"session_id":"s1"}}"#;
+        let ok = r#"{"status":"ok","result":{}}"#;
+        let hits = r#"{"status":"ok","result":{"resources":[{"uri":"viking://r/a.md"}]}}"#;
+        let (url, requests) = mock_server(vec![(200, created), (200, ok), (200, hits), (200, ok)]).await;
+        let client = HttpClient::new(url, None);
+        let out = output_for(OutputFormat::Json);
+        let result = search_in_ephemeral_session(&client, "q", "viking://", Some("ctx"), 5, None, &out)
+            .await
+            .unwrap();
+        assert_eq!(result["resources"][0]["uri"], "viking://r/a.md");
+        let requests = requests.lock().unwrap().clone();
+        assert!(requests[0].starts_with("POST /api/v1/sessions "));
+        assert!(requests[1].starts_with("POST /api/v1/sessions/s1/messages "));
+        assert_eq!(request_body(&requests[1])["content"], "ctx");
+        assert_eq!(request_body(&requests[2])["session_id"], "s1");
+        assert!(requests[3].starts_with("DELETE /api/v1/sessions/s1 "));
+
+        // The session is deleted even when the search fails
+        let failure = r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#;
+        let (url, requests) = mock_server(vec![(200, created), (500, failure), (200, ok)]).await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let err = search_in_ephemeral_session(&client, "q", "viking://", None, 5, None, &out).await.unwrap_err();
+        assert!(matches!(err, Error::Api(_)));
+        assert!(requests.lock().unwrap()[2].starts_with("DELETE /api/v1/sessions/s1 "));
+    }
+
+    #[tokio::test]
+    async fn test_find_with_relations_attaches_relations_to_hits() {
+        let hits = r#"{"status":"ok","result":{"resources":[{"uri":"viking://r/a.md"},{"uri":"viking://r/b.md"}]}}"#;
+        let relations = r#"{"status":"ok","result":[{"uri":"viking://r/linked.md","reason":"see also"}]}"#;
+        let (url, requests) = mock_server(vec![(200, hits), (200, relations), (200, relations)]).await;
+        let client = HttpClient::new(url, None);
+        let path = std::env::temp_dir().join(format!("ov-find-relations-{}.json", std::process::id()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        find(&client, "q", "viking://", 10, None, true, None, false, false, &out).await.unwrap();
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        for hit in written["result"]["resources"].as_array().unwrap() {
+            assert_eq!(hit["relations"][0]["uri"], "viking://r/linked.md");
+        }
+        let lookups = requests.lock().unwrap().iter().filter(|r| r.starts_with("GET /api/v1/relations?uri=")).count();
+        assert_eq!(lookups, 2);
+
+        // A failed lookup warns and leaves the hit without relations
+        let hits = r#"{"status":"ok","result":{"resources":[{"uri":"viking://r/a.md"}]}}"#;
+        let failure = r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#;
+        let (url, _requests) = mock_server(vec![(200, hits), (500, failure)]).await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let out = output_for(OutputFormat::Json);
+        let mut result = client.find("q".to_string(), "viking://".to_string(), 10, None, false).await.unwrap();
+        attach_relations(&client, &mut result, &out).await;
+        assert!(out.opts.warnings.emitted());
+        assert!(result["resources"][0].get("relations").is_none());
+    }
+
+    #[test]
+    fn test_grep_pattern_rejects_unbalanced_parens() {
+        let err = grep_pattern("fn (foo", false).unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg.starts_with("Invalid regex: ") && msg.contains("unclosed group")));
+        assert!(grep_pattern("foo)", false).is_err());
+        assert_eq!(grep_pattern(r"fn \w+\(", false).unwrap(), r"fn \w+\(");
+        // Valid for the server's Python `re`, just not for the `regex` crate
+        assert_eq!(grep_pattern("foo(?=bar)", false).unwrap(), "foo(?=bar)");
+    }
+
+    #[test]
+    fn test_grep_pattern_fixed_strings_escapes() {
+        assert_eq!(grep_pattern("fn (foo", true).unwrap(), r"fn \(foo");
+        assert_eq!(grep_pattern("a.b*c", true).unwrap(), r"a\.b\*c");
+    }
+
+    #[test]
+    fn test_explain_hits_puts_score_first() {
+        let found = json!({
+            "resources": [{"uri": "viking://a", "abstract": "x", "score": 0.9}, {"uri": "viking://b"}],
+            "debug": {"rerank": true},
+        });
+        let mut explained = found.clone();
+        explain_hits(&mut explained, true, false);
+        let keys: Vec<&String> = explained["resources"][0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["score", "uri", "abstract"]);
+        assert_eq!(explained["resources"][1]["score"], Value::Null);
+        assert!(explained.get("debug").is_none());
+
+        let mut json_explained = found.clone();
+        explain_hits(&mut json_explained, true, true);
+        assert_eq!(json_explained["debug"], json!({"rerank": true}));
+
+        let mut plain = found;
+        explain_hits(&mut plain, false, true);
+        assert!(plain.get("debug").is_none());
+        assert!(plain["resources"][1].get("score").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_explain_sends_flag() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"resources":[]}}"#),
+            (200, r#"{"status":"ok","result":{"resources":[]}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        find(&client, "q", "", 5, None, false, None, false, true, &output_for(OutputFormat::Json)).await.unwrap();
+        find(&client, "q", "", 5, None, false, None, false, false, &output_for(OutputFormat::Json)).await.unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(request_body(&requests[0])["explain"], true);
+        assert!(request_body(&requests[1]).get("explain").is_none());
+    }
+}
@@ -0,0 +1,241 @@
+use serde_json::{json, Value};
+use std::future::Future;
+use std::time::Instant;
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::output::{output_success, Output, ProgressReporter};
+
+/// Parent of the scratch trees; add-resource only accepts the resources scope
+const SELFTEST_ROOT: &str = "viking://resources/.selftest";
+/// mkdir, add-resource, read, find, link, export, import, rm
+const STEP_COUNT: u64 = 8;
+
+/// Runs steps in order, recording timings; once one fails the rest are skipped
+struct Steps {
+    rows: Vec<Value>,
+    failed: bool,
+    progress: ProgressReporter,
+}
+
+impl Steps {
+    async fn run<T>(&mut self, name: &str, step: impl Future<Output = Result<T>>) -> Option<T> {
+        self.progress.update(self.rows.len() as u64, name);
+        if self.failed {
+            self.rows.push(json!({ "step": name, "status": "skipped", "ms": null, "detail": "" }));
+            return None;
+        }
+        let started = Instant::now();
+        let result = step.await;
+        let ms = started.elapsed().as_millis() as u64;
+        match result {
+            Ok(value) => {
+                self.rows.push(json!({ "step": name, "status": "pass", "ms": ms, "detail": "" }));
+                Some(value)
+            }
+            Err(e) => {
+                self.failed = true;
+                self.rows.push(json!({ "step": name, "status": "fail", "ms": ms, "detail": e.to_string() }));
+                None
+            }
+        }
+    }
+}
+
+/// Exercise mkdir, add-resource, read, find, link, export, import and rm against a
+/// scratch URI, then clean up and report each step. Fails if any step failed.
+pub async fn selftest(client: &HttpClient, out: &Output) -> Result<()> {
+    let id = format!(
+        "{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    );
+    selftest_with_id(client, &id, out).await
+}
+
+/// Run the selftest below `SELFTEST_ROOT/<id>`
+async fn selftest_with_id(client: &HttpClient, id: &str, out: &Output) -> Result<()> {
+    let base = format!("{}/{}", SELFTEST_ROOT, id);
+    let marker = format!("openviking-selftest-{}", id);
+    let tmp = std::env::temp_dir();
+    let doc_path = tmp.join(format!("ov-selftest-{}.md", id));
+    let pack_path = tmp.join(format!("ov-selftest-{}.ovpack", id));
+    let mut steps = Steps {
+        rows: Vec::new(),
+        failed: false,
+        progress: ProgressReporter::new("selftest", Some(STEP_COUNT), out),
+    };
+
+    steps.run("mkdir", client.mkdir(&base)).await;
+
+    let resource = steps
+        .run("add-resource", async {
+            std::fs::write(&doc_path, format!("# Selftest\n\n{}\n", marker))?;
+            let result = client
+                .add_resource(&doc_path.to_string_lossy(), Some(base.clone()), "selftest", "", true, Some(120.0))
+                .await?;
+            result
+                .get("root_uri")
+                .and_then(|u| u.as_str())
+                .map(|u| u.to_string())
+                .ok_or_else(|| Error::Parse("add-resource returned no root_uri".to_string()))
+        })
+        .await;
+
+    steps
+        .run("read", async {
+            let uri = resource.clone().unwrap_or_default();
+            let content = read_first_file(client, &uri).await?;
+            if content.contains(&marker) {
+                Ok(())
+            } else {
+                Err(Error::Client(format!("content of {} is missing the marker", uri)))
+            }
+        })
+        .await;
+
+    steps
+        .run("find", async {
+            let result = client.find(marker.clone(), base.clone(), 5, None, false).await?;
+            let total = result.get("total").and_then(|t| t.as_u64()).unwrap_or(0);
+            if total > 0 {
+                Ok(())
+            } else {
+                Err(Error::Client("no hits for the selftest document".to_string()))
+            }
+        })
+        .await;
+
+    steps
+        .run("link", async {
+            let target = resource.clone().unwrap_or_default();
+            client.link(&base, std::slice::from_ref(&target), "selftest").await?;
+            let relations = client.relations(&base).await?;
+            let linked = relations
+                .as_array()
+                .is_some_and(|rows| rows.iter().any(|r| r.get("uri").and_then(|u| u.as_str()) == Some(&target)));
+            if linked {
+                Ok(())
+            } else {
+                Err(Error::Client("link not listed in relations".to_string()))
+            }
+        })
+        .await;
+
+    steps
+        .run("export", async {
+            let uri = resource.clone().unwrap_or_default();
+            client.export_ovpack(&uri, &pack_path.to_string_lossy()).await
+        })
+        .await;
+
+    steps
+        .run("import", async {
+            let parent = format!("{}/imported", base);
+            client.mkdir(&parent).await?;
+            client.import_ovpack(&pack_path.to_string_lossy(), &parent, true, false).await
+        })
+        .await;
+
+    // Cleanup always runs, even after a failure
+    steps.failed = false;
+    steps.run("rm", client.rm(&base, true)).await;
+    let _ = std::fs::remove_file(&doc_path);
+    let _ = std::fs::remove_file(&pack_path);
+
+    let failures = steps.rows.iter().filter(|r| r["status"] != "pass").count();
+    output_success(&steps.rows, out);
+    if failures > 0 {
+        return Err(Error::Client(format!(
+            "selftest failed: {} of {} steps did not pass",
+            failures,
+            steps.rows.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Read `uri`, or the first file below it when it is a directory
+async fn read_first_file(client: &HttpClient, uri: &str) -> Result<String> {
+    if let Ok(content) = client.read(uri).await {
+        return Ok(content);
+    }
+    let listing = client.ls(uri, false, true, "original", 256, false, 1000, None).await?;
+    let file = listing
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|e| !e.get("isDir").and_then(|d| d.as_bool()).unwrap_or(false))
+        .filter_map(|e| e.get("uri").and_then(|u| u.as_str()))
+        .find(|u| !u.rsplit('/').next().unwrap_or("").starts_with('.'))
+        .ok_or_else(|| Error::Client(format!("no readable file under {}", uri)))?;
+    client.read(file).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::mock_server;
+    use crate::output::tests::output_for;
+    use crate::output::{OutputFormat, ResultSink};
+
+    const OK: &str = r#"{"status":"ok","result":{}}"#;
+
+    async fn run(responses: Vec<(u16, &'static str)>) -> (Result<()>, Vec<Value>, Vec<String>) {
+        let (url, requests) = mock_server(responses).await;
+        let client = HttpClient::new(url, None).with_retries(0);
+        let path = std::env::temp_dir().join(format!("ov-selftest-report-{}-{}.json", std::process::id(), line!()));
+        let out = output_for(OutputFormat::Json).with_sink(ResultSink::file(&path).unwrap());
+        let result = selftest_with_id(&client, "t", &out).await;
+        out.finish().unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let requests = requests.lock().unwrap().clone();
+        (result, written["result"].as_array().unwrap().clone(), requests)
+    }
+
+    fn statuses(rows: &[Value]) -> Vec<(&str, &str)> {
+        rows.iter()
+            .map(|r| (r["step"].as_str().unwrap(), r["status"].as_str().unwrap()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_selftest_reports_every_step() {
+        let (result, rows, requests) = run(vec![
+            (200, OK),
+            (200, r#"{"status":"ok","result":{"root_uri":"viking://resources/.selftest/t/doc"}}"#),
+            (200, r##"{"status":"ok","result":"# Selftest\n\nopenviking-selftest-t\n"}"##),
+            (200, r#"{"status":"ok","result":{"total":1,"resources":[]}}"#),
+            (200, OK),
+            (200, r#"{"status":"ok","result":[{"uri":"viking://resources/.selftest/t/doc"}]}"#),
+            (200, OK),
+            (200, OK),
+            (200, OK),
+            (200, OK),
+        ])
+        .await;
+        result.unwrap();
+        let steps = ["mkdir", "add-resource", "read", "find", "link", "export", "import", "rm"];
+        assert_eq!(statuses(&rows), steps.iter().map(|s| (*s, "pass")).collect::<Vec<_>>());
+        assert!(rows.iter().all(|r| r["ms"].is_u64()));
+        assert!(requests.last().unwrap().contains("viking%3A%2F%2Fresources%2F.selftest%2Ft"));
+    }
+
+    #[tokio::test]
+    async fn test_selftest_fails_and_still_cleans_up() {
+        let failure = r#"{"status":"error","error":{"code":"INTERNAL","message":"boom"}}"#;
+        let (result, rows, requests) = run(vec![(500, failure), (200, OK)]).await;
+        let err = result.unwrap_err();
+        assert!(matches!(&err, Error::Client(msg) if msg == "selftest failed: 7 of 8 steps did not pass"));
+        let mut expected = vec![("mkdir", "fail")];
+        expected.extend(["add-resource", "read", "find", "link", "export", "import"].map(|s| (s, "skipped")));
+        expected.push(("rm", "pass"));
+        assert_eq!(statuses(&rows), expected);
+        assert!(rows[0]["detail"].as_str().unwrap().contains("boom"));
+        assert_eq!(requests.len(), 2);
+    }
+}
@@ -1,45 +1,146 @@
-use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
-use serde_json::json;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use crate::client::{HttpClient, Paginated};
+use crate::error::{Error, Result};
+use crate::output::{output_success, Output};
+use serde_json::{json, Value};
+
+/// File format for `session export`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON array of messages
+    Json,
+    /// One message object per line
+    Ndjson,
+}
+
+/// Resolve a content argument: `-` reads stdin, `@path` reads a file, and anything else
+/// is the content itself (`@@text` for text that starts with `@`)
+pub fn read_content_arg(content: String) -> Result<String> {
+    resolve_content(content, std::io::stdin().lock())
+}
+
+fn resolve_content(content: String, mut stdin: impl Read) -> Result<String> {
+    if content == "-" {
+        let mut text = String::new();
+        stdin.read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    if let Some(literal) = content.strip_prefix("@@") {
+        return Ok(format!("@{}", literal));
+    }
+    match content.strip_prefix('@') {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| Error::Client(format!("Failed to read {}: {}", path, e))),
+        None => Ok(content),
+    }
+}
 
 pub async fn new_session(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.post("/api/v1/sessions", &json!({})).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
 pub async fn list_sessions(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
-    let response: serde_json::Value = client.get("/api/v1/sessions", &[]).await?;
-    output_success(&response, output_format, compact);
+    let sessions: Paginated<serde_json::Value> = client.get_paginated("/api/v1/sessions", &[]).await?;
+    if sessions.truncated {
+        out.warn(format!("stopped after {} sessions; the server has more", sessions.items.len()));
+    }
+    output_success(&sessions.items, out);
     Ok(())
 }
 
 pub async fn get_session(
     client: &HttpClient,
     session_id: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let path = format!("/api/v1/sessions/{}", url_encode(session_id));
     let response: serde_json::Value = client.get(&path, &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
+    Ok(())
+}
+
+/// Write the `messages` of a session to `to` (stdout when omitted or `-`).
+///
+/// An existing file is only replaced with `force`; the check runs before the session is fetched.
+pub async fn export_session(
+    client: &HttpClient,
+    session_id: &str,
+    format: ExportFormat,
+    to: Option<&Path>,
+    force: bool,
+    out: &Output,
+) -> Result<()> {
+    let to = to.filter(|path| *path != Path::new("-"));
+    if let Some(path) = to {
+        if path.exists() && !force {
+            return Err(Error::Client(format!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            )));
+        }
+    }
+    let path = format!("/api/v1/sessions/{}", url_encode(session_id));
+    let session: Value = client.get(&path, &[]).await?;
+    let messages = session_messages(&session)?;
+    let text = render_messages(messages, format)?;
+    match to {
+        Some(path) => {
+            std::fs::write(path, text)?;
+            eprintln!("Exported {} messages to {}", messages.len(), path.display());
+        }
+        None => write!(out.writer(), "{}", text)?,
+    }
     Ok(())
 }
 
+/// The `messages` array of a session. A missing or null field only means an empty
+/// history when `message_count` is 0; servers that report just the count cannot be
+/// exported.
+fn session_messages(session: &Value) -> Result<&[Value]> {
+    match session.get("messages") {
+        None | Some(Value::Null) => match session.get("message_count").and_then(Value::as_u64) {
+            Some(0) => Ok(&[]),
+            Some(count) => Err(Error::Api(format!(
+                "[UNSUPPORTED] server returned no messages for a session with {} messages; export needs a server that includes them",
+                count
+            ))),
+            None => Err(Error::Api(
+                "[UNSUPPORTED] server returned no messages for this session; export needs a server that includes them".to_string(),
+            )),
+        },
+        Some(Value::Array(messages)) => Ok(messages),
+        Some(other) => Err(Error::Parse(format!("session messages is not an array: {}", other))),
+    }
+}
+
+fn render_messages(messages: &[Value], format: ExportFormat) -> Result<String> {
+    Ok(match format {
+        ExportFormat::Json => serde_json::to_string_pretty(messages)? + "\n",
+        ExportFormat::Ndjson => {
+            let mut text = String::new();
+            for message in messages {
+                text.push_str(&serde_json::to_string(message)?);
+                text.push('\n');
+            }
+            text
+        }
+    })
+}
+
 pub async fn delete_session(
     client: &HttpClient,
     session_id: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let path = format!("/api/v1/sessions/{}", url_encode(session_id));
     let response: serde_json::Value = client.delete(&path, &[]).await?;
@@ -51,7 +152,7 @@ pub async fn delete_session(
         response
     };
     
-    output_success(&result, output_format, compact);
+    output_success(&result, out);
     Ok(())
 }
 
@@ -60,8 +161,7 @@ pub async fn add_message(
     session_id: &str,
     role: &str,
     content: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let path = format!("/api/v1/sessions/{}/messages", url_encode(session_id));
     let body = json!({
@@ -70,19 +170,18 @@ pub async fn add_message(
     });
     
     let response: serde_json::Value = client.post(&path, &body).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
 pub async fn commit_session(
     client: &HttpClient,
     session_id: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let path = format!("/api/v1/sessions/{}/commit", url_encode(session_id));
     let response: serde_json::Value = client.post(&path, &json!({})).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
@@ -95,8 +194,7 @@ pub async fn commit_session(
 pub async fn add_memory(
     client: &HttpClient,
     input: &str,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     // Parse input to determine messages
     let messages: Vec<(String, String)> = if let Ok(value) = serde_json::from_str::<serde_json::Value>(input) {
@@ -149,13 +247,92 @@ pub async fn add_memory(
     let result = json!({
         "memories_extracted": memories_extracted
     });
-    output_success(&result, output_format, compact);
+    output_success(&result, out);
     Ok(())
 }
 
-fn url_encode(s: &str) -> String {
+pub(crate) fn url_encode(s: &str) -> String {
     // Simple URL encoding for session IDs
     s.replace('/', "%2F")
         .replace(':', "%3A")
         .replace(' ', "%20")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::mock_server;
+    use crate::output::tests::output_for;
+    use crate::output::OutputFormat;
+
+    #[tokio::test]
+    async fn test_list_sessions_warns_when_capped() {
+        let page = r#"{"status":"ok","result":{"items":[{"session_id":"a"}],"next_cursor":"more"}}"#;
+        let (url, _requests) = mock_server(vec![(200, page), (200, page)]).await;
+        let client = HttpClient::new(url, None).with_max_pages(2);
+        let out = output_for(OutputFormat::Json);
+        list_sessions(&client, &out).await.unwrap();
+        assert!(out.opts.warnings.emitted());
+    }
+
+    #[test]
+    fn test_render_messages() {
+        let session = json!({"session_id": "s1", "messages": [
+            {"role": "user", "content": "hi"},
+            {"role": "assistant", "content": null},
+        ]});
+        let messages = session_messages(&session).unwrap();
+        assert_eq!(
+            render_messages(messages, ExportFormat::Ndjson).unwrap(),
+            "{\"role\":\"user\",\"content\":\"hi\"}\n{\"role\":\"assistant\",\"content\":null}\n"
+        );
+        let json: Value = serde_json::from_str(&render_messages(messages, ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json[1]["content"], Value::Null);
+
+        for empty in [json!({"messages": []}), json!({"messages": null, "message_count": 0}), json!({"message_count": 0})] {
+            let messages = session_messages(&empty).unwrap();
+            assert_eq!(render_messages(messages, ExportFormat::Ndjson).unwrap(), "");
+            assert_eq!(render_messages(messages, ExportFormat::Json).unwrap(), "[]\n");
+        }
+        assert!(matches!(session_messages(&json!({"messages": "x"})), Err(Error::Parse(_))));
+        for count_only in [json!({"session_id": "s1", "message_count": 3}), json!({})] {
+            assert!(matches!(session_messages(&count_only), Err(Error::Api(msg)) if msg.starts_with("[UNSUPPORTED]")));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_session_refuses_existing_file() {
+        let session = r#"{"status":"ok","result":{"messages":[{"role":"user","content":"hi"}]}}"#;
+        let (url, requests) = mock_server(vec![(200, session)]).await;
+        let client = HttpClient::new(url, None);
+        let path = std::env::temp_dir().join(format!("ov-export-{}.ndjson", std::process::id()));
+        std::fs::write(&path, "old").unwrap();
+
+        let err = export_session(&client, "s1", ExportFormat::Ndjson, Some(&path), false, &output_for(OutputFormat::Table)).await.unwrap_err();
+        assert!(matches!(err, Error::Client(msg) if msg.contains("--force")));
+        assert!(requests.lock().unwrap().is_empty());
+
+        export_session(&client, "s1", ExportFormat::Ndjson, Some(&path), true, &output_for(OutputFormat::Table)).await.unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, "{\"role\":\"user\",\"content\":\"hi\"}\n");
+        assert!(requests.lock().unwrap()[0].starts_with("GET /api/v1/sessions/s1 "));
+    }
+
+    #[test]
+    fn test_resolve_content() {
+        let stdin = "line one\nline two\n".as_bytes();
+        assert_eq!(resolve_content("-".to_string(), stdin).unwrap(), "line one\nline two\n");
+        assert_eq!(resolve_content("plain".to_string(), std::io::empty()).unwrap(), "plain");
+        assert_eq!(resolve_content("@@alice".to_string(), std::io::empty()).unwrap(), "@alice");
+
+        let path = std::env::temp_dir().join(format!("ov-content-{}.json", std::process::id()));
+        std::fs::write(&path, r#"[{"role":"user","content":"hi"}]"#).unwrap();
+        let loaded = resolve_content(format!("@{}", path.display()), std::io::empty()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, r#"[{"role":"user","content":"hi"}]"#);
+
+        let missing = resolve_content("@/nonexistent/ov-content".to_string(), std::io::empty());
+        assert!(matches!(missing, Err(Error::Client(msg)) if msg.contains("/nonexistent/ov-content")));
+    }
+}
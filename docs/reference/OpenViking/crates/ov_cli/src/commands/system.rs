@@ -1,14 +1,173 @@
 use crate::client::HttpClient;
-use crate::error::Result;
-use crate::output::{output_success, OutputFormat};
-use serde_json::json;
+use crate::error::{Error, Result};
+use crate::output::{output_success, paint_health, Output, OutputFormat, ProgressReporter};
+use serde_json::{json, Value};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Smallest `--poll-interval` accepted, so polling cannot hammer the server
+pub const MIN_POLL_INTERVAL: f64 = 0.5;
+/// Poll interval `system wait` uses on a terminal when `--poll-interval` is not given
+pub const DEFAULT_POLL_INTERVAL: f64 = 2.0;
+
+/// Parse and validate a `--poll-interval` value in seconds
+pub fn parse_poll_interval(s: &str) -> std::result::Result<f64, String> {
+    let secs: f64 = s
+        .parse()
+        .map_err(|_| format!("invalid poll interval '{}'", s))?;
+    if !secs.is_finite() || secs < MIN_POLL_INTERVAL {
+        return Err(format!("poll interval must be at least {}s", MIN_POLL_INTERVAL));
+    }
+    Ok(secs)
+}
+
+/// Poll the server until queued processing completes, one bounded wait per `interval`.
+///
+/// Each poll asks the server to wait at most `interval` seconds, so the CLI checks
+/// back at that cadence until the queue drains or the overall `timeout` passes.
+///
+/// For human output on a terminal, a spinner on stderr shows the elapsed time and the
+/// queue's pending work, and finishes with a summary once waiting ends.
+pub async fn poll_until_complete(
+    client: &HttpClient,
+    interval: f64,
+    timeout: Option<f64>,
+    out: &Output,
+) -> Result<Value> {
+    let progress = WaitProgress::new(progress_enabled(out.format, std::io::stdout().is_terminal()));
+    let started = Instant::now();
+    let result = poll_with_status(client, interval, timeout, &progress, out).await;
+    progress.finish(result.is_ok(), started.elapsed().as_secs_f64());
+    result
+}
+
+/// The wait spinner is for people: never under JSON output or when stdout is piped
+fn progress_enabled(output_format: OutputFormat, stdout_is_terminal: bool) -> bool {
+    stdout_is_terminal && !output_format.is_json()
+}
+
+async fn poll_with_status(
+    client: &HttpClient,
+    interval: f64,
+    timeout: Option<f64>,
+    status: &WaitProgress,
+    out: &Output,
+) -> Result<Value> {
+    let started = Instant::now();
+    let deadline = timeout.map(|t| started + Duration::from_secs_f64(t));
+    let progress = ProgressReporter::new("wait", None, out);
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        progress.update(polls, &format!("{:.1}s elapsed", started.elapsed().as_secs_f64()));
+        if polls > 1 && status.is_enabled() {
+            let queue = client.get::<Value>("/api/v1/observer/queue", &[]).await.ok();
+            let counts = queue
+                .as_ref()
+                .and_then(|q| q.get("status"))
+                .and_then(|s| s.as_str())
+                .and_then(queue_totals);
+            status.show(wait_status(counts, started.elapsed().as_secs_f64()));
+        }
+        let slice = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now()).as_secs_f64();
+                if remaining <= 0.0 {
+                    return Err(Error::Client(format!(
+                        "Timed out after {:.1}s waiting for processing",
+                        started.elapsed().as_secs_f64()
+                    )));
+                }
+                remaining.min(interval)
+            }
+            None => interval,
+        };
+        match client.post("/api/v1/system/wait", &json!({ "timeout": slice })).await {
+            Err(Error::Api(msg)) if msg.starts_with("[DEADLINE_EXCEEDED]") => continue,
+            other => return other,
+        }
+    }
+}
+
+/// Pending and in-progress counts from the `TOTAL` row of the queue observer's status table
+pub(crate) fn queue_totals(status: &str) -> Option<(u64, u64)> {
+    let cells = |line: &str| -> Vec<String> {
+        line.split('|').map(|c| c.trim().to_string()).collect()
+    };
+    let header = cells(status.lines().find(|l| l.contains("Pending"))?);
+    let total = cells(status.lines().find(|l| l.contains("TOTAL"))?);
+    let column = |name: &str| -> Option<u64> {
+        let index = header.iter().position(|c| c == name)?;
+        total.get(index)?.parse().ok()
+    };
+    Some((column("Pending")?, column("In Progress").unwrap_or(0)))
+}
+
+fn wait_status(counts: Option<(u64, u64)>, elapsed: f64) -> String {
+    match counts {
+        Some((pending, in_progress)) => format!(
+            "waiting: {} pending, {} in progress, {:.1}s elapsed",
+            pending, in_progress, elapsed
+        ),
+        None => format!("waiting: {:.1}s elapsed", elapsed),
+    }
+}
+
+/// Spinner on stderr that ticks every 500 ms; does nothing when disabled
+struct WaitProgress {
+    bar: Option<ProgressBar>,
+}
+
+impl WaitProgress {
+    fn new(enabled: bool) -> Self {
+        let bar = enabled.then(|| {
+            let bar = ProgressBar::new_spinner();
+            bar.set_style(
+                ProgressStyle::with_template("{spinner} [{elapsed}] {msg}")
+                    .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+            );
+            bar.set_message("waiting for processing");
+            bar.enable_steady_tick(Duration::from_millis(500));
+            bar
+        });
+        Self { bar }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.bar.is_some()
+    }
+
+    fn show(&self, text: String) {
+        if let Some(bar) = &self.bar {
+            bar.set_message(text);
+        }
+    }
+
+    /// Leave a one-line summary on success; clear the spinner so errors print cleanly
+    fn finish(&self, succeeded: bool, elapsed: f64) {
+        match &self.bar {
+            Some(bar) if succeeded => bar.finish_with_message(format!("processing complete in {:.1}s", elapsed)),
+            Some(bar) => bar.finish_and_clear(),
+            None => {}
+        }
+    }
+}
 
 pub async fn wait(
     client: &HttpClient,
     timeout: Option<f64>,
-    output_format: OutputFormat,
-    compact: bool,
+    poll_interval: Option<f64>,
+    out: &Output,
 ) -> Result<()> {
+    // On a terminal, poll so there is progress to show; scripts keep the single blocking call
+    let poll_interval = poll_interval.or_else(|| std::io::stderr().is_terminal().then_some(DEFAULT_POLL_INTERVAL));
+    if let Some(interval) = poll_interval {
+        let response = poll_until_complete(client, interval, timeout, out).await?;
+        output_success(&response, out);
+        return Ok(());
+    }
+
     let path = if let Some(t) = timeout {
         format!("/api/v1/system/wait?timeout={}", t)
     } else {
@@ -16,37 +175,121 @@ pub async fn wait(
     };
 
     let response: serde_json::Value = client.post(&path, &json!({})).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
     Ok(())
 }
 
 pub async fn status(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/api/v1/system/status", &[]).await?;
-    output_success(&response, output_format, compact);
+    output_success(&response, out);
+    Ok(())
+}
+
+/// Print the CLI version next to the server's, or "unavailable" when the server
+/// cannot be reached or does not report one. Never fails on the server side.
+pub async fn version(
+    client: &HttpClient,
+    server_url: &str,
+    out: &Output,
+) -> Result<()> {
+    let server = server_version(client).await;
+    output_success(
+        json!({
+            "client": env!("CARGO_PKG_VERSION"),
+            "server": server.as_deref().unwrap_or("unavailable"),
+            "url": server_url,
+        }),
+        out,
+    );
     Ok(())
 }
 
+/// Server version from `system/status` (`version` or `build`), falling back to the
+/// `info.version` of the server's OpenAPI document
+async fn server_version(client: &HttpClient) -> Option<String> {
+    if let Ok(status) = client.get::<Value>("/api/v1/system/status", &[]).await {
+        if let Some(version) = ["version", "build"]
+            .iter()
+            .find_map(|key| status.get(*key).and_then(Value::as_str))
+        {
+            return Some(version.to_string());
+        }
+    }
+    let openapi: Value = client.get("/openapi.json", &[]).await.ok()?;
+    openapi
+        .pointer("/info/version")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
 pub async fn health(
     client: &HttpClient,
-    output_format: OutputFormat,
-    compact: bool,
+    out: &Output,
 ) -> Result<()> {
     let response: serde_json::Value = client.get("/health", &[]).await?;
     
     // For health check, if it's a simple status, just print it
     if let Some(status) = response.get("status").and_then(|v| v.as_str()) {
-        if matches!(output_format, OutputFormat::Json) {
-            output_success(&response, output_format, compact);
+        if out.format != OutputFormat::Table {
+            output_success(&response, out);
         } else {
-            println!("{}", status);
+            let healthy = matches!(status, "ok" | "healthy");
+            writeln!(out.writer(), "{}", paint_health(status, healthy, out))?;
         }
     } else {
-        output_success(&response, output_format, compact);
+        output_success(&response, out);
     }
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::mock_server;
+
+    #[tokio::test]
+    async fn test_server_version_falls_back_to_openapi() {
+        let (url, requests) = mock_server(vec![
+            (200, r#"{"status":"ok","result":{"initialized":true,"user":"u"}}"#),
+            (200, r#"{"openapi":"3.1.0","info":{"title":"OpenViking","version":"0.1.0"}}"#),
+        ])
+        .await;
+        let client = HttpClient::new(url, None);
+        assert_eq!(server_version(&client).await.as_deref(), Some("0.1.0"));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        let unreachable = HttpClient::new("http://127.0.0.1:1", None).with_retries(0);
+        assert_eq!(server_version(&unreachable).await, None);
+    }
+
+    const QUEUE_STATUS: &str = "\
++----------------+---------+-------------+-----------+--------+-------+
+|     Queue      | Pending | In Progress | Processed | Errors | Total |
++----------------+---------+-------------+-----------+--------+-------+
+|   Embedding    |    3    |      1      |    10     |   0    |  14   |
+| Semantic-Nodes |    0    |      0      |     0     |   0    |   0   |
+|     TOTAL      |    3    |      1      |    10     |   0    |  14   |
++----------------+---------+-------------+-----------+--------+-------+";
+
+    #[test]
+    fn test_queue_totals_from_status_table() {
+        assert_eq!(queue_totals(QUEUE_STATUS), Some((3, 1)));
+        assert_eq!(queue_totals("No queue status data available."), None);
+        assert_eq!(
+            wait_status(Some((3, 1)), 2.04),
+            "waiting: 3 pending, 1 in progress, 2.0s elapsed"
+        );
+    }
+
+    #[test]
+    fn test_progress_only_for_human_output_on_terminal() {
+        assert!(progress_enabled(OutputFormat::Table, true));
+        assert!(!progress_enabled(OutputFormat::Table, false));
+        assert!(!progress_enabled(OutputFormat::Json, true));
+        assert!(!progress_enabled(OutputFormat::Jsonl, true));
+    }
+}
@@ -0,0 +1,240 @@
+use std::future::Future;
+use std::io::Write;
+use std::time::Duration;
+
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::output::{output_line, output_success, Output, OutputFormat};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+/// Output format for `watch --format`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum WatchFormat {
+    /// Redraw the stat table each poll, like `watch(1)`
+    Table,
+    /// One `{poll, changed, result}` object per poll
+    Json,
+}
+
+impl From<WatchFormat> for OutputFormat {
+    fn from(format: WatchFormat) -> Self {
+        match format {
+            WatchFormat::Table => OutputFormat::Table,
+            WatchFormat::Json => OutputFormat::Json,
+        }
+    }
+}
+
+/// Poll `stat` on `uri` every `interval` seconds until Ctrl-C or `count` polls.
+///
+/// Table output clears the screen before each poll and lists fields that changed since
+/// the previous one; JSON output emits one object per poll with a `changed` field list.
+pub async fn watch(
+    client: &HttpClient,
+    uri: &str,
+    interval: f64,
+    count: Option<u64>,
+    out: &Output,
+) -> Result<()> {
+    let table = out.format == OutputFormat::Table;
+    let clear = table && out.to_terminal();
+    let mut ticker = tokio::time::interval(Duration::from_secs_f64(interval));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut previous: Option<Value> = None;
+    let mut polls = 0;
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = ticker.tick() => {}
+        }
+        let current = tokio::select! {
+            _ = &mut ctrl_c => break,
+            result = client.stat(uri) => result?,
+        };
+        polls += 1;
+        let changed = previous
+            .as_ref()
+            .map(|previous| changed_fields(previous, &current))
+            .unwrap_or_default();
+
+        if table {
+            if clear {
+                let _ = write!(out.writer(), "\x1b[2J\x1b[H");
+            }
+            output_line(&format!(
+                "Every {}s: stat {}    {}",
+                interval,
+                uri,
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+            ), out);
+            output_success(&current, out);
+            if let Some(previous) = &previous {
+                for field in &changed {
+                    let before = previous.get(field).map(Value::to_string).unwrap_or_default();
+                    let after = current.get(field).map(Value::to_string).unwrap_or_default();
+                    let _ = writeln!(out.writer(), "{}", format!("changed {}: {} -> {}", field, before, after).yellow());
+                }
+            }
+        } else {
+            output_success(
+                json!({ "poll": polls, "changed": changed, "result": current }),
+                out,
+            );
+        }
+
+        previous = Some(current);
+        if count.is_some_and(|count| polls >= count) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Re-run `render` every `interval` seconds until Ctrl-C, for `--watch` on status commands.
+///
+/// Table output clears the screen and prints a `title` line before each run. Network errors
+/// are reported as warnings and retried on the next cycle, but the command fails if no run
+/// reached the server; any other error stops the loop.
+pub async fn refresh<F, Fut>(title: &str, interval: f64, out: &Output, render: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    refresh_cycles(title, interval, out, None, render).await
+}
+
+async fn refresh_cycles<F, Fut>(
+    title: &str,
+    interval: f64,
+    out: &Output,
+    cycles: Option<u64>,
+    mut render: F,
+) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let table = out.format == OutputFormat::Table;
+    let clear = table && out.to_terminal();
+    let mut ticker = tokio::time::interval(Duration::from_secs_f64(interval));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut runs = 0;
+    let mut last_error: Option<String> = None;
+    let mut reached = false;
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = ticker.tick() => {}
+        }
+        if clear {
+            let _ = write!(out.writer(), "\x1b[2J\x1b[H");
+        }
+        if table {
+            output_line(&format!(
+                "Every {}s: {}    {}",
+                interval,
+                title,
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+            ), out);
+        }
+        let result = tokio::select! {
+            _ = &mut ctrl_c => break,
+            result = render() => result,
+        };
+        runs += 1;
+        match result {
+            Err(Error::Network(msg)) => {
+                out.warn(format!("refresh {} failed: {}", runs, msg));
+                last_error = Some(msg);
+            }
+            other => {
+                other?;
+                reached = true;
+            }
+        }
+        if cycles.is_some_and(|cycles| runs >= cycles) {
+            break;
+        }
+    }
+    match last_error {
+        Some(msg) if !reached => Err(Error::Network(format!("no refresh reached the server: {}", msg))),
+        _ => Ok(()),
+    }
+}
+
+/// Top-level fields whose values differ between two stat results, in first-seen order
+fn changed_fields(previous: &Value, current: &Value) -> Vec<String> {
+    let (Some(before), Some(after)) = (previous.as_object(), current.as_object()) else {
+        return if previous == current { Vec::new() } else { vec!["result".to_string()] };
+    };
+    let mut fields: Vec<String> = Vec::new();
+    for key in before.keys().chain(after.keys()) {
+        if before.get(key) != after.get(key) && !fields.contains(key) {
+            fields.push(key.clone());
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::tests::output_for;
+    use crate::client::tests::mock_server;
+
+    #[test]
+    fn test_changed_fields() {
+        let before = json!({"uri": "viking://a", "size": 1, "old": true});
+        let after = json!({"uri": "viking://a", "size": 2, "new": true});
+        assert_eq!(changed_fields(&before, &after), vec!["size", "old", "new"]);
+        assert!(changed_fields(&before, &before).is_empty());
+        assert_eq!(changed_fields(&json!("a"), &json!("b")), vec!["result"]);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_survives_network_errors() {
+        let client = HttpClient::new("http://127.0.0.1:1", None).with_retries(0);
+        let mut runs = 0;
+        let out = output_for(OutputFormat::Json);
+        let result = refresh_cycles("status", 0.01, &out, Some(2), || {
+            runs += 1;
+            async { client.get::<Value>("/api/v1/system/status", &[]).await.map(|_| ()) }
+        })
+        .await;
+        assert_eq!(runs, 2);
+        assert!(matches!(result, Err(Error::Network(msg)) if msg.starts_with("no refresh reached the server")));
+        assert!(out.opts.warnings.emitted());
+
+        // One run reaching the server is enough
+        let mut runs = 0;
+        refresh_cycles("status", 0.01, &output_for(OutputFormat::Json), Some(2), || {
+            runs += 1;
+            let result = if runs == 1 { Err(Error::Network("down".to_string())) } else { Ok(()) };
+            async move { result }
+        })
+        .await
+        .unwrap();
+
+        let result = refresh_cycles("status", 0.01, &output_for(OutputFormat::Json), Some(2), || async {
+            Err(Error::Client("bad".to_string()))
+        })
+        .await;
+        assert!(matches!(result, Err(Error::Client(_))));
+    }
+
+    #[tokio::test]
+    async fn test_watch_stops_after_count() {
+        let stat = r#"{"status":"ok","result":{"size":1}}"#;
+        let (url, requests) = mock_server(vec![(200, stat), (200, stat), (200, stat)]).await;
+        let client = HttpClient::new(url, None);
+        watch(&client, "viking://resources/a", 0.01, Some(2), &output_for(OutputFormat::Json))
+            .await
+            .unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
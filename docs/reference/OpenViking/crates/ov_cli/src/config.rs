@@ -1,22 +1,124 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::error::{Error, Result};
 
+/// On-disk config syntax, detected from the file extension
+#[derive(Debug, Clone, Copy, Default, PartialEq, clap::ValueEnum)]
+pub enum ConfigFormat {
+    #[default]
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// `.toml`, `.yaml`/`.yml` or `.json`; anything else (including `ovcli.conf`) is JSON
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref() {
+            Some("toml") => ConfigFormat::Toml,
+            Some("yaml") | Some("yml") => ConfigFormat::Yaml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// File extension used when writing a new config in this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "conf",
+            ConfigFormat::Toml => "toml",
+            ConfigFormat::Yaml => "yaml",
+        }
+    }
+
+    fn parse(self, content: &str) -> std::result::Result<Value, String> {
+        match self {
+            ConfigFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            ConfigFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+            ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn serialize<T: Serialize>(self, value: &T) -> std::result::Result<String, String> {
+        match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            ConfigFormat::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string()),
+            ConfigFormat::Yaml => serde_yaml::to_string(value).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// How `api_key` is sent: `X-API-Key: <key>` or `Authorization: Bearer <key>`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthScheme {
+    #[default]
+    ApiKey,
+    Bearer,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default = "default_url")]
     pub url: String,
     pub api_key: Option<String>,
+    /// Header scheme for `api_key` (`api-key` or `bearer`; `--bearer` forces bearer)
+    #[serde(default)]
+    pub auth_scheme: AuthScheme,
     pub user: Option<String>,
+    /// Header used to send `user` with every request
+    #[serde(default = "default_user_header")]
+    pub user_header: String,
     #[serde(default = "default_output_format")]
     pub output: String,
+    /// Default for `--human-time`
+    #[serde(default)]
+    pub human_time: bool,
+    /// Per-request HTTP timeout in seconds; 0 disables it (`--timeout-secs` overrides)
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: f64,
+    /// Command shortcuts: alias name -> argument string it expands to
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub aliases: HashMap<String, String>,
+    /// Named server settings selected with `--profile`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, ProfileConfig>,
+    /// Profile applied by `apply_profile`, if any
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+    /// Where each top-level field was set (file, env var or profile); absent means default
+    #[serde(skip)]
+    pub sources: HashMap<String, String>,
+    /// Format of the user config file, kept so `save_default` writes it back the same way
+    #[serde(skip)]
+    pub format: ConfigFormat,
+}
+
+/// Per-profile overrides; unset fields fall back to the top-level values
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
 }
 
 fn default_url() -> String {
     "http://localhost:1933".to_string()
 }
 
+fn default_timeout_secs() -> f64 {
+    60.0
+}
+
+fn default_user_header() -> String {
+    "X-User".to_string()
+}
+
 fn default_output_format() -> String {
     "table".to_string()
 }
@@ -26,51 +128,621 @@ impl Default for Config {
         Self {
             url: "http://localhost:1933".to_string(),
             api_key: None,
+            auth_scheme: AuthScheme::default(),
             user: None,
+            user_header: default_user_header(),
             output: "table".to_string(),
+            human_time: false,
+            timeout_secs: default_timeout_secs(),
+            aliases: HashMap::new(),
+            profiles: HashMap::new(),
+            active_profile: None,
+            sources: HashMap::new(),
+            format: ConfigFormat::default(),
         }
     }
 }
 
 impl Config {
-    /// Load config from default location or create default
-    pub fn load() -> Result<Self> {
-        Self::load_default()
+    /// Load config by layering every existing file from `config_layers`, overlaying the
+    /// `OPENVIKING_*` environment variables, then applying `profile` (or `$OV_PROFILE`
+    /// when `None`) over the top-level settings.
+    ///
+    /// Later layers override earlier ones only for the fields they set; nested
+    /// objects such as `aliases` are merged key by key. Without a profile the flat
+    /// top-level fields are used as they are.
+    pub fn load(profile: Option<&str>) -> Result<Self> {
+        let mut config = Self::load_base(None)?;
+        config.use_profile(profile)?;
+        Ok(config)
     }
 
-    pub fn load_default() -> Result<Self> {
-        let config_path = default_config_path()?;
-        if config_path.exists() {
-            Self::from_file(&config_path.to_string_lossy())
-        } else {
-            Ok(Self::default())
+    /// Load only `path`, in place of the default layers, then apply the environment
+    /// overrides and `profile` as `load` does. Relative paths resolve against the
+    /// current directory, and the file must exist.
+    pub fn load_file(path: &Path, profile: Option<&str>) -> Result<Self> {
+        let mut config = Self::load_base(Some(path))?;
+        config.use_profile(profile)?;
+        Ok(config)
+    }
+
+    /// The first steps of `load` (or `load_file` with `path`): the files and the
+    /// environment overrides, but no profile until `use_profile`
+    pub fn load_base(path: Option<&Path>) -> Result<Self> {
+        let var = |name: &str| std::env::var(name).ok();
+        let Some(path) = path else {
+            let mut config = Self::load_layers(&config_layers()?, var)?;
+            config.format = ConfigFormat::from_path(&default_config_path()?);
+            return Ok(config);
+        };
+        let path = std::path::absolute(path)
+            .map_err(|e| Error::Config(format!("Invalid config path {}: {}", path.display(), e)))?;
+        if !path.is_file() {
+            return Err(Error::Config(format!("Config file {} not found", path.display())));
         }
+        let mut config = Self::load_layers(std::slice::from_ref(&path), var)?;
+        config.format = ConfigFormat::from_path(&path);
+        Ok(config)
     }
 
-    pub fn from_file(path: &str) -> Result<Self> {
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| Error::Config(format!("Failed to read config file: {}", e)))?;
-        let config: Config = serde_json::from_str(&content)
+    /// Apply `profile` (or `$OV_PROFILE` when `None`) to a config from `load_base`
+    pub fn use_profile(&mut self, profile: Option<&str>) -> Result<()> {
+        self.select_profile(profile, |name| std::env::var(name).ok())
+    }
+
+    /// `load` with explicit layer paths and environment lookup
+    #[cfg(test)]
+    fn load_from(
+        layers: &[PathBuf],
+        profile: Option<&str>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        let mut config = Self::load_layers(layers, &var)?;
+        config.select_profile(profile, var)?;
+        Ok(config)
+    }
+
+    /// Merge `layers` and apply the environment overrides, recording in `sources` where
+    /// each top-level field came from
+    fn load_layers(layers: &[PathBuf], var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let mut merged = Value::Object(Default::default());
+        let mut sources = HashMap::new();
+        for path in layers {
+            if path.exists() {
+                let layer = read_layer(path)?;
+                if let Some(obj) = layer.as_object() {
+                    for key in obj.keys() {
+                        sources.insert(key.clone(), format!("file {}", path.display()));
+                    }
+                }
+                merge_layer(&mut merged, layer);
+            }
+        }
+        let mut config: Config = serde_json::from_value(merged)
             .map_err(|e| Error::Config(format!("Failed to parse config file: {}", e)))?;
+        config.sources = sources;
+        config.apply_env(var);
+        Ok(config)
+    }
+
+    fn select_profile(&mut self, profile: Option<&str>, var: impl Fn(&str) -> Option<String>) -> Result<()> {
+        let env_profile = var("OV_PROFILE").filter(|v| !v.is_empty());
+        if let Some(profile) = profile.or(env_profile.as_deref()) {
+            self.apply_profile(profile)?;
+        }
+        self.check_headers()
+    }
+
+    /// Reject settings that are sent as HTTP headers but cannot be: `user_header` must be
+    /// a valid header name, `api_key` and `user` valid header values
+    fn check_headers(&self) -> Result<()> {
+        let invalid = |field: &str| {
+            let source = self.sources.get(field).map(String::as_str).unwrap_or("default");
+            Error::Config(format!("{} cannot be sent as an HTTP header (set by {})", field, source))
+        };
+        if reqwest::header::HeaderName::from_bytes(self.user_header.as_bytes()).is_err() {
+            return Err(invalid("user_header"));
+        }
+        for (field, value) in [("api_key", &self.api_key), ("user", &self.user)] {
+            if value.as_deref().is_some_and(|v| reqwest::header::HeaderValue::from_str(v).is_err()) {
+                return Err(invalid(field));
+            }
+        }
+        Ok(())
+    }
+
+    /// Override `url`, `api_key`, `user` and `output` from `OPENVIKING_URL`,
+    /// `OPENVIKING_API_KEY`, `OPENVIKING_USER` and `OPENVIKING_OUTPUT`; empty variables
+    /// are ignored
+    fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) {
+        let var = |name: &str| var(name).filter(|v| !v.is_empty());
+        if let Some(url) = var("OPENVIKING_URL") {
+            self.url = url;
+            self.sources.insert("url".to_string(), "env OPENVIKING_URL".to_string());
+        }
+        if let Some(api_key) = var("OPENVIKING_API_KEY") {
+            self.api_key = Some(api_key);
+            self.sources.insert("api_key".to_string(), "env OPENVIKING_API_KEY".to_string());
+        }
+        if let Some(user) = var("OPENVIKING_USER") {
+            self.user = Some(user);
+            self.sources.insert("user".to_string(), "env OPENVIKING_USER".to_string());
+        }
+        if let Some(output) = var("OPENVIKING_OUTPUT") {
+            self.output = output;
+            self.sources.insert("output".to_string(), "env OPENVIKING_OUTPUT".to_string());
+        }
+    }
+
+    /// Default output format from `output`, for when `--output` is not given; an
+    /// unknown name is an error naming where it was set
+    pub fn output_format(&self) -> Result<crate::output::OutputFormat> {
+        self.output.parse().map_err(|e| match (e, self.sources.get("output")) {
+            (Error::Config(msg), Some(source)) => Error::Config(format!("{} (set by {})", msg, source)),
+            (e, _) => e,
+        })
+    }
+
+    /// Check settings that parse but cannot work, such as a URL without an HTTP scheme
+    pub fn validate(&self) -> Result<()> {
+        if !(self.url.starts_with("http://") || self.url.starts_with("https://")) {
+            return Err(Error::Config(format!(
+                "url '{}' must start with http:// or https://",
+                self.url
+            )));
+        }
+        Ok(())
+    }
+
+    /// Overlay the named profile's settings onto the top-level ones
+    pub fn apply_profile(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self.profiles.get(name).cloned() else {
+            let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            let available = if names.is_empty() {
+                "none are defined; add one with `config profile add`".to_string()
+            } else {
+                format!("available: {}", names.join(", "))
+            };
+            return Err(Error::Config(format!("Unknown profile '{}' ({})", name, available)));
+        };
+        let source = format!("profile {}", name);
+        if let Some(url) = profile.url {
+            self.url = url;
+            self.sources.insert("url".to_string(), source.clone());
+        }
+        if let Some(api_key) = profile.api_key {
+            self.api_key = Some(api_key);
+            self.sources.insert("api_key".to_string(), source.clone());
+        }
+        if let Some(user) = profile.user {
+            self.user = Some(user);
+            self.sources.insert("user".to_string(), source);
+        }
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
+
+    /// The settings in effect, without the profile table and with the API key redacted,
+    /// for display by `config show`
+    pub fn effective(&self) -> Value {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("profiles");
+            if self.api_key.is_some() {
+                obj.insert("api_key".to_string(), Value::String("[redacted]".to_string()));
+            }
+            if let Some(profile) = &self.active_profile {
+                obj.insert("profile".to_string(), Value::String(profile.clone()));
+            }
+        }
+        value
+    }
+
+    /// One `{field, value, source}` row per effective setting, for `config show --sources`.
+    /// The source is a config file, an environment variable, a profile, or `default`.
+    pub fn source_rows(&self) -> Vec<Value> {
+        let effective = self.effective();
+        let Some(fields) = effective.as_object() else {
+            return Vec::new();
+        };
+        fields
+            .iter()
+            .filter(|(field, _)| field.as_str() != "profile")
+            .map(|(field, value)| {
+                let source = self.sources.get(field).map(String::as_str).unwrap_or("default");
+                serde_json::json!({ "field": field, "value": value, "source": source })
+            })
+            .collect()
+    }
+
+    /// Load a single config file, parsing it according to its extension
+    pub fn from_file(path: &str) -> Result<Self> {
+        let path = Path::new(path);
+        let mut config: Config = serde_json::from_value(read_layer(path)?).map_err(|e| {
+            Error::Config(format!("Failed to parse config file {}: {}", path.display(), e))
+        })?;
+        config.format = ConfigFormat::from_path(path);
         Ok(config)
     }
 
+    /// Write the config to the user config file in the format it was loaded from
     pub fn save_default(&self) -> Result<()> {
         let config_path = default_config_path()?;
-        if let Some(parent) = config_path.parent() {
+        let config_path = if ConfigFormat::from_path(&config_path) == self.format {
+            config_path
+        } else {
+            config_path.with_extension(self.format.extension())
+        };
+        self.save_to(&config_path)
+    }
+
+    /// Write the config to `path` in `self.format`, creating parent directories
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)
                 .map_err(|e| Error::Config(format!("Failed to create config directory: {}", e)))?;
         }
-        let content = serde_json::to_string_pretty(self)
+        let content = self
+            .format
+            .serialize(self)
             .map_err(|e| Error::Config(format!("Failed to serialize config: {}", e)))?;
-        std::fs::write(&config_path, content)
+        std::fs::write(path, content)
             .map_err(|e| Error::Config(format!("Failed to write config file: {}", e)))?;
         Ok(())
     }
 }
 
+/// Edit the user config file in place, keeping its format and leaving unrelated keys untouched.
+///
+/// Only the user layer is rewritten, so values inherited from other layers are not copied into it.
+pub fn update_user_config(
+    edit: impl FnOnce(&mut serde_json::Map<String, Value>) -> Result<()>,
+) -> Result<PathBuf> {
+    let path = default_config_path()?;
+    update_config_file(&path, edit)?;
+    Ok(path)
+}
+
+/// Edit one config file in place, keeping its format; a missing file starts out empty
+pub fn update_config_file(
+    path: &Path,
+    edit: impl FnOnce(&mut serde_json::Map<String, Value>) -> Result<()>,
+) -> Result<()> {
+    let mut layer = if path.exists() {
+        read_layer(path)?
+    } else {
+        Value::Object(Default::default())
+    };
+    if let Value::Object(obj) = &mut layer {
+        edit(obj)?;
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| Error::Config(format!("Failed to create config directory: {}", e)))?;
+    }
+    let content = ConfigFormat::from_path(path)
+        .serialize(&layer)
+        .map_err(|e| Error::Config(format!("Failed to serialize config: {}", e)))?;
+    std::fs::write(path, content)
+        .map_err(|e| Error::Config(format!("Failed to write config file: {}", e)))?;
+    Ok(())
+}
+
+/// Keys `config set` and `config unset` accept
+pub const SETTABLE_KEYS: [&str; 4] = ["url", "api_key", "user", "output"];
+
+fn check_settable(key: &str) -> Result<()> {
+    if SETTABLE_KEYS.contains(&key) {
+        Ok(())
+    } else {
+        Err(Error::Config(format!(
+            "Unknown config key '{}' (supported: {})",
+            key,
+            SETTABLE_KEYS.join(", ")
+        )))
+    }
+}
+
+/// Set `key` in a config file's top-level mapping, rejecting values `validate` would refuse
+pub fn set_key(obj: &mut serde_json::Map<String, Value>, key: &str, value: &str) -> Result<()> {
+    check_settable(key)?;
+    obj.insert(key.to_string(), Value::String(value.to_string()));
+    let config: Config = serde_json::from_value(Value::Object(obj.clone()))
+        .map_err(|e| Error::Config(format!("Invalid value for {}: {}", key, e)))?;
+    config.validate()
+}
+
+/// Remove `key` from a config file's top-level mapping so it falls back to the default
+/// (or a lower layer); returns whether it was set
+pub fn unset_key(obj: &mut serde_json::Map<String, Value>, key: &str) -> Result<bool> {
+    check_settable(key)?;
+    Ok(obj.remove(key).is_some())
+}
+
+/// Config files in precedence order, lowest first: system, user, `$OPENVIKING_CONFIG`
+pub fn config_layers() -> Result<Vec<PathBuf>> {
+    let mut layers = vec![PathBuf::from(SYSTEM_CONFIG_PATH), default_config_path()?];
+    if let Some(path) = std::env::var_os("OPENVIKING_CONFIG").filter(|p| !p.is_empty()) {
+        layers.push(PathBuf::from(path));
+    }
+    Ok(layers)
+}
+
+/// Where settings come from, lowest precedence first, for error messages
+pub const PRECEDENCE: &str = "/etc/openviking/ovcli.conf < ~/.openviking/ovcli.conf < $OPENVIKING_CONFIG \
+    < OPENVIKING_URL/OPENVIKING_API_KEY/OPENVIKING_USER/OPENVIKING_OUTPUT < --profile/OV_PROFILE";
+
+const SYSTEM_CONFIG_PATH: &str = "/etc/openviking/ovcli.conf";
+
+fn read_layer(path: &Path) -> Result<Value> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::Config(format!("Failed to read config file {}: {}", path.display(), e)))?;
+    let layer = ConfigFormat::from_path(path)
+        .parse(&content)
+        .map_err(|e| Error::Config(format!("Failed to parse config file {}: {}", path.display(), e)))?;
+    if !layer.is_object() {
+        return Err(Error::Config(format!(
+            "Config file {} must contain a key/value mapping",
+            path.display()
+        )));
+    }
+    Ok(layer)
+}
+
+/// Overlay `layer` onto `base`, recursing into objects present in both
+fn merge_layer(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) if existing.is_object() && value.is_object() => {
+                        merge_layer(existing, value)
+                    }
+                    _ => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+/// User config file: the first existing of `ovcli.conf`, `ovcli.toml`, `ovcli.yaml`
+/// and `ovcli.yml` under `~/.openviking`, or `ovcli.conf` if none exists
 pub fn default_config_path() -> Result<PathBuf> {
+    let dir = config_dir()?;
+    Ok(USER_CONFIG_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| dir.join(USER_CONFIG_NAMES[0])))
+}
+
+const USER_CONFIG_NAMES: [&str; 4] = ["ovcli.conf", "ovcli.toml", "ovcli.yaml", "ovcli.yml"];
+
+fn config_dir() -> Result<PathBuf> {
     let home = dirs::home_dir()
         .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
-    Ok(home.join(".openviking").join("ovcli.conf"))
+    Ok(home.join(".openviking"))
+}
+
+/// Path a fresh user config in `format` would be written to
+pub fn user_config_path(format: ConfigFormat) -> Result<PathBuf> {
+    Ok(config_dir()?.join(format!("ovcli.{}", format.extension())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_later_layers_override_only_set_fields() {
+        let mut merged = json!({});
+        merge_layer(
+            &mut merged,
+            json!({"url": "http://team:1933", "user": "shared", "aliases": {"recent": "ls", "docs": "ls viking://docs"}}),
+        );
+        merge_layer(&mut merged, json!({"user": "alice", "aliases": {"recent": "ls --sort mtime"}}));
+
+        let config: Config = serde_json::from_value(merged).unwrap();
+        assert_eq!(config.url, "http://team:1933");
+        assert_eq!(config.user.as_deref(), Some("alice"));
+        assert_eq!(config.aliases["recent"], "ls --sort mtime");
+        assert_eq!(config.aliases["docs"], "ls viking://docs");
+        assert_eq!(config.user_header, "X-User");
+    }
+
+    fn sample_config(format: ConfigFormat) -> Config {
+        Config {
+            url: "http://team:1933".to_string(),
+            api_key: Some("secret".to_string()),
+            user: Some("alice".to_string()),
+            human_time: true,
+            aliases: HashMap::from([("recent".to_string(), "ls --sort mtime".to_string())]),
+            format,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(ConfigFormat::from_path(Path::new("ovcli.toml")), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path(Path::new("ovcli.YML")), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path(Path::new("ovcli.yaml")), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path(Path::new("ovcli.json")), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::from_path(Path::new("ovcli.conf")), ConfigFormat::Json);
+    }
+
+    #[test]
+    fn test_round_trip_all_formats() {
+        let dir = std::env::temp_dir().join(format!("ov-config-test-{}", std::process::id()));
+        for (format, name) in [
+            (ConfigFormat::Json, "ovcli.json"),
+            (ConfigFormat::Toml, "ovcli.toml"),
+            (ConfigFormat::Yaml, "ovcli.yml"),
+        ] {
+            let path = dir.join(name);
+            sample_config(format).save_to(&path).unwrap();
+            let loaded = Config::from_file(path.to_str().unwrap()).unwrap();
+            assert_eq!(loaded.format, format);
+            assert_eq!(loaded.url, "http://team:1933");
+            assert_eq!(loaded.api_key.as_deref(), Some("secret"));
+            assert_eq!(loaded.user.as_deref(), Some("alice"));
+            assert!(loaded.human_time);
+            assert_eq!(loaded.aliases["recent"], "ls --sort mtime");
+            assert_eq!(loaded.user_header, "X-User");
+        }
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_profile_overrides_only_its_fields() {
+        let mut config: Config = serde_json::from_value(json!({
+            "url": "http://localhost:1933",
+            "api_key": "base-key",
+            "user": "alice",
+            "profiles": {
+                "prod": {"url": "https://prod:1933", "user": "deploy"},
+                "staging": {"url": "https://staging:1933", "api_key": "staging-key"}
+            }
+        }))
+        .unwrap();
+
+        let mut prod = config.clone();
+        prod.apply_profile("prod").unwrap();
+        assert_eq!(prod.url, "https://prod:1933");
+        assert_eq!(prod.api_key.as_deref(), Some("base-key"));
+        assert_eq!(prod.user.as_deref(), Some("deploy"));
+        assert_eq!(prod.active_profile.as_deref(), Some("prod"));
+        assert_eq!(prod.effective()["profile"], "prod");
+        assert!(prod.effective().get("profiles").is_none());
+
+        config.apply_profile("staging").unwrap();
+        assert_eq!(config.url, "https://staging:1933");
+        assert_eq!(config.api_key.as_deref(), Some("staging-key"));
+        assert_eq!(config.user.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn test_unknown_profile_lists_available_names() {
+        let mut config: Config =
+            serde_json::from_value(json!({"profiles": {"prod": {}, "dev": {}}})).unwrap();
+        let err = config.apply_profile("qa").unwrap_err().to_string();
+        assert!(err.contains("Unknown profile 'qa'"));
+        assert!(err.contains("available: dev, prod"));
+    }
+
+    #[test]
+    fn test_env_overrides_file_values() {
+        let mut config: Config =
+            serde_json::from_value(json!({"url": "http://file:1933", "api_key": "file-key", "user": "alice"})).unwrap();
+        config.apply_env(|name| match name {
+            "OPENVIKING_URL" => Some("http://env:1933".to_string()),
+            "OPENVIKING_USER" => Some(String::new()),
+            _ => None,
+        });
+        assert_eq!(config.url, "http://env:1933");
+        assert_eq!(config.api_key.as_deref(), Some("file-key"));
+        assert_eq!(config.user.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn test_output_format_from_env() {
+        let mut config = Config::default();
+        assert_eq!(config.output_format().unwrap(), crate::output::OutputFormat::Table);
+        config.apply_env(|name| (name == "OPENVIKING_OUTPUT").then(|| "csv".to_string()));
+        assert_eq!(config.output_format().unwrap(), crate::output::OutputFormat::Csv);
+        assert_eq!(config.sources["output"], "env OPENVIKING_OUTPUT");
+
+        config.apply_env(|name| (name == "OPENVIKING_OUTPUT").then(|| "xml".to_string()));
+        let err = config.output_format().unwrap_err();
+        assert!(matches!(err, Error::Config(msg) if msg.contains("'xml'") && msg.contains("env OPENVIKING_OUTPUT")));
+    }
+
+    #[test]
+    fn test_env_beats_file_beats_default() {
+        let dir = std::env::temp_dir().join(format!("ov-config-env-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ovcli.conf");
+        std::fs::write(&path, r#"{"url": "http://file:1933", "api_key": "file-key"}"#).unwrap();
+
+        let env = |name: &str| match name {
+            "OPENVIKING_API_KEY" => Some("env-key".to_string()),
+            _ => None,
+        };
+        let config = Config::load_from(std::slice::from_ref(&path), None, env).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(config.url, "http://file:1933");
+        assert_eq!(config.api_key.as_deref(), Some("env-key"));
+        assert_eq!(config.output, "table");
+        let rows = config.source_rows();
+        let source_of = |field: &str| {
+            rows.iter()
+                .find(|r| r["field"] == field)
+                .map(|r| r["source"].as_str().unwrap().to_string())
+                .unwrap()
+        };
+        assert_eq!(source_of("url"), format!("file {}", path.display()));
+        assert_eq!(source_of("api_key"), "env OPENVIKING_API_KEY");
+        assert_eq!(source_of("output"), "default");
+    }
+
+    #[test]
+    fn test_invalid_header_settings_are_rejected() {
+        let dir = std::env::temp_dir().join(format!("ov-config-header-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ovcli.conf");
+        std::fs::write(&path, r#"{"user_header": "X User"}"#).unwrap();
+        let bad_name = Config::load_from(std::slice::from_ref(&path), None, |_| None).unwrap_err();
+        std::fs::write(&path, r#"{"user": "alice"}"#).unwrap();
+        let env = |name: &str| (name == "OPENVIKING_API_KEY").then(|| "key\nX-Injected: 1".to_string());
+        let bad_value = Config::load_from(std::slice::from_ref(&path), None, env).unwrap_err();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert!(matches!(bad_name, Error::Config(msg) if msg.contains("user_header")));
+        assert!(matches!(bad_value, Error::Config(msg) if msg.contains("api_key") && msg.contains("env OPENVIKING_API_KEY")));
+    }
+
+    #[test]
+    fn test_set_and_unset_keys_in_file() {
+        let dir = std::env::temp_dir().join(format!("ov-config-set-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ovcli.toml");
+        std::fs::write(&path, "user = \"alice\"\n").unwrap();
+
+        update_config_file(&path, |obj| {
+            set_key(obj, "url", "https://viking:1933")?;
+            set_key(obj, "api_key", "secret")
+        })
+        .unwrap();
+        let config = Config::from_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(config.url, "https://viking:1933");
+        assert_eq!(config.api_key.as_deref(), Some("secret"));
+        assert_eq!(config.user.as_deref(), Some("alice"));
+        assert_eq!(config.effective()["api_key"], "[redacted]");
+        assert!(!config.source_rows().iter().any(|r| r["value"] == "secret"));
+
+        update_config_file(&path, |obj| unset_key(obj, "url").map(|_| ())).unwrap();
+        assert_eq!(Config::from_file(path.to_str().unwrap()).unwrap().url, default_url());
+
+        let unknown = update_config_file(&path, |obj| set_key(obj, "colour", "red")).unwrap_err();
+        assert!(matches!(unknown, Error::Config(msg) if msg.contains("Unknown config key 'colour'")));
+        let bad_url = update_config_file(&path, |obj| set_key(obj, "url", "ftp://x")).unwrap_err();
+        assert!(matches!(bad_url, Error::Config(_)));
+        // Rejected edits leave the file untouched
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("ftp://"));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_file_requires_existing_file() {
+        let missing = Path::new("definitely-missing-ovcli.conf");
+        let err = Config::load_file(missing, None).unwrap_err();
+        let cwd = std::env::current_dir().unwrap();
+        assert!(matches!(err, Error::Config(msg) if msg.contains(&cwd.join(missing).display().to_string())));
+    }
 }
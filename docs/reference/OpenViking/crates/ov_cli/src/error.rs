@@ -14,12 +14,19 @@ pub enum Error {
     #[error("Client error: {0}")]
     Client(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Parse error: {0}")]
     Parse(String),
 
     #[error("Output error: {0}")]
     Output(String),
 
+    /// `--dry-run` stopped a request whose response the caller could not do without
+    #[error("dry run: request not sent")]
+    DryRun,
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -30,6 +37,7 @@ pub enum Error {
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// CLI-specific error type for command handlers
+#[allow(dead_code)]
 #[derive(Error, Debug)]
 #[error("{message}")]
 pub struct CliError {
@@ -38,6 +46,7 @@ pub struct CliError {
     pub exit_code: i32,
 }
 
+#[allow(dead_code)]
 impl CliError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
@@ -62,19 +71,59 @@ impl CliError {
             exit_code: 3,
         }
     }
+
+    /// Server-reported error; `code` is the `[CODE]` prefix of the message when present
+    pub fn api(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let code = message
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .map(|(code, _)| code.to_string())
+            .unwrap_or_else(|| "API_ERROR".to_string());
+        Self { message, code, exit_code: 4 }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            code: "PARSE_ERROR".to_string(),
+            exit_code: 5,
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            code: "NOT_FOUND".to_string(),
+            exit_code: 6,
+        }
+    }
 }
 
+/// Exit codes by error class, shown in `--help`
+pub const EXIT_CODES_HELP: &str = "Exit codes:
+  0  success
+  1  other errors (bad arguments, local files, --abort-on, unhealthy)
+  2  configuration error
+  3  network error (server unreachable or timed out)
+  4  API error reported by the server
+  5  unparseable response
+  6  not found (e.g. `stat` of a missing URI)";
+
 impl From<Error> for CliError {
     fn from(err: Error) -> Self {
         match err {
             Error::Config(msg) => CliError::config(msg),
             Error::Network(msg) => CliError::network(msg),
-            Error::Api(msg) => CliError::new(format!("API error: {}", msg)),
+            Error::Api(msg) if msg.starts_with("[NOT_FOUND]") => CliError::not_found(msg),
+            Error::Api(msg) => CliError::api(msg),
             Error::Client(msg) => CliError::new(format!("Client error: {}", msg)),
-            Error::Parse(msg) => CliError::new(format!("Parse error: {}", msg)),
+            Error::Conflict(msg) => CliError::api(format!("Conflict: {}", msg)),
+            Error::Parse(msg) => CliError::parse(msg),
             Error::Output(msg) => CliError::new(format!("Output error: {}", msg)),
+            Error::DryRun => CliError::new("dry run: request not sent"),
             Error::Io(e) => CliError::new(format!("IO error: {}", e)),
-            Error::Serialization(e) => CliError::new(format!("Serialization error: {}", e)),
+            Error::Serialization(e) => CliError::parse(format!("Serialization error: {}", e)),
         }
     }
 }
@@ -98,3 +147,31 @@ impl From<serde_json::Error> for CliError {
         CliError::new(format!("JSON error: {}", err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes_by_error_class() {
+        let exit = |err: Error| CliError::from(err).exit_code;
+        assert_eq!(exit(Error::Config("x".into())), 2);
+        assert_eq!(exit(Error::Network("x".into())), 3);
+        assert_eq!(exit(Error::Api("[INTERNAL] boom".into())), 4);
+        assert_eq!(exit(Error::Parse("x".into())), 5);
+        assert_eq!(exit(Error::Api("[NOT_FOUND] viking://a".into())), 6);
+        assert_eq!(exit(Error::Client("x".into())), 1);
+
+        assert_eq!(CliError::from(Error::Api("[INTERNAL] boom".into())).code, "INTERNAL");
+        assert_eq!(CliError::from(Error::Api("HTTP error 500".into())).code, "API_ERROR");
+    }
+
+    #[test]
+    fn test_api_error_keeps_bracketed_code_in_message() {
+        let err = CliError::from(Error::Api("[INVALID_URI] bad uri".into()));
+        assert_eq!(err.code, "INVALID_URI");
+        assert_eq!(err.message, "[INVALID_URI] bad uri");
+        // A message that merely contains brackets has no code
+        assert_eq!(CliError::from(Error::Api("bad uri [x]".into())).code, "API_ERROR");
+    }
+}
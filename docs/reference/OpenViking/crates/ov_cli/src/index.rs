@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+
+/// Age after which `--offline` results carry a staleness warning
+pub const STALE_AFTER_SECS: u64 = 24 * 60 * 60;
+
+/// Local snapshot of a recursive `ls`, used to serve `ls`/`glob` with `--offline`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Index {
+    /// URI the snapshot was taken from
+    pub root: String,
+    /// Unix seconds when the snapshot was taken
+    pub refreshed_at: u64,
+    pub entries: Vec<Value>,
+}
+
+impl Index {
+    pub fn new(root: &str, entries: Vec<Value>) -> Self {
+        Self {
+            root: root.to_string(),
+            refreshed_at: now_secs(),
+            entries,
+        }
+    }
+
+    /// Load the index from its default location
+    pub fn load() -> Result<Self> {
+        let path = default_index_path()?;
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            Error::Client(format!(
+                "No local index at {} ({}); run `ov index refresh` first",
+                path.display(),
+                e
+            ))
+        })?;
+        serde_json::from_str(&content)
+            .map_err(|e| Error::Parse(format!("Failed to parse index: {}", e)))
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        let path = default_index_path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string(self)?)?;
+        Ok(path)
+    }
+
+    /// Seconds since the snapshot was taken
+    pub fn age_secs(&self) -> u64 {
+        now_secs().saturating_sub(self.refreshed_at)
+    }
+
+    /// Print a warning to stderr when the snapshot is older than `STALE_AFTER_SECS`
+    pub fn warn_if_stale(&self, out: &crate::output::Output) {
+        let age = self.age_secs();
+        if age > STALE_AFTER_SECS {
+            out.warn(format!(
+                "local index is {}h old; run `ov index refresh` to update it",
+                age / 3600
+            ));
+        }
+    }
+
+    /// Entries directly under `uri`, or every descendant when `recursive`
+    pub fn ls(&self, uri: &str, recursive: bool) -> Result<Vec<Value>> {
+        let base = self.check_covered(uri)?;
+        Ok(self
+            .entries
+            .iter()
+            .filter(|entry| {
+                let Some(rel) = entry_uri(entry).and_then(|u| relative_to(u, &base)) else {
+                    return false;
+                };
+                recursive || !rel.contains('/')
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// URIs under `uri` whose relative path matches `pattern`, like the server's `glob`
+    pub fn glob(&self, pattern: &str, uri: &str) -> Result<Vec<String>> {
+        let base = self.check_covered(uri)?;
+        let pattern = glob::Pattern::new(pattern)
+            .map_err(|e| Error::Client(format!("Invalid glob pattern: {}", e)))?;
+        Ok(self
+            .entries
+            .iter()
+            .filter_map(entry_uri)
+            .filter(|u| relative_to(u, &base).is_some_and(|rel| pattern.matches(rel)))
+            .map(|u| normalize(u).to_string())
+            .collect())
+    }
+
+    fn check_covered(&self, uri: &str) -> Result<String> {
+        let base = normalize(uri).to_string();
+        let root = normalize(&self.root);
+        if base != root && relative_to(&base, root).is_none() {
+            return Err(Error::Client(format!(
+                "{} is outside the local index (indexed from {})",
+                uri, self.root
+            )));
+        }
+        Ok(base)
+    }
+}
+
+fn entry_uri(entry: &Value) -> Option<&str> {
+    entry.get("uri").and_then(|u| u.as_str())
+}
+
+/// Strip trailing slashes, keeping the scheme root (`viking://`) intact
+fn normalize(uri: &str) -> &str {
+    if uri.ends_with("://") {
+        return uri;
+    }
+    let trimmed = uri.trim_end_matches('/');
+    if trimmed.ends_with(':') {
+        &uri[..trimmed.len() + 2]
+    } else {
+        trimmed
+    }
+}
+
+/// Path of `uri` relative to `base`, if it lies strictly below it
+fn relative_to<'a>(uri: &'a str, base: &str) -> Option<&'a str> {
+    let rel = normalize(uri).strip_prefix(base)?;
+    let rel = if base.ends_with("://") { rel } else { rel.strip_prefix('/')? };
+    (!rel.is_empty()).then_some(rel)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn default_index_path() -> Result<PathBuf> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+    Ok(home.join(".openviking").join("index.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn index() -> Index {
+        Index::new(
+            "viking://",
+            vec![
+                json!({"uri": "viking://resources/", "isDir": true}),
+                json!({"uri": "viking://resources/docs/", "isDir": true}),
+                json!({"uri": "viking://resources/docs/guide.md", "isDir": false}),
+                json!({"uri": "viking://resources/notes.md", "isDir": false}),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_ls_direct_children_and_recursive() {
+        let idx = index();
+        let names = |v: Vec<Value>| -> Vec<String> {
+            v.iter().map(|e| e["uri"].as_str().unwrap().to_string()).collect()
+        };
+        assert_eq!(
+            names(idx.ls("viking://resources", false).unwrap()),
+            vec!["viking://resources/docs/", "viking://resources/notes.md"]
+        );
+        assert_eq!(idx.ls("viking://resources/", true).unwrap().len(), 3);
+        assert_eq!(idx.ls("viking://", false).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_glob_matches_relative_paths() {
+        let idx = index();
+        assert_eq!(
+            idx.glob("**/*.md", "viking://resources").unwrap(),
+            vec!["viking://resources/docs/guide.md", "viking://resources/notes.md"]
+        );
+        assert_eq!(
+            idx.glob("docs/*", "viking://resources").unwrap(),
+            vec!["viking://resources/docs/guide.md"]
+        );
+    }
+
+    #[test]
+    fn test_uri_outside_index_is_an_error() {
+        let idx = Index::new("viking://resources", vec![]);
+        assert!(idx.ls("viking://memories", false).is_err());
+        assert!(idx.ls("viking://resources", false).is_ok());
+    }
+}
@@ -0,0 +1,325 @@
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+/// Parse a byte rate such as `512K`, `2M` or `1048576` (suffixes are powers of 1024)
+pub fn parse_rate(s: &str) -> std::result::Result<u64, String> {
+    let s = s.trim();
+    let trimmed = s.trim_end_matches("/s").trim_end_matches(['B', 'b']);
+    let (digits, multiplier) = match trimmed.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => (&trimmed[..trimmed.len() - 1], 1024),
+        Some('M') => (&trimmed[..trimmed.len() - 1], 1024 * 1024),
+        Some('G') => (&trimmed[..trimmed.len() - 1], 1024 * 1024 * 1024),
+        _ => (trimmed, 1),
+    };
+    let value: f64 = digits
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid rate '{}', expected bytes/s like 512K or 2M", s))?;
+    let rate = (value * multiplier as f64) as u64;
+    if rate == 0 {
+        return Err("rate must be greater than zero".to_string());
+    }
+    Ok(rate)
+}
+
+/// Token bucket holding at most one second's worth of bytes
+#[derive(Debug)]
+pub struct Throttle {
+    rate: u64,
+    available: f64,
+    last: Instant,
+}
+
+impl Throttle {
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate,
+            available: rate as f64,
+            last: Instant::now(),
+        }
+    }
+
+    /// Largest chunk worth passing through at once
+    pub fn chunk_size(&self) -> usize {
+        (self.rate as usize).clamp(1, 64 * 1024)
+    }
+
+    /// Take `bytes` tokens at `now`, returning how long to wait to stay under the rate
+    pub fn reserve(&mut self, bytes: usize, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.available = (self.available + elapsed * self.rate as f64).min(self.rate as f64);
+        self.available -= bytes as f64;
+        if self.available >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.available / self.rate as f64)
+        }
+    }
+
+    /// Take `bytes` tokens, sleeping without blocking the runtime until they are within the rate
+    pub async fn pace(&mut self, bytes: usize) {
+        let wait = self.reserve(bytes, Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Decodes the `result` string of a `{"status": ..., "result": "..."}` envelope as the
+/// body arrives, so the content can be written without holding the whole body.
+///
+/// The bytes before the string are kept: if `result` turns out not to be a string (or
+/// is missing, as in an error envelope) the rest is buffered and `finish` hands the
+/// whole envelope back to be parsed like any other response.
+#[derive(Debug, Default)]
+pub struct ResultStream {
+    state: StreamState,
+    head: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    string: Vec<u8>,
+    last_string: Option<Vec<u8>>,
+    before_result: bool,
+}
+
+#[derive(Debug, Default)]
+enum StreamState {
+    /// Scanning the envelope for the `result` key
+    #[default]
+    Envelope,
+    /// Inside the result string; `escape` holds an escape sequence split across chunks
+    Content { escape: Vec<u8>, high_surrogate: Option<u16> },
+    /// Past the end of the result string
+    Done,
+    /// `result` is not a string, so the body is parsed whole at the end
+    Buffered,
+}
+
+impl ResultStream {
+    /// Feed the next body chunk, writing any decoded content to `w`
+    pub fn feed(&mut self, chunk: &[u8], w: &mut dyn Write) -> Result<()> {
+        let mut rest = chunk;
+        while !rest.is_empty() {
+            match self.state {
+                StreamState::Envelope => {
+                    self.scan(rest[0]);
+                    rest = &rest[1..];
+                }
+                StreamState::Content { .. } => {
+                    let used = self.decode(rest, w)?;
+                    rest = &rest[used..];
+                }
+                StreamState::Done => return Ok(()),
+                StreamState::Buffered => {
+                    self.head.extend_from_slice(rest);
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// End of body: `None` once the content was written, otherwise the whole envelope
+    pub fn finish(self) -> Result<Option<Vec<u8>>> {
+        match self.state {
+            StreamState::Done => Ok(None),
+            StreamState::Content { .. } => {
+                Err(Error::Network("Response ended in the middle of the content".to_string()))
+            }
+            StreamState::Envelope | StreamState::Buffered => Ok(Some(self.head)),
+        }
+    }
+
+    /// Track one envelope byte, noting when the value of the top-level `result` key starts
+    fn scan(&mut self, b: u8) {
+        self.head.push(b);
+        if self.before_result {
+            match b {
+                b' ' | b'\t' | b'\n' | b'\r' => {}
+                b'"' => {
+                    self.head.clear();
+                    self.state = StreamState::Content { escape: Vec::new(), high_surrogate: None };
+                }
+                _ => self.state = StreamState::Buffered,
+            }
+            return;
+        }
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if b == b'\\' {
+                self.escaped = true;
+            } else if b == b'"' {
+                self.in_string = false;
+                let string = std::mem::take(&mut self.string);
+                self.last_string = (self.depth == 1).then_some(string);
+                return;
+            }
+            self.string.push(b);
+            return;
+        }
+        match b {
+            b'"' => self.in_string = true,
+            b':' => self.before_result = self.depth == 1 && self.last_string.as_deref() == Some(b"result"),
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            b',' => self.last_string = None,
+            _ => {}
+        }
+    }
+
+    /// Decode result-string bytes into `w` up to the closing quote, returning how many were used
+    fn decode(&mut self, bytes: &[u8], w: &mut dyn Write) -> Result<usize> {
+        let StreamState::Content { escape, high_surrogate } = &mut self.state else {
+            return Ok(0);
+        };
+        let mut run = 0;
+        for (i, &b) in bytes.iter().enumerate() {
+            if !escape.is_empty() {
+                escape.push(b);
+                if let Some(decoded) = finish_escape(escape, high_surrogate)? {
+                    let mut buf = [0u8; 4];
+                    w.write_all(decoded.encode_utf8(&mut buf).as_bytes())?;
+                }
+                run = i + 1;
+                continue;
+            }
+            if high_surrogate.is_some() && b != b'\\' {
+                return Err(invalid_content());
+            }
+            match b {
+                b'\\' => {
+                    w.write_all(&bytes[run..i])?;
+                    escape.push(b);
+                }
+                b'"' => {
+                    w.write_all(&bytes[run..i])?;
+                    self.state = StreamState::Done;
+                    return Ok(i + 1);
+                }
+                _ => {}
+            }
+        }
+        if escape.is_empty() {
+            w.write_all(&bytes[run..])?;
+        }
+        Ok(bytes.len())
+    }
+}
+
+/// Complete escape sequence (starting with `\`) as a char, clearing `escape`; `None` while
+/// more bytes are needed or when it is the first half of a surrogate pair
+fn finish_escape(escape: &mut Vec<u8>, high_surrogate: &mut Option<u16>) -> Result<Option<char>> {
+    let decoded = match escape[1] {
+        b'u' if escape.len() < 6 => return Ok(None),
+        b'u' => {
+            let hex = std::str::from_utf8(&escape[2..6]).map_err(|_| invalid_content())?;
+            let unit = u16::from_str_radix(hex, 16).map_err(|_| invalid_content())?;
+            escape.clear();
+            match (high_surrogate.take(), unit) {
+                (None, 0xD800..=0xDBFF) => {
+                    *high_surrogate = Some(unit);
+                    return Ok(None);
+                }
+                (Some(high), 0xDC00..=0xDFFF) => {
+                    let code = 0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(unit) - 0xDC00);
+                    return char::from_u32(code).map(Some).ok_or_else(invalid_content);
+                }
+                (None, unit) => return char::from_u32(u32::from(unit)).map(Some).ok_or_else(invalid_content),
+                (Some(_), _) => return Err(invalid_content()),
+            }
+        }
+        _ if high_surrogate.is_some() => return Err(invalid_content()),
+        b'"' => '"',
+        b'\\' => '\\',
+        b'/' => '/',
+        b'b' => '\u{8}',
+        b'f' => '\u{c}',
+        b'n' => '\n',
+        b'r' => '\r',
+        b't' => '\t',
+        _ => return Err(invalid_content()),
+    };
+    escape.clear();
+    Ok(Some(decoded))
+}
+
+fn invalid_content() -> Error {
+    Error::Parse("Invalid escape in response content".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate_suffixes() {
+        assert_eq!(parse_rate("100").unwrap(), 100);
+        assert_eq!(parse_rate("512K").unwrap(), 512 * 1024);
+        assert_eq!(parse_rate("2M").unwrap(), 2 * 1024 * 1024);
+        assert_eq!(parse_rate("1.5m").unwrap(), 1536 * 1024);
+        assert_eq!(parse_rate("2MB/s").unwrap(), 2 * 1024 * 1024);
+        assert!(parse_rate("fast").is_err());
+        assert!(parse_rate("0").is_err());
+    }
+
+    #[test]
+    fn test_throttle_waits_once_burst_is_spent() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(1000);
+        assert_eq!(throttle.reserve(1000, start), Duration::ZERO);
+        assert_eq!(throttle.reserve(500, start), Duration::from_millis(500));
+        // Tokens refill with elapsed time
+        let later = start + Duration::from_secs(2);
+        assert_eq!(throttle.reserve(1000, later), Duration::ZERO);
+    }
+
+    fn stream(body: &str, piece: usize) -> (Vec<u8>, Result<Option<Vec<u8>>>) {
+        let mut decoder = ResultStream::default();
+        let mut out = Vec::new();
+        for chunk in body.as_bytes().chunks(piece) {
+            decoder.feed(chunk, &mut out).unwrap();
+        }
+        (out, decoder.finish())
+    }
+
+    #[test]
+    fn test_result_stream_decodes_across_chunk_boundaries() {
+        let body = r#"{"status":"ok", "result" : "a\"b\\c\n\u00e9\ud83d\ude00 ü {\"result\":1}","time":0.1}"#;
+        let expected = "a\"b\\c\n\u{e9}\u{1f600} ü {\"result\":1}";
+        for piece in [1, 2, 3, 7, body.len()] {
+            let (out, rest) = stream(body, piece);
+            assert_eq!(String::from_utf8(out).unwrap(), expected, "chunks of {}", piece);
+            assert!(rest.unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_result_stream_hands_back_other_envelopes() {
+        // A key named "result" inside a nested object is not the content
+        let body = r#"{"status":"ok","meta":{"result":"x"},"result":null}"#;
+        let (out, rest) = stream(body, 5);
+        assert!(out.is_empty());
+        assert_eq!(rest.unwrap().unwrap(), body.as_bytes());
+
+        let body = r#"{"status":"error","error":{"code":"NOT_FOUND","message":"gone"}}"#;
+        assert_eq!(stream(body, 4).1.unwrap().unwrap(), body.as_bytes());
+
+        let (out, rest) = stream(r#"{"status":"ok","result":"cut off"#, 4);
+        assert_eq!(out, b"cut off");
+        assert!(matches!(rest, Err(Error::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn test_pace_sleeps_once_burst_is_spent() {
+        let mut throttle = Throttle::new(1000);
+        let start = Instant::now();
+        throttle.pace(1000).await;
+        throttle.pace(100).await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
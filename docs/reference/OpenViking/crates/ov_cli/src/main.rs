@@ -1,34 +1,136 @@
+mod alias;
 mod client;
 mod commands;
 mod config;
 mod error;
+mod index;
+mod io;
 mod output;
+mod state;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use std::io::IsTerminal;
 use config::Config;
 use error::{Error, Result};
-use output::OutputFormat;
+use commands::filesystem::Paging;
+use output::{Output, OutputFormat, RenderOptions, SortKey};
 
 /// CLI context shared across commands
 #[derive(Debug, Clone)]
 pub struct CliContext {
     pub config: Config,
-    pub output_format: OutputFormat,
-    pub compact: bool,
+    /// Output format and rendering options, passed to every command
+    pub output: Output,
+    /// Serve `ls`/`glob` from the local index instead of the server
+    pub offline: bool,
+    /// HTTP timeouts: `--timeout-secs` (else config `timeout_secs`) for reads and writes,
+    /// refined by `--connect-timeout`/`--read-timeout`/`--write-timeout`
+    pub timeouts: client::TimeoutConfig,
+    /// Retries per request (`--retries`)
+    pub retries: u32,
+    /// Config file given with `--config`, used instead of the default layers
+    pub config_path: Option<std::path::PathBuf>,
+    /// Log HTTP traffic to stderr (`--debug`)
+    pub debug: bool,
 }
 
 impl CliContext {
-    pub fn new(output_format: OutputFormat, compact: bool) -> Result<Self> {
-        let config = Config::load()?;
+    /// `output_format` is `--output` if given; otherwise `OPENVIKING_OUTPUT` or config `output`
+    pub fn new(
+        config: Config,
+        output_format: Option<OutputFormat>,
+        mut render: RenderOptions,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<Self> {
+        let output_format = match output_format {
+            Some(format) => format,
+            None => config.output_format()?,
+        };
+        render.human_time |= config.human_time;
+        let timeout = client::timeout_from_secs(config.timeout_secs)?;
+        let timeouts = client::TimeoutConfig {
+            read_timeout: timeout,
+            write_timeout: timeout,
+            ..client::TimeoutConfig::default()
+        };
         Ok(Self {
             config,
-            output_format,
-            compact,
+            output: Output::new(output_format, render),
+            offline: false,
+            timeouts,
+            retries: client::DEFAULT_RETRIES,
+            config_path: config_path.map(|p| p.to_path_buf()),
+            debug: false,
         })
     }
 
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Override the configured HTTP timeout for reads and writes; 0 disables it, negative
+    /// values are rejected
+    pub fn with_timeout(mut self, secs: Option<f64>) -> Result<Self> {
+        if let Some(secs) = secs {
+            let timeout = client::timeout_from_secs(secs)?;
+            self.timeouts.read_timeout = timeout;
+            self.timeouts.write_timeout = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Override individual timeouts, taking precedence over `with_timeout`
+    pub fn with_timeouts(mut self, connect: Option<f64>, read: Option<f64>, write: Option<f64>) -> Result<Self> {
+        if let Some(secs) = connect {
+            self.timeouts.connect_timeout = client::timeout_from_secs(secs)?;
+        }
+        if let Some(secs) = read {
+            self.timeouts.read_timeout = client::timeout_from_secs(secs)?;
+        }
+        if let Some(secs) = write {
+            self.timeouts.write_timeout = client::timeout_from_secs(secs)?;
+        }
+        Ok(self)
+    }
+
+    /// Statuses that fail the run when a result reports them (`--abort-on`)
+    pub fn with_abort_on(mut self, statuses: Vec<String>) -> Self {
+        self.output = self.output.with_abort_on(statuses);
+        self
+    }
+
+    pub fn with_sink(mut self, sink: output::ResultSink) -> Self {
+        self.output = self.output.with_sink(sink);
+        self
+    }
+
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Force bearer auth for this invocation (`--bearer`), whatever the config says
+    pub fn with_bearer(mut self, bearer: bool) -> Self {
+        if bearer {
+            self.config.auth_scheme = config::AuthScheme::Bearer;
+        }
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
     pub fn get_client(&self) -> client::HttpClient {
         client::HttpClient::new(&self.config.url, self.config.api_key.clone())
+            .with_auth_scheme(self.config.auth_scheme)
+            .with_user(self.config.user.clone(), &self.config.user_header)
+            .with_timeout_config(self.timeouts)
+            .with_retries(self.retries)
+            .with_dry_run(self.output.opts.dry_run)
+            .with_debug(self.debug)
     }
 }
 
@@ -37,15 +139,144 @@ impl CliContext {
 #[command(about = "OpenViking - An Agent-native context database")]
 #[command(version = env!("CARGO_PKG_VERSION"))]
 #[command(arg_required_else_help = true)]
+#[command(after_help = error::EXIT_CODES_HELP)]
 struct Cli {
-    /// Output format
-    #[arg(short, long, value_enum, default_value = "table", global = true)]
-    output: OutputFormat,
+    /// Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown
+    /// (default: $OPENVIKING_OUTPUT, else config output, else table)
+    #[arg(short, long, value_enum, global = true)]
+    output: Option<OutputFormat>,
+
+    /// Config file to use instead of the default layers (relative to the current directory)
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<std::path::PathBuf>,
+
+    /// Shorthand for --output jsonl: one compact JSON value per line, for piping into jq
+    #[arg(long, global = true)]
+    json_lines: bool,
 
     /// Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output
     #[arg(short, long, global = true, default_value = "true")]
     compact: bool,
 
+    /// Show columns whose values are all empty (disables compact column filtering in tables)
+    #[arg(long, global = true)]
+    show_empty: bool,
+
+    /// Print control characters verbatim instead of escaping them on a terminal
+    #[arg(long, global = true)]
+    raw_control: bool,
+
+    /// Maximum response nesting depth rendered as a table before falling back to raw JSON
+    #[arg(long, global = true, default_value_t = output::DEFAULT_MAX_DEPTH)]
+    max_depth: usize,
+
+    /// Serve ls/glob from the local index (see `index refresh`)
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "COLUMN=WIDTH", value_parser = output::parse_col_width)]
+    col_width: Vec<(String, usize)>,
+
+    /// Per-request HTTP timeout in seconds, e.g. 0.5 or 120; 0 for none (config: timeout_secs,
+    /// default 60). Given before the command, as `wait`/`add-resource --timeout` is the
+    /// server-side processing timeout
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<f64>,
+
+    /// Same as the top-level --timeout, but accepted anywhere on the command line
+    #[arg(long, global = true, value_name = "SECS")]
+    timeout_secs: Option<f64>,
+
+    /// Time allowed to connect to the server in seconds; 0 for none (default 10)
+    #[arg(long, global = true, value_name = "SECS")]
+    connect_timeout: Option<f64>,
+
+    /// Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none
+    #[arg(long, global = true, value_name = "SECS")]
+    read_timeout: Option<f64>,
+
+    /// Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding
+    /// --timeout; 0 for none
+    #[arg(long, global = true, value_name = "SECS")]
+    write_timeout: Option<f64>,
+
+    /// Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only
+    /// when the connection failed)
+    #[arg(long, global = true, value_name = "N", default_value_t = client::DEFAULT_RETRIES)]
+    retries: u32,
+
+    /// Write results to this file instead of stdout; it is only replaced if the command
+    /// succeeds (warnings and errors stay on stderr)
+    #[arg(long, global = true, value_name = "PATH")]
+    output_file: Option<std::path::PathBuf>,
+
+    /// Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr
+    #[arg(long, global = true)]
+    debug: bool,
+
+    /// Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and
+    /// unlink instead print the request they would send as their result
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`
+    #[arg(long, global = true)]
+    bearer: bool,
+
+    /// Use the server settings of a named profile from the config file (env: OV_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered
+    /// too, and accept dotted paths like `meta.size`)
+    #[arg(long, visible_alias = "fields", global = true, value_delimiter = ',', value_name = "KEYS")]
+    columns: Vec<String>,
+
+    /// Colorize tables: auto (terminal only, honours NO_COLOR), always or never
+    #[arg(long, global = true, value_enum, default_value = "auto")]
+    color: output::ColorChoice,
+
+    /// Show full cell values in tables instead of cutting them at 256 columns
+    #[arg(long, global = true)]
+    no_truncate: bool,
+
+    /// Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate
+    #[arg(long, global = true, value_name = "N", conflicts_with = "no_truncate")]
+    truncate: Option<usize>,
+
+    /// Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)
+    #[arg(long, global = true)]
+    no_header: bool,
+
+    /// Show epoch timestamps and durations in human form in tables (config: human_time)
+    #[arg(long, global = true)]
+    human_time: bool,
+
+    /// Print JSON results without the ok/result envelope (scalars print bare)
+    #[arg(long, global = true)]
+    no_envelope: bool,
+
+    /// Never append a newline to raw content, even on a terminal
+    #[arg(long, global = true)]
+    no_newline: bool,
+
+    /// Write read/abstract/overview content byte-for-byte: no added newline, no escaping
+    #[arg(long, global = true)]
+    raw: bool,
+
+    /// Emit progress events and the final result as type-tagged NDJSON on stdout
+    #[arg(long, global = true)]
+    events: bool,
+
+    /// Exit non-zero if any warning was printed
+    #[arg(long, global = true)]
+    fail_on_warning: bool,
+
+    /// Exit non-zero when a successful response has one of these status/state values (comma-separated)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "STATUSES")]
+    abort_on: Vec<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -53,10 +284,17 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Add resources into OpenViking
+    #[command(after_help = "Reading from stdin (PATH is `-`):\n  \
+        The text on stdin becomes the resource content and --to is required.\n  \
+        It is sent inline, so the server does not need to see a local file.\n  \
+        With --type or --stdin-name the text is instead written to a temp file with that\n  \
+        name (which picks the parser and resource name); that needs a server on this host.\n\n  \
+        cat notes.md | ov add-resource - --to viking://resources/notes --stdin-name notes.md")]
     AddResource {
-        /// Local path or URL to import
-        path: String,
-        /// Target URI
+        /// Local paths or URLs to import (several run concurrently), or `-` to read from stdin
+        #[arg(required = true, value_name = "PATH")]
+        paths: Vec<String>,
+        /// Target URI (only with a single path)
         #[arg(long)]
         to: Option<String>,
         /// Reason for import
@@ -71,8 +309,46 @@ enum Commands {
         /// Wait timeout in seconds
         #[arg(long)]
         timeout: Option<f64>,
+        /// Deprecated: use --timeout
+        #[arg(long, hide = true)]
+        wait_timeout: Option<f64>,
+        /// Poll for completion every N seconds instead of one long server-side wait (min 0.5)
+        #[arg(long, value_name = "SECS", value_parser = commands::system::parse_poll_interval, requires = "wait")]
+        poll_interval: Option<f64>,
+        /// Content type of stdin input, as an extension (md) or MIME type (text/markdown)
+        #[arg(long = "type", value_name = "TYPE")]
+        content_type: Option<String>,
+        /// File name for stdin input, used as the resource's display name
+        #[arg(long, value_name = "FILENAME")]
+        stdin_name: Option<String>,
+        /// Upload PATH's bytes even if it looks like a URL, with no fallback to sending the path
+        #[arg(long)]
+        upload: bool,
+        /// Imports to run at once when several paths are given
+        #[arg(long, default_value = "4", value_parser = clap::value_parser!(u16).range(1..))]
+        concurrency: u16,
+    },
+    /// Replace a resource's content and/or update its reason and instruction (needs a server
+    /// with PUT /api/v1/content and PATCH /api/v1/resources)
+    #[command(group = clap::ArgGroup::new("update").required(true).multiple(true))]
+    UpdateResource {
+        /// Viking URI of the resource
+        uri: String,
+        /// Local file with the new content, or `-` to read it from stdin
+        #[arg(long, group = "update")]
+        file: Option<String>,
+        /// New reason for the resource
+        #[arg(long, group = "update")]
+        reason: Option<String>,
+        /// New processing instruction
+        #[arg(long, group = "update")]
+        instruction: Option<String>,
     },
     /// Add a skill into OpenViking
+    ///
+    /// A local directory is zipped and uploaded when the server offers
+    /// `POST /api/v1/skills/upload`. Otherwise its path is sent as-is, and the
+    /// server must be able to read that path on its own host.
     AddSkill {
         /// Skill directory, SKILL.md, or raw content
         data: String,
@@ -82,11 +358,20 @@ enum Commands {
         /// Wait timeout in seconds
         #[arg(long)]
         timeout: Option<f64>,
+        /// Deprecated: use --timeout
+        #[arg(long, hide = true)]
+        wait_timeout: Option<f64>,
+        /// Poll for completion every N seconds instead of one long server-side wait (min 0.5)
+        #[arg(long, value_name = "SECS", value_parser = commands::system::parse_poll_interval, requires = "wait")]
+        poll_interval: Option<f64>,
     },
     /// List relations of a resource
     Relations {
         /// Viking URI
         uri: String,
+        /// Follow relations up to N hops out, listing each edge with its `hop`
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        depth: Option<u32>,
     },
     /// Create relation links from one URI to one or more targets
     Link {
@@ -109,8 +394,11 @@ enum Commands {
     Export {
         /// Source URI
         uri: String,
-        /// Output .ovpack file path
+        /// Output .ovpack file path (a local graph file with --relations-only)
         to: String,
+        /// Export only the relation graph under URI, without content, to a local file
+        #[arg(long)]
+        relations_only: bool,
     },
     /// Import .ovpack into target URI
     Import {
@@ -124,17 +412,32 @@ enum Commands {
         /// Disable vectorization after import
         #[arg(long)]
         no_vectorize: bool,
+        /// Import into a new child directory of the target (named after the pack if omitted)
+        #[arg(long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+        into_new: Option<String>,
+        /// Recreate links from a graph file made by `export --relations-only`, mapping its root onto the target
+        #[arg(long, conflicts_with_all = ["force", "no_vectorize", "into_new"])]
+        relations_only: bool,
     },
     /// Wait for queued async processing to complete
     Wait {
         /// Wait timeout in seconds
         #[arg(long)]
         timeout: Option<f64>,
+        /// Poll for completion every N seconds instead of one long server-side wait (min 0.5)
+        #[arg(long, value_name = "SECS", value_parser = commands::system::parse_poll_interval)]
+        poll_interval: Option<f64>,
     },
     /// Show OpenViking component status
-    Status,
+    Status {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// Quick health check
-    Health,
+    Health {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// System utility commands
     System {
         #[command(subcommand)]
@@ -169,8 +472,29 @@ enum Commands {
         #[arg(short, long)]
         all: bool,
         /// Maximum number of nodes to list
-        #[arg(long = "node-limit", short = 'n', default_value = "1000")]
+        #[arg(long = "node-limit", visible_alias = "limit", short = 'n', default_value = "1000")]
         node_limit: i32,
+        /// Skip this many entries client-side, showing the next --limit (prints "showing A-B of N")
+        #[arg(long, conflicts_with_all = ["page", "all_pages"])]
+        offset: Option<u64>,
+        /// Sort entries client-side: name, path, size, mtime or any other field (numbers sort numerically; rows without it go last)
+        #[arg(long, value_name = "FIELD")]
+        sort: Option<output::SortSpec>,
+        /// Reverse the sort order (sorts by path unless --sort is given)
+        #[arg(long)]
+        reverse: bool,
+        /// Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)
+        #[arg(long)]
+        natural: bool,
+        /// Fetch only this page of results (1-based)
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "all_pages")]
+        page: Option<u64>,
+        /// Entries per page for --page and --all-pages
+        #[arg(long, default_value = "100", value_parser = clap::value_parser!(u64).range(1..))]
+        page_size: u64,
+        /// Fetch page after page until the listing is exhausted
+        #[arg(long)]
+        all_pages: bool,
     },
     /// Get directory tree
     Tree {
@@ -191,14 +515,31 @@ enum Commands {
         /// Directory URI to create
         uri: String,
     },
+    /// Create an empty resource, e.g. as a placeholder for `add-resource --to`
+    Touch {
+        /// Resource URI to create
+        uri: String,
+    },
     /// Remove resource
     #[command(alias = "del", alias = "delete")]
     Rm {
-        /// Viking URI to remove
+        /// Viking URI to remove (the root to search under with --glob)
         uri: String,
         /// Remove recursively
         #[arg(short, long)]
         recursive: bool,
+        /// Remove every resource under URI matching this glob pattern
+        #[arg(long, value_name = "PATTERN")]
+        glob: Option<String>,
+        /// Skip the confirmation prompt for --glob
+        #[arg(short, long, requires = "glob")]
+        force: bool,
+        /// Skip the confirmation prompt for --recursive (required when stdout is not a terminal)
+        #[arg(short, long, conflicts_with = "glob")]
+        yes: bool,
+        /// Parallel removals for --glob
+        #[arg(long, default_value = "4", requires = "glob", value_parser = clap::value_parser!(u16).range(1..))]
+        concurrency: u16,
     },
     /// Move or rename resource
     #[command(alias = "rename")]
@@ -207,16 +548,85 @@ enum Commands {
         from_uri: String,
         /// Target URI
         to_uri: String,
+        /// Abort if the target already exists (checked with stat first; not atomic)
+        #[arg(long, conflicts_with = "interactive")]
+        no_overwrite: bool,
+        /// Ask before overwriting an existing target (fails without a terminal)
+        #[arg(short, long)]
+        interactive: bool,
+    },
+    /// Copy a resource to a new URI
+    Cp {
+        /// Source URI
+        from_uri: String,
+        /// Target URI
+        to_uri: String,
+        /// Copy directories and everything under them
+        #[arg(short, long)]
+        recursive: bool,
+        /// Do not build vectors for the copy
+        #[arg(long)]
+        no_vectorize: bool,
+        /// Overwrite the target if it already exists
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Poll a URI's metadata and show what changed, like watch(1)
+    Watch {
+        /// Viking URI to watch
+        uri: String,
+        /// Seconds between polls
+        #[arg(long, value_name = "SECS", default_value = "5", value_parser = commands::system::parse_poll_interval)]
+        interval: f64,
+        /// Output format for each poll (defaults to --output)
+        #[arg(long, value_enum)]
+        format: Option<commands::watch::WatchFormat>,
+        /// Stop after this many polls (default: until Ctrl-C)
+        #[arg(long)]
+        count: Option<u64>,
+    },
+    /// Report the aggregate size of a subtree
+    Du {
+        /// Viking URI to measure
+        uri: String,
+        /// Report every directory below URI, not just the total
+        #[arg(short, long)]
+        recursive: bool,
+        /// Include files as well as directories
+        #[arg(short, long)]
+        all: bool,
+    },
+    /// Compare the content of two URIs
+    Diff {
+        /// Old side
+        left_uri: String,
+        /// New side
+        right_uri: String,
+        /// Context lines around each change; 0 shows only changed lines
+        #[arg(short = 'U', long, value_name = "LINES", default_value = "3")]
+        unified: usize,
     },
     /// Get resource metadata
     Stat {
-        /// Viking URI to get metadata for
-        uri: String,
+        /// Viking URIs to get metadata for; several are fetched in one request
+        #[arg(required = true)]
+        uris: Vec<String>,
     },
     /// Read file content (L2)
     Read {
         /// Viking URI
         uri: String,
+        /// Cap the content download speed in bytes/s (suffixes K, M, G)
+        #[arg(long, value_name = "RATE", value_parser = io::parse_rate)]
+        rate_limit: Option<u64>,
+    },
+    /// Edit file content in $EDITOR and write it back if changed
+    Edit {
+        /// Viking URI
+        uri: String,
+        /// Edit even if the server sends no version (ETag), so a concurrent change may be overwritten
+        #[arg(long)]
+        force: bool,
     },
     /// Read abstract content (L0)
     Abstract {
@@ -241,6 +651,19 @@ enum Commands {
         /// Score threshold
         #[arg(short, long)]
         threshold: Option<f64>,
+        /// Attach each hit's relations (top 10 hits)
+        #[arg(long)]
+        with_relations: bool,
+        /// Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)
+        #[arg(long, value_name = "FIELD")]
+        sort: Option<String>,
+        /// Sort descending (with --sort)
+        #[arg(long, requires = "sort")]
+        desc: bool,
+        /// Show each hit's relevance score as the first column; JSON output also keeps
+        /// the server's `debug` breakdown, which only servers that accept `explain` send
+        #[arg(long)]
+        explain: bool,
     },
     /// Run context-aware retrieval
     Search {
@@ -249,15 +672,30 @@ enum Commands {
         /// Target URI
         #[arg(short, long, default_value = "")]
         uri: String,
-        /// Session ID for context-aware search
+        /// Session ID for context-aware search (default: the current session from `session use`)
         #[arg(long)]
         session_id: Option<String>,
+        /// Search in a throwaway session that is deleted afterwards
+        #[arg(long, conflicts_with = "session_id")]
+        session_auto: bool,
+        /// Message to seed the --session-auto session with
+        #[arg(long, requires = "session_auto")]
+        context: Option<String>,
         /// Maximum number of results
         #[arg(short = 'n', long, default_value = "10")]
         limit: i32,
         /// Score threshold
         #[arg(short, long)]
         threshold: Option<f64>,
+        /// Attach each hit's relations (top 10 hits)
+        #[arg(long)]
+        with_relations: bool,
+        /// Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)
+        #[arg(long, value_name = "FIELD")]
+        sort: Option<String>,
+        /// Sort descending (with --sort)
+        #[arg(long, requires = "sort")]
+        desc: bool,
     },
     /// Run content pattern search
     Grep {
@@ -268,6 +706,15 @@ enum Commands {
         /// Case insensitive
         #[arg(short, long)]
         ignore_case: bool,
+        /// Match the pattern literally, escaping regex metacharacters (like grep -F)
+        #[arg(short = 'F', long)]
+        fixed_strings: bool,
+        /// Grep every file under the URI, streaming matches as uri:line:text
+        #[arg(short, long)]
+        recursive: bool,
+        /// Maximum number of nodes to list when recursive
+        #[arg(long = "node-limit", short = 'n', default_value = "1000")]
+        node_limit: i32,
     },
     /// Run file glob pattern search
     Glob {
@@ -276,21 +723,61 @@ enum Commands {
         /// Search root URI
         #[arg(short, long, default_value = "viking://")]
         uri: String,
+        /// Sort matches client-side (glob results only carry paths, so name and path apply)
+        #[arg(long, value_enum)]
+        sort: Option<SortKey>,
+        /// Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)
+        #[arg(long)]
+        natural: bool,
     },
     /// Add memory in one shot (creates session, adds messages, commits)
     AddMemory {
         /// Content to memorize. Plain string (treated as user message),
         /// JSON {"role":"...","content":"..."} for a single message,
         /// or JSON array of such objects for multiple messages.
+        /// `@path` reads it from a file and `-` from stdin.
         content: String,
     },
+    /// Run an end-to-end round-trip against a scratch URI and report each step
+    Selftest,
+    /// Local index for offline ls/glob
+    Index {
+        #[command(subcommand)]
+        action: IndexCommands,
+    },
     /// Configuration management
     Config {
         #[command(subcommand)]
         action: ConfigCommands,
     },
+    /// Call any server endpoint directly, e.g. `api GET /api/v1/system/status`
+    Api {
+        /// HTTP method: GET, POST or DELETE
+        #[arg(value_enum, ignore_case = true)]
+        method: commands::api::ApiMethod,
+        /// Endpoint path, including any query string
+        path: String,
+        /// JSON request body (POST defaults to `{}`; not allowed for GET)
+        #[arg(short, long)]
+        body: Option<String>,
+    },
     /// Show CLI version
-    Version,
+    Version {
+        /// Also query the server and print its version ("unavailable" if unreachable)
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Print a shell completion script to stdout
+    #[command(hide = true)]
+    #[command(group = clap::ArgGroup::new("target").required(true))]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(group = "target")]
+        shell: Option<clap_complete::Shell>,
+        /// Shell to generate completions for, as a flag
+        #[arg(long = "shell", value_name = "SHELL", group = "target")]
+        shell_flag: Option<clap_complete::Shell>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -300,23 +787,73 @@ enum SystemCommands {
         /// Wait timeout in seconds
         #[arg(long)]
         timeout: Option<f64>,
+        /// Poll for completion every N seconds instead of one long server-side wait (min 0.5)
+        #[arg(long, value_name = "SECS", value_parser = commands::system::parse_poll_interval)]
+        poll_interval: Option<f64>,
     },
     /// Show component status
-    Status,
+    Status {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// Quick health check
-    Health,
+    Health {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
+}
+
+/// `--watch`/`--interval` for status commands
+#[derive(clap::Args, Clone, Copy)]
+struct WatchArgs {
+    /// Refresh every --interval seconds until Ctrl-C, like watch(1)
+    #[arg(long)]
+    watch: bool,
+    /// Seconds between refreshes with --watch
+    #[arg(long, value_name = "SECS", default_value = "2", requires = "watch", value_parser = commands::system::parse_poll_interval)]
+    interval: f64,
 }
 
 #[derive(Subcommand)]
 enum ObserverCommands {
     /// Get queue status
-    Queue,
+    Queue {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// Get VikingDB status
-    Vikingdb,
+    Vikingdb {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// Get VLM status
-    Vlm,
+    Vlm {
+        /// Send a sample image to the VLM and report the round-trip result and latency
+        #[arg(long)]
+        probe: bool,
+        /// Image to probe with instead of the bundled sample
+        #[arg(long, requires = "probe")]
+        image: Option<String>,
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
     /// Get overall system status
-    System,
+    System {
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
+    /// Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)
+    Poll {
+        /// Seconds between polls
+        #[arg(long, value_name = "SECS", default_value = "2", value_parser = commands::system::parse_poll_interval)]
+        interval: f64,
+        /// Stop after this many polls; 0 runs until Ctrl-C
+        #[arg(long, default_value = "0")]
+        count: u64,
+        /// Exit with an error as soon as the queue depth (pending + in progress) exceeds this
+        #[arg(long)]
+        threshold: Option<u64>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -335,56 +872,265 @@ enum SessionCommands {
         /// Session ID
         session_id: String,
     },
-    /// Add one message to a session
-    AddMessage {
+    /// Write a session's messages to a file or stdout
+    Export {
         /// Session ID
         session_id: String,
+        /// json writes one array; ndjson writes one message per line
+        #[arg(long, value_enum, default_value = "json")]
+        format: commands::session::ExportFormat,
+        /// Destination file; `-` or omitted writes to stdout
+        #[arg(long, value_name = "FILE")]
+        to: Option<std::path::PathBuf>,
+        /// Overwrite the destination file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Add one message to a session
+    AddMessage {
+        /// Session ID (default: the current session from `session use`)
+        session_id: Option<String>,
         /// Message role, e.g. user/assistant
         #[arg(long)]
         role: String,
-        /// Message content
+        /// Message content; `@path` reads it from a file and `-` from stdin
         #[arg(long)]
         content: String,
     },
     /// Commit a session (archive messages and extract memories)
     Commit {
+        /// Session ID (default: the current session from `session use`)
+        session_id: Option<String>,
+    },
+    /// Remember a session as current, for commands run without a session ID
+    Use {
         /// Session ID
         session_id: String,
     },
+    /// Print the current session ID
+    Current,
+    /// Forget the current session
+    Clear,
 }
 
 #[derive(Subcommand)]
 enum ConfigCommands {
-    /// Show current configuration
-    Show,
+    /// Show the effective configuration, after layering and profile selection
+    Show {
+        /// List each setting with where it came from: a file, an env var, a profile or the default
+        #[arg(long)]
+        sources: bool,
+    },
     /// Validate configuration file
     Validate,
+    /// Write a skeleton user config file
+    Init {
+        /// File format; also picks the extension (ovcli.conf, ovcli.toml or ovcli.yaml)
+        #[arg(long, value_enum, default_value = "json")]
+        format: config::ConfigFormat,
+        /// Overwrite an existing config file of the same format
+        #[arg(long)]
+        force: bool,
+    },
+    /// Set url, api_key, user or output in the user config file (or the --config file)
+    Set {
+        /// Setting to change: url, api_key, user or output
+        key: String,
+        /// New value
+        value: String,
+    },
+    /// Remove a setting from the user config file (or the --config file) so its default applies
+    Unset {
+        /// Setting to remove: url, api_key, user or output
+        key: String,
+    },
+    /// Manage named server profiles
+    Profile {
+        #[command(subcommand)]
+        action: ProfileCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommands {
+    /// Add or replace a profile in the user config file
+    Add {
+        /// Profile name
+        name: String,
+        /// Server URL
+        #[arg(long)]
+        url: String,
+        /// API key
+        #[arg(long)]
+        key: Option<String>,
+        /// User to send with every request
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Remove a profile from the user config file
+    Remove {
+        /// Profile name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum IndexCommands {
+    /// Rebuild the local index from a recursive listing
+    Refresh {
+        /// Root URI to index
+        #[arg(default_value = "viking://")]
+        uri: String,
+        /// Maximum number of nodes to list
+        #[arg(long, short = 'n', default_value = "10000")]
+        node_limit: i32,
+    },
 }
 
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
+    let raw_args: Vec<String> = std::env::args().collect();
+    // Loaded once: aliases are expanded from it, then the context is built on it
+    let base_config = Config::load_base(raw_config_path(&raw_args).as_deref());
+    let args = match &base_config {
+        Ok(config) => expand_args(raw_args, config),
+        Err(_) => Ok(raw_args),
+    };
+    let args = match args {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+    };
+    let cli = match Cli::try_parse_from(args) {
+        Ok(cli) => cli,
+        Err(e) => {
+            // Without the config an alias cannot be expanded, so that is the likelier cause
+            if let (Err(config_err), clap::error::ErrorKind::InvalidSubcommand) = (&base_config, e.kind()) {
+                eprintln!("Error: {}", config_err);
+                std::process::exit(2);
+            }
+            e.exit()
+        }
+    };
+
+    // Completions need no config or server, so they are printed before either is loaded
+    if let Commands::Completions { shell, shell_flag } = cli.command {
+        let shell = shell.or(shell_flag).expect("clap requires a shell");
+        if let Err(e) = write_completions(shell, &mut std::io::stdout()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
     
-    let output_format = cli.output;
+    // --events implies machine output so nothing else is interleaved as plain text
+    let output_format = if cli.events {
+        Some(OutputFormat::Json)
+    } else if cli.json_lines {
+        Some(OutputFormat::Jsonl)
+    } else {
+        cli.output
+    };
     let compact = cli.compact;
+    let sink = match cli.output_file.as_deref().map(output::ResultSink::file).transpose() {
+        Ok(sink) => sink.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    // A file gets plain output, as if stdout were piped
+    let is_terminal = std::io::stdout().is_terminal() && cli.output_file.is_none();
+    let warnings = output::Warnings::default();
+    if let Some(width) = cli.truncate.filter(|&width| width > 0 && width < 10) {
+        warnings.warn(format!("--truncate {} leaves table cells almost unreadable", width));
+    }
+    let render = RenderOptions {
+        compact,
+        show_empty: cli.show_empty,
+        escape_control: !cli.raw_control && !cli.raw && is_terminal,
+        max_depth: cli.max_depth,
+        content_newline: !cli.no_newline && !cli.raw && is_terminal,
+        no_envelope: cli.no_envelope,
+        col_widths: cli.col_width.into_iter().collect(),
+        human_time: cli.human_time,
+        events: cli.events,
+        no_truncate: cli.no_truncate || cli.truncate == Some(0),
+        max_col_width: cli.truncate.filter(|&width| width > 0).unwrap_or(output::MAX_COL_WIDTH),
+        no_header: cli.no_header,
+        columns: cli.columns,
+        dry_run: cli.dry_run,
+        color: output::resolve_color(
+            cli.color,
+            is_terminal,
+            std::env::var("NO_COLOR").ok().as_deref(),
+        ),
+        warnings,
+    };
+    colored::control::set_override(render.color);
 
-    let ctx = match CliContext::new(output_format, compact) {
-        Ok(ctx) => ctx,
+    let config = base_config.and_then(|mut config| {
+        if selects_profile(&cli.command) {
+            config.use_profile(cli.profile.as_deref())?;
+        }
+        Ok(config)
+    });
+    let ctx = match config.and_then(|config| CliContext::new(config, output_format, render, cli.config.as_deref()))
+        .and_then(|ctx| ctx.with_timeout(cli.timeout_secs.or(cli.timeout)))
+        .and_then(|ctx| ctx.with_timeouts(cli.connect_timeout, cli.read_timeout, cli.write_timeout))
+    {
+        Ok(ctx) => ctx
+            .with_offline(cli.offline)
+            .with_retries(cli.retries)
+            .with_bearer(cli.bearer)
+            .with_debug(cli.debug)
+            .with_abort_on(cli.abort_on)
+            .with_sink(sink),
         Err(e) => {
             eprintln!("Error: {}", e);
             std::process::exit(2);
         }
     };
+    let out = ctx.output.clone();
+    let fail_on_warning = cli.fail_on_warning;
+    let cli_events = cli.events;
+    let to_file = cli.output_file.is_some();
 
     let result = match cli.command {
-        Commands::AddResource { path, to, reason, instruction, wait, timeout } => {
-            handle_add_resource(path, to, reason, instruction, wait, timeout, ctx).await
+        Commands::AddResource { paths, to, reason, instruction, wait, timeout, wait_timeout, poll_interval, content_type, stdin_name, upload, concurrency } => {
+            let timeout = fold_wait_timeout(timeout, wait_timeout, &ctx.output);
+            if let [path] = paths.as_slice() {
+                handle_add_resource(path.clone(), to, reason, instruction, wait, timeout, poll_interval, content_type, stdin_name, upload, ctx).await
+            } else if content_type.is_some() || stdin_name.is_some() {
+                Err(Error::Client("--type and --stdin-name only apply to stdin input (path '-')".to_string()))
+            } else {
+                let client = ctx.get_client();
+                commands::resources::add_resources(
+                    &client, &paths, to, reason, instruction, wait, timeout, poll_interval, upload,
+                    concurrency as usize, &ctx.output
+                ).await
+            }
+        }
+        Commands::UpdateResource { uri, file, reason, instruction } => {
+            let client = ctx.get_client();
+            commands::resources::update_resource(
+                &client,
+                &uri,
+                file.as_deref(),
+                reason.as_deref(),
+                instruction.as_deref(),
+                &ctx.output,
+            )
+            .await
         }
-        Commands::AddSkill { data, wait, timeout } => {
-            handle_add_skill(data, wait, timeout, ctx).await
+        Commands::AddSkill { data, wait, timeout, wait_timeout, poll_interval } => {
+            let timeout = fold_wait_timeout(timeout, wait_timeout, &ctx.output);
+            handle_add_skill(data, wait, timeout, poll_interval, ctx).await
         }
-        Commands::Relations { uri } => {
-            handle_relations(uri, ctx).await
+        Commands::Relations { uri, depth } => {
+            handle_relations(uri, depth, ctx).await
         }
         Commands::Link { from_uri, to_uris, reason } => {
             handle_link(from_uri, to_uris, reason, ctx).await
@@ -392,26 +1138,32 @@ async fn main() {
         Commands::Unlink { from_uri, to_uri } => {
             handle_unlink(from_uri, to_uri, ctx).await
         }
-        Commands::Export { uri, to } => {
-            handle_export(uri, to, ctx).await
+        Commands::Export { uri, to, relations_only } => {
+            handle_export(uri, to, relations_only, ctx).await
         }
-        Commands::Import { file_path, target_uri, force, no_vectorize } => {
-            handle_import(file_path, target_uri, force, no_vectorize, ctx).await
+        Commands::Import { file_path, target_uri, force, no_vectorize, into_new, relations_only } => {
+            handle_import(file_path, target_uri, force, no_vectorize, into_new, relations_only, ctx).await
         }
-        Commands::Wait { timeout } => {
+        Commands::Wait { timeout, poll_interval } => {
             let client = ctx.get_client();
-            commands::system::wait(&client, timeout, ctx.output_format, ctx.compact).await
+            commands::system::wait(&client, timeout, poll_interval, &ctx.output).await
         },
-        Commands::Status => {
+        Commands::Status { watch } => {
             let client = ctx.get_client();
-            commands::observer::system(&client, ctx.output_format, ctx.compact).await
+            run_watched(watch, "status", &ctx, || commands::observer::system(&client, &ctx.output)).await
         },
-        Commands::Health => handle_health(ctx).await,
+        Commands::Health { watch } => handle_health(watch, ctx).await,
         Commands::System { action } => handle_system(action, ctx).await,
         Commands::Observer { action } => handle_observer(action, ctx).await,
         Commands::Session { action } => handle_session(action, ctx).await,
-        Commands::Ls { uri, simple, recursive, abs_limit, all, node_limit } => {
-            handle_ls(uri, simple, recursive, abs_limit, all, node_limit, ctx).await
+        Commands::Ls { uri, simple, recursive, abs_limit, all, node_limit, offset, sort, reverse, natural, page, page_size, all_pages } => {
+            let paging = match (page, all_pages, offset) {
+                (Some(number), _, _) => Paging::Page { number, size: page_size },
+                (None, true, _) => Paging::All { size: page_size },
+                (None, false, Some(offset)) => Paging::Offset { offset },
+                (None, false, None) => Paging::Off,
+            };
+            handle_ls(uri, simple, recursive, abs_limit, all, node_limit, paging, sort, reverse, natural, ctx).await
         }
         Commands::Tree { uri, abs_limit, all, node_limit } => {
             handle_tree(uri, abs_limit, all, node_limit, ctx).await
@@ -419,46 +1171,178 @@ async fn main() {
         Commands::Mkdir { uri } => {
             handle_mkdir(uri, ctx).await
         }
-        Commands::Rm { uri, recursive } => {
-            handle_rm(uri, recursive, ctx).await
+        Commands::Touch { uri } => {
+            let client = ctx.get_client();
+            commands::filesystem::touch(&client, &uri, &ctx.output).await
+        }
+        Commands::Rm { uri, recursive, glob, force, yes, concurrency } => {
+            handle_rm(uri, recursive, glob, force, yes, concurrency, ctx).await
         }
-        Commands::Mv { from_uri, to_uri } => {
-            handle_mv(from_uri, to_uri, ctx).await
+        Commands::Mv { from_uri, to_uri, no_overwrite, interactive } => {
+            handle_mv(from_uri, to_uri, no_overwrite, interactive, ctx).await
         }
-        Commands::Stat { uri } => {
-            handle_stat(uri, ctx).await
+        Commands::Cp { from_uri, to_uri, recursive, no_vectorize, force } => {
+            handle_cp(from_uri, to_uri, recursive, no_vectorize, force, ctx).await
+        }
+        Commands::Watch { uri, interval, format, count } => {
+            let client = ctx.get_client();
+            let out = format.map_or_else(|| ctx.output.clone(), |format| ctx.output.with_format(format.into()));
+            commands::watch::watch(&client, &uri, interval, count, &out).await
+        }
+        Commands::Du { uri, recursive, all } => {
+            let client = ctx.get_client();
+            commands::filesystem::du(&client, &uri, recursive, all, &ctx.output).await
+        }
+        Commands::Diff { left_uri, right_uri, unified } => {
+            let client = ctx.get_client();
+            commands::diff::diff(&client, &left_uri, &right_uri, unified, &ctx.output).await
+        }
+        Commands::Stat { uris } => {
+            handle_stat(uris, ctx).await
         }
         Commands::AddMemory { content } => {
             handle_add_memory(content, ctx).await
         }
+        Commands::Selftest => {
+            let client = ctx.get_client();
+            commands::selftest::selftest(&client, &ctx.output).await
+        }
+        Commands::Index { action } => handle_index(action, ctx).await,
         Commands::Config { action } => handle_config(action, ctx).await,
-        Commands::Version => {
-            println!("{}", env!("CARGO_PKG_VERSION"));
+        Commands::Api { method, path, body } => {
+            let client = ctx.get_client();
+            commands::api::call(&client, method, &path, body.as_deref(), &ctx.output).await
+        }
+        Commands::Version { verbose: false } => {
+            output::output_line(env!("CARGO_PKG_VERSION"), &ctx.output);
             Ok(())
         }
-        Commands::Read { uri } => handle_read(uri, ctx).await,
+        Commands::Version { verbose: true } => {
+            let client = ctx.get_client();
+            commands::system::version(&client, &ctx.config.url, &ctx.output).await
+        }
+        Commands::Completions { .. } => unreachable!("completions are handled before the context is built"),
+        Commands::Read { uri, rate_limit } => handle_read(uri, rate_limit, ctx).await,
+        Commands::Edit { uri, force } => {
+            let client = ctx.get_client();
+            commands::content::edit(&client, &uri, force, &ctx.output).await
+        }
         Commands::Abstract { uri } => handle_abstract(uri, ctx).await,
         Commands::Overview { uri } => handle_overview(uri, ctx).await,
-        Commands::Find { query, uri, limit, threshold } => {
-            handle_find(query, uri, limit, threshold, ctx).await
+        Commands::Find { query, uri, limit, threshold, with_relations, sort, desc, explain } => {
+            handle_find(query, uri, limit, threshold, with_relations, sort, desc, explain, ctx).await
         }
-        Commands::Search { query, uri, session_id, limit, threshold } => {
-            handle_search(query, uri, session_id, limit, threshold, ctx).await
+        Commands::Search { query, uri, session_id, session_auto, context, limit, threshold, with_relations, sort, desc } => {
+            handle_search(query, uri, session_id, session_auto, context, limit, threshold, with_relations, sort, desc, ctx).await
         }
-        Commands::Grep { uri, pattern, ignore_case } => {
-            handle_grep(uri, pattern, ignore_case, ctx).await
+        Commands::Grep { uri, pattern, ignore_case, fixed_strings, recursive, node_limit } => {
+            handle_grep(uri, pattern, ignore_case, fixed_strings, recursive, node_limit, ctx).await
         }
-        Commands::Glob { pattern, uri } => {
-            handle_glob(pattern, uri, ctx).await
+        Commands::Glob { pattern, uri, sort, natural } => {
+            handle_glob(pattern, uri, sort, natural, ctx).await
         }
     };
 
-    if let Err(e) = result {
+    if let Err(Error::DryRun) = result {
+        output::output_dry_run(&out);
+    } else if let Err(e) = result {
+        // A failed command leaves an existing --output-file as it was
+        out.discard();
+        let message = e.to_string();
+        let err = error::CliError::from(e);
+        if (out.format == OutputFormat::JsonlWrapped || cli_events) && !to_file {
+            output::output_error(&err.code, &err.message, &out);
+        } else {
+            eprintln!("Error: {}", message);
+        }
+        std::process::exit(err.exit_code);
+    }
+    if let Err(e) = out.finish() {
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }
+
+    if let Some(status) = out.aborted_status() {
+        eprintln!("Error: response status '{}' matched --abort-on", status);
+        std::process::exit(1);
+    }
+
+    if fail_on_warning && out.opts.warnings.emitted() {
+        eprintln!("Error: warnings were emitted and --fail-on-warning is set");
+        std::process::exit(1);
+    }
+}
+
+/// Accept the legacy `--wait-timeout` flag, preferring `--timeout` when both are given
+fn fold_wait_timeout(timeout: Option<f64>, wait_timeout: Option<f64>, out: &Output) -> Option<f64> {
+    if wait_timeout.is_some() {
+        output::warn_deprecated("--wait-timeout", "--timeout", out);
+    }
+    timeout.or(wait_timeout)
+}
+
+/// Write the completion script for `shell`, keyed to the installed binary name
+fn write_completions(shell: clap_complete::Shell, w: &mut dyn std::io::Write) -> std::io::Result<()> {
+    let mut command = Cli::command();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, env!("CARGO_BIN_NAME"), &mut script);
+    w.write_all(&script)
+}
+
+/// The `--config` file when given, else the default config layers
+fn load_config(config_path: Option<&std::path::Path>, profile: Option<&str>) -> Result<Config> {
+    match config_path {
+        Some(path) => Config::load_file(path, profile),
+        None => Config::load(profile),
+    }
+}
+
+/// Whether `--profile`/`OV_PROFILE` applies to `command`; `config profile` manages the
+/// profiles themselves, so e.g. adding the selected profile must not require it to exist
+fn selects_profile(command: &Commands) -> bool {
+    !matches!(command, Commands::Config { action: ConfigCommands::Profile { .. } })
+}
+
+/// Command-line arguments with the aliases of `config` expanded after any global flags
+fn expand_args(args: Vec<String>, config: &Config) -> Result<Vec<String>> {
+    if config.aliases.is_empty() {
+        return Ok(args);
+    }
+    let command = Cli::command();
+    alias::expand_aliases(
+        args,
+        &config.aliases,
+        |name| command.find_subcommand(name).is_some(),
+        |flag| flag_takes_value(&command, flag),
+    )
+}
+
+/// Whether the top-level flag `flag` (`--name` or `-c`) consumes a value
+fn flag_takes_value(command: &clap::Command, flag: &str) -> bool {
+    command
+        .get_arguments()
+        .filter(|arg| arg.get_action().takes_values())
+        .any(|arg| match flag.strip_prefix("--") {
+            Some(long) => arg.get_long() == Some(long) || arg.get_all_aliases().is_some_and(|a| a.contains(&long)),
+            None => flag.strip_prefix('-').and_then(|s| s.chars().next()).is_some_and(|c| arg.get_short() == Some(c)),
+        })
+}
+
+/// `--config` from the raw command line, needed before parsing since aliases live in the config
+fn raw_config_path(args: &[String]) -> Option<std::path::PathBuf> {
+    let mut args = args.iter().skip(1).take_while(|arg| *arg != "--");
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().map(Into::into);
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(path.into());
+        }
+    }
+    None
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_add_resource(
     path: String,
     to: Option<String>,
@@ -466,11 +1350,16 @@ async fn handle_add_resource(
     instruction: String,
     wait: bool,
     timeout: Option<f64>,
+    poll_interval: Option<f64>,
+    content_type: Option<String>,
+    stdin_name: Option<String>,
+    upload: bool,
     ctx: CliContext,
 ) -> Result<()> {
     let client = ctx.get_client();
     commands::resources::add_resource(
-        &client, &path, to, reason, instruction, wait, timeout, ctx.output_format, ctx.compact
+        &client, &path, to, reason, instruction, wait, timeout, poll_interval, content_type.as_deref(),
+        stdin_name.as_deref(), upload, &ctx.output
     ).await
 }
 
@@ -478,17 +1367,18 @@ async fn handle_add_skill(
     data: String,
     wait: bool,
     timeout: Option<f64>,
+    poll_interval: Option<f64>,
     ctx: CliContext,
 ) -> Result<()> {
     let client = ctx.get_client();
     commands::resources::add_skill(
-        &client, &data, wait, timeout, ctx.output_format, ctx.compact
+        &client, &data, wait, timeout, poll_interval, &ctx.output
     ).await
 }
 
-async fn handle_relations(uri: String, ctx: CliContext) -> Result<()> {
+async fn handle_relations(uri: String, depth: Option<u32>, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::relations::list_relations(&client, &uri, ctx.output_format, ctx.compact
+    commands::relations::list_relations(&client, &uri, depth, &ctx.output
     ).await
 }
 
@@ -500,7 +1390,7 @@ async fn handle_link(
 ) -> Result<()> {
     let client = ctx.get_client();
     commands::relations::link(
-        &client, &from_uri, &to_uris, &reason, ctx.output_format, ctx.compact
+        &client, &from_uri, &to_uris, &reason, &ctx.output
     ).await
 }
 
@@ -511,13 +1401,13 @@ async fn handle_unlink(
 ) -> Result<()> {
     let client = ctx.get_client();
     commands::relations::unlink(
-        &client, &from_uri, &to_uri, ctx.output_format, ctx.compact
+        &client, &from_uri, &to_uri, &ctx.output
     ).await
 }
 
-async fn handle_export(uri: String, to: String, ctx: CliContext) -> Result<()> {
+async fn handle_export(uri: String, to: String, relations_only: bool, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::pack::export(&client, &uri, &to, ctx.output_format, ctx.compact
+    commands::pack::export(&client, &uri, &to, relations_only, &ctx.output
     ).await
 }
 
@@ -526,25 +1416,27 @@ async fn handle_import(
     target_uri: String,
     force: bool,
     no_vectorize: bool,
+    into_new: Option<String>,
+    relations_only: bool,
     ctx: CliContext,
 ) -> Result<()> {
     let client = ctx.get_client();
     commands::pack::import(
-        &client, &file_path, &target_uri, force, no_vectorize, ctx.output_format, ctx.compact
+        &client, &file_path, &target_uri, force, no_vectorize, into_new.as_deref(), relations_only, &ctx.output
     ).await
 }
 
 async fn handle_system(cmd: SystemCommands, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
     match cmd {
-        SystemCommands::Wait { timeout } => {
-            commands::system::wait(&client, timeout, ctx.output_format, ctx.compact).await
+        SystemCommands::Wait { timeout, poll_interval } => {
+            commands::system::wait(&client, timeout, poll_interval, &ctx.output).await
         }
-        SystemCommands::Status => {
-            commands::system::status(&client, ctx.output_format, ctx.compact).await
+        SystemCommands::Status { watch } => {
+            run_watched(watch, "system status", &ctx, || commands::system::status(&client, &ctx.output)).await
         }
-        SystemCommands::Health => {
-            commands::system::health(&client, ctx.output_format, ctx.compact).await
+        SystemCommands::Health { watch } => {
+            run_watched(watch, "system health", &ctx, || commands::system::health(&client, &ctx.output)).await
         }
     }
 }
@@ -552,17 +1444,26 @@ async fn handle_system(cmd: SystemCommands, ctx: CliContext) -> Result<()> {
 async fn handle_observer(cmd: ObserverCommands, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
     match cmd {
-        ObserverCommands::Queue => {
-            commands::observer::queue(&client, ctx.output_format, ctx.compact).await
+        ObserverCommands::Queue { watch } => {
+            run_watched(watch, "observer queue", &ctx, || commands::observer::queue(&client, &ctx.output)).await
         }
-        ObserverCommands::Vikingdb => {
-            commands::observer::vikingdb(&client, ctx.output_format, ctx.compact).await
+        ObserverCommands::Vikingdb { watch } => {
+            run_watched(watch, "observer vikingdb", &ctx, || commands::observer::vikingdb(&client, &ctx.output)).await
         }
-        ObserverCommands::Vlm => {
-            commands::observer::vlm(&client, ctx.output_format, ctx.compact).await
+        ObserverCommands::Vlm { probe: true, image, watch } => {
+            run_watched(watch, "observer vlm --probe", &ctx, || {
+                commands::observer::vlm_probe(&client, image.as_deref(), &ctx.output)
+            })
+            .await
         }
-        ObserverCommands::System => {
-            commands::observer::system(&client, ctx.output_format, ctx.compact).await
+        ObserverCommands::Vlm { watch, .. } => {
+            run_watched(watch, "observer vlm", &ctx, || commands::observer::vlm(&client, &ctx.output)).await
+        }
+        ObserverCommands::System { watch } => {
+            run_watched(watch, "observer system", &ctx, || commands::observer::system(&client, &ctx.output)).await
+        }
+        ObserverCommands::Poll { interval, count, threshold } => {
+            commands::observer::poll(&client, interval, count, threshold, &ctx.output).await
         }
     }
 }
@@ -571,148 +1472,397 @@ async fn handle_session(cmd: SessionCommands, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
     match cmd {
         SessionCommands::New => {
-            commands::session::new_session(&client, ctx.output_format, ctx.compact).await
+            commands::session::new_session(&client, &ctx.output).await
         }
         SessionCommands::List => {
-            commands::session::list_sessions(&client, ctx.output_format, ctx.compact).await
+            commands::session::list_sessions(&client, &ctx.output).await
         }
         SessionCommands::Get { session_id } => {
-            commands::session::get_session(&client, &session_id, ctx.output_format, ctx.compact
+            commands::session::get_session(&client, &session_id, &ctx.output
             ).await
         }
         SessionCommands::Delete { session_id } => {
-            commands::session::delete_session(&client, &session_id, ctx.output_format, ctx.compact
+            commands::session::delete_session(&client, &session_id, &ctx.output
             ).await
         }
+        SessionCommands::Export { session_id, format, to, force } => {
+            commands::session::export_session(&client, &session_id, format, to.as_deref(), force, &ctx.output).await
+        }
         SessionCommands::AddMessage { session_id, role, content } => {
+            let session_id = state::resolve_session_id(session_id)?;
+            let content = commands::session::read_content_arg(content)?;
             commands::session::add_message(
-                &client, &session_id, &role, &content, ctx.output_format, ctx.compact
+                &client, &session_id, &role, &content, &ctx.output
             ).await
         }
         SessionCommands::Commit { session_id } => {
-            commands::session::commit_session(&client, &session_id, ctx.output_format, ctx.compact
+            let session_id = state::resolve_session_id(session_id)?;
+            commands::session::commit_session(&client, &session_id, &ctx.output
             ).await
         }
+        SessionCommands::Use { session_id } => {
+            let mut state = state::StateFile::load()?;
+            state.current_session_id = Some(session_id.clone());
+            state.save()?;
+            output::output_line(&format!("Using session: {}", session_id), &ctx.output);
+            Ok(())
+        }
+        SessionCommands::Current => {
+            let session_id = state::resolve_session_id(None)?;
+            output::output_success(&session_id, &ctx.output);
+            Ok(())
+        }
+        SessionCommands::Clear => {
+            let mut state = state::StateFile::load()?;
+            state.current_session_id = None;
+            state.save()?;
+            output::output_line("Cleared current session", &ctx.output);
+            Ok(())
+        }
     }
 }
 
 async fn handle_add_memory(content: String, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::session::add_memory(&client, &content, ctx.output_format, ctx.compact).await
+    let content = commands::session::read_content_arg(content)?;
+    commands::session::add_memory(&client, &content, &ctx.output).await
 }
 
-async fn handle_config(cmd: ConfigCommands, _ctx: CliContext) -> Result<()> {
+async fn handle_index(cmd: IndexCommands, ctx: CliContext) -> Result<()> {
     match cmd {
-        ConfigCommands::Show => {
-            let config = Config::load()?;
-            output::output_success(
-                &serde_json::to_value(config).unwrap(),
-                output::OutputFormat::Json,
-                true
-            );
+        IndexCommands::Refresh { uri, node_limit } => {
+            let client = ctx.get_client();
+            commands::index::refresh(&client, &uri, node_limit, &ctx.output).await
+        }
+    }
+}
+
+async fn handle_config(cmd: ConfigCommands, ctx: CliContext) -> Result<()> {
+    match cmd {
+        ConfigCommands::Show { sources: true } => {
+            output::output_success(ctx.config.source_rows(), &ctx.output);
+            Ok(())
+        }
+        ConfigCommands::Show { sources: false } => {
+            let json = Output::new(OutputFormat::Json, RenderOptions { compact: true, ..ctx.output.opts.clone() });
+            output::output_success(ctx.config.effective(), &json);
             Ok(())
         }
         ConfigCommands::Validate => {
-            match Config::load() {
+            match load_config(ctx.config_path.as_deref(), None).and_then(|config| config.validate()) {
                 Ok(_) => {
-                    println!("Configuration is valid");
+                    output::output_line("Configuration is valid", &ctx.output);
                     Ok(())
                 }
                 Err(e) => {
-                    Err(Error::Config(e.to_string()))
+                    Err(Error::Config(format!(
+                        "{} (settings apply in this order, later wins: {})",
+                        e,
+                        config::PRECEDENCE
+                    )))
                 }
             }
         }
+        ConfigCommands::Init { format, force } => {
+            let path = config::user_config_path(format)?;
+            let existing = config::default_config_path()?;
+            if existing.exists() && existing != path {
+                return Err(Error::Config(format!(
+                    "A config file already exists at {}; remove it before creating one in another format",
+                    existing.display()
+                )));
+            }
+            if path.exists() && !force {
+                return Err(Error::Config(format!(
+                    "{} already exists (use --force to overwrite)",
+                    path.display()
+                )));
+            }
+            let config = Config { format, ..Config::default() };
+            config.save_to(&path)?;
+            output::output_line(&format!("Wrote {}", path.display()), &ctx.output);
+            Ok(())
+        }
+        ConfigCommands::Set { key, value } => {
+            let path = config_edit_path(&ctx)?;
+            config::update_config_file(&path, |obj| config::set_key(obj, &key, &value))?;
+            output::output_line(&format!("Set {} in {}", key, path.display()), &ctx.output);
+            Ok(())
+        }
+        ConfigCommands::Unset { key } => {
+            let path = config_edit_path(&ctx)?;
+            let mut removed = false;
+            config::update_config_file(&path, |obj| {
+                removed = config::unset_key(obj, &key)?;
+                Ok(())
+            })?;
+            if removed {
+                output::output_line(&format!("Unset {} in {}", key, path.display()), &ctx.output);
+            } else {
+                output::output_line(&format!("{} was not set in {}", key, path.display()), &ctx.output);
+            }
+            Ok(())
+        }
+        ConfigCommands::Profile { action } => handle_profile(action, &ctx.output),
     }
 }
 
-async fn handle_read(uri: String, ctx: CliContext) -> Result<()> {
-    let client = ctx.get_client();
-    commands::content::read(&client, &uri, ctx.output_format, ctx.compact).await
+/// File `config set`/`config unset` edit: the `--config` file if given, else the user config
+fn config_edit_path(ctx: &CliContext) -> Result<std::path::PathBuf> {
+    match &ctx.config_path {
+        Some(path) => Ok(path.clone()),
+        None => config::default_config_path(),
+    }
+}
+
+fn handle_profile(cmd: ProfileCommands, out: &Output) -> Result<()> {
+    match cmd {
+        ProfileCommands::Add { name, url, key, user } => {
+            let profile = config::ProfileConfig { url: Some(url), api_key: key, user };
+            let path = config::update_user_config(|obj| {
+                let profiles = obj
+                    .entry("profiles")
+                    .or_insert_with(|| serde_json::json!({}));
+                let Some(profiles) = profiles.as_object_mut() else {
+                    return Err(Error::Config("`profiles` in the config file is not a mapping".to_string()));
+                };
+                profiles.insert(name.clone(), serde_json::to_value(&profile)?);
+                Ok(())
+            })?;
+            output::output_line(&format!("Saved profile '{}' to {}", name, path.display()), out);
+            Ok(())
+        }
+        ProfileCommands::Remove { name } => {
+            let path = config::update_user_config(|obj| {
+                let removed = obj
+                    .get_mut("profiles")
+                    .and_then(|p| p.as_object_mut())
+                    .and_then(|profiles| profiles.remove(&name));
+                match removed {
+                    Some(_) => Ok(()),
+                    None => Err(Error::Config(format!("No profile named '{}' in the user config file", name))),
+                }
+            })?;
+            output::output_line(&format!("Removed profile '{}' from {}", name, path.display()), out);
+            Ok(())
+        }
+    }
+}
+
+async fn handle_read(uri: String, rate_limit: Option<u64>, ctx: CliContext) -> Result<()> {
+    let client = ctx.get_client().with_rate_limit(rate_limit);
+    commands::content::read(&client, &uri, &ctx.output).await
 }
 
 async fn handle_abstract(uri: String, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::content::abstract_content(&client, &uri, ctx.output_format, ctx.compact).await
+    commands::content::abstract_content(&client, &uri, &ctx.output).await
 }
 
 async fn handle_overview(uri: String, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::content::overview(&client, &uri, ctx.output_format, ctx.compact).await
+    commands::content::overview(&client, &uri, &ctx.output).await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_find(
     query: String,
     uri: String,
     limit: i32,
     threshold: Option<f64>,
+    with_relations: bool,
+    sort: Option<String>,
+    desc: bool,
+    explain: bool,
     ctx: CliContext,
 ) -> Result<()> {
     let client = ctx.get_client();
-    commands::search::find(&client, &query, &uri, limit, threshold, ctx.output_format, ctx.compact).await
+    commands::search::find(&client, &query, &uri, limit, threshold, with_relations, sort.as_deref(), desc, explain, &ctx.output).await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_search(
     query: String,
     uri: String,
     session_id: Option<String>,
+    session_auto: bool,
+    context: Option<String>,
     limit: i32,
     threshold: Option<f64>,
+    with_relations: bool,
+    sort: Option<String>,
+    desc: bool,
     ctx: CliContext,
 ) -> Result<()> {
     let client = ctx.get_client();
-    commands::search::search(&client, &query, &uri, session_id, limit, threshold, ctx.output_format, ctx.compact).await
+    let session_id = match session_id {
+        Some(id) => Some(id),
+        None if !session_auto => state::StateFile::load()?.current_session_id,
+        None => None,
+    };
+    commands::search::search(&client, &query, &uri, session_id, session_auto, context.as_deref(), limit, threshold, with_relations, sort.as_deref(), desc, &ctx.output).await
 }
 
-async fn handle_ls(uri: String, simple: bool, recursive: bool, abs_limit: i32, show_all_hidden: bool, node_limit: i32, ctx: CliContext) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn handle_ls(uri: String, simple: bool, recursive: bool, abs_limit: i32, show_all_hidden: bool, node_limit: i32, paging: Paging, sort: Option<output::SortSpec>, reverse: bool, natural: bool, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    let api_output = if ctx.compact { "agent" } else { "original" };
-    commands::filesystem::ls(&client, &uri, simple, recursive, api_output, abs_limit, show_all_hidden, node_limit, ctx.output_format, ctx.compact).await
+    let api_output = if ctx.output.opts.compact { "agent" } else { "original" };
+    commands::filesystem::ls(&client, &uri, simple, recursive, api_output, abs_limit, show_all_hidden, node_limit, paging, sort, reverse, natural, ctx.offline, &ctx.output).await
 }
 
 async fn handle_tree(uri: String, abs_limit: i32, show_all_hidden: bool, node_limit: i32, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    let api_output = if ctx.compact { "agent" } else { "original" };
-    commands::filesystem::tree(&client, &uri, api_output, abs_limit, show_all_hidden, node_limit, ctx.output_format, ctx.compact).await
+    let api_output = if ctx.output.opts.compact { "agent" } else { "original" };
+    commands::filesystem::tree(&client, &uri, api_output, abs_limit, show_all_hidden, node_limit, &ctx.output).await
 }
 
 async fn handle_mkdir(uri: String, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::filesystem::mkdir(&client, &uri, ctx.output_format, ctx.compact).await
+    commands::filesystem::mkdir(&client, &uri, &ctx.output).await
 }
 
-async fn handle_rm(uri: String, recursive: bool, ctx: CliContext) -> Result<()> {
+async fn handle_rm(uri: String, recursive: bool, glob: Option<String>, force: bool, yes: bool, concurrency: u16, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::filesystem::rm(&client, &uri, recursive, ctx.output_format, ctx.compact).await
+    match glob {
+        Some(pattern) => {
+            commands::filesystem::rm_glob(&client, &uri, &pattern, recursive, force, concurrency as usize, &ctx.output).await
+        }
+        None => commands::filesystem::rm(&client, &uri, recursive, yes, &ctx.output).await,
+    }
 }
 
-async fn handle_mv(from_uri: String, to_uri: String, ctx: CliContext) -> Result<()> {
+async fn handle_mv(from_uri: String, to_uri: String, no_overwrite: bool, interactive: bool, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::filesystem::mv(&client, &from_uri, &to_uri, ctx.output_format, ctx.compact).await
+    commands::filesystem::mv(&client, &from_uri, &to_uri, no_overwrite, interactive, &ctx.output).await
 }
 
-async fn handle_stat(uri: String, ctx: CliContext) -> Result<()> {
+async fn handle_cp(from_uri: String, to_uri: String, recursive: bool, no_vectorize: bool, force: bool, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::filesystem::stat(&client, &uri, ctx.output_format, ctx.compact).await
+    commands::filesystem::cp(&client, &from_uri, &to_uri, recursive, !no_vectorize, force, &ctx.output).await
 }
 
-async fn handle_grep(uri: String, pattern: String, ignore_case: bool, ctx: CliContext) -> Result<()> {
+async fn handle_stat(uris: Vec<String>, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::search::grep(&client, &uri, &pattern, ignore_case, ctx.output_format, ctx.compact).await
+    commands::filesystem::stat(&client, &uris, &ctx.output).await
 }
 
-async fn handle_glob(pattern: String, uri: String, ctx: CliContext) -> Result<()> {
+async fn handle_grep(uri: String, pattern: String, ignore_case: bool, fixed_strings: bool, recursive: bool, node_limit: i32, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    commands::search::glob(&client, &pattern, &uri, ctx.output_format, ctx.compact).await
+    commands::search::grep(&client, &uri, &pattern, ignore_case, fixed_strings, recursive, node_limit, &ctx.output).await
 }
 
-async fn handle_health(ctx: CliContext) -> Result<()> {
+async fn handle_glob(pattern: String, uri: String, sort: Option<SortKey>, natural: bool, ctx: CliContext) -> Result<()> {
     let client = ctx.get_client();
-    let system_status: serde_json::Value = client.get("/api/v1/observer/system", &[]).await?;
-    let is_healthy = system_status.get("is_healthy").and_then(|v| v.as_bool()).unwrap_or(false);
-    output::output_success(&serde_json::json!({ "healthy": is_healthy }), ctx.output_format, ctx.compact);
-    if !is_healthy {
+    commands::search::glob(&client, &pattern, &uri, sort, natural, ctx.offline, &ctx.output).await
+}
+
+/// Run a status command once, or with `--watch` every `--interval` until Ctrl-C
+async fn run_watched<F, Fut>(watch: WatchArgs, title: &str, ctx: &CliContext, mut render: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    if watch.watch {
+        commands::watch::refresh(title, watch.interval, &ctx.output, render).await
+    } else {
+        render().await
+    }
+}
+
+async fn handle_health(watch: WatchArgs, ctx: CliContext) -> Result<()> {
+    let client = ctx.get_client();
+    if watch.watch {
+        // Keep refreshing while unhealthy instead of exiting
+        return run_watched(watch, "health", &ctx, || async {
+            report_health(&client, &ctx).await.map(|_| ())
+        })
+        .await;
+    }
+    if !report_health(&client, &ctx).await? {
+        ctx.output.finish()?;
         std::process::exit(1);
     }
     Ok(())
 }
+
+/// Print `{"healthy": ...}` from the system observer and return it
+async fn report_health(client: &client::HttpClient, ctx: &CliContext) -> Result<bool> {
+    let system_status: serde_json::Value = client.get("/api/v1/observer/system", &[]).await?;
+    let is_healthy = system_status.get("is_healthy").and_then(|v| v.as_bool()).unwrap_or(false);
+    output::output_success(serde_json::json!({ "healthy": is_healthy }), &ctx.output);
+    Ok(is_healthy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap_complete::Shell;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_wait_timeout_is_folded_with_a_warning() {
+        let out = output::tests::output_for(OutputFormat::Table);
+        assert_eq!(fold_wait_timeout(Some(5.0), None, &out), Some(5.0));
+        assert!(!out.opts.warnings.emitted());
+        assert_eq!(fold_wait_timeout(Some(5.0), Some(9.0), &out), Some(5.0));
+        assert_eq!(fold_wait_timeout(None, Some(9.0), &out), Some(9.0));
+        assert!(out.opts.warnings.emitted());
+    }
+
+    #[test]
+    fn test_aliases_expand_after_global_flags() {
+        let config = Config {
+            aliases: [("recent".to_string(), "ls viking://logs".to_string())].into_iter().collect(),
+            ..Config::default()
+        };
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            expand_args(args(&["ov", "-o", "json", "--fields", "uri", "--compact", "recent"]), &config).unwrap(),
+            args(&["ov", "-o", "json", "--fields", "uri", "--compact", "ls", "viking://logs"])
+        );
+        assert_eq!(raw_config_path(&args(&["ov", "recent", "--config", "a.toml"])), Some(PathBuf::from("a.toml")));
+        assert_eq!(raw_config_path(&args(&["ov", "--config=b.toml", "ls"])), Some(PathBuf::from("b.toml")));
+        assert_eq!(raw_config_path(&args(&["ov", "ls", "--", "--config", "c"])), None);
+    }
+
+    #[test]
+    fn test_profile_commands_ignore_the_selected_profile() {
+        let cli = Cli::try_parse_from(["ov", "--profile", "prod", "config", "profile", "add", "prod", "--url", "http://a:1933"]).unwrap();
+        assert!(!selects_profile(&cli.command));
+        let cli = Cli::try_parse_from(["ov", "--profile", "prod", "config", "show"]).unwrap();
+        assert!(selects_profile(&cli.command));
+    }
+
+    /// Compare each shell's script against `tests/fixtures/completions/`.
+    ///
+    /// After changing the CLI, regenerate the fixtures with
+    /// `OV_UPDATE_SNAPSHOTS=1 cargo test completions` and review the diff.
+    #[test]
+    fn test_completions_match_fixtures() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/completions");
+        let update = std::env::var_os("OV_UPDATE_SNAPSHOTS").is_some();
+        for (shell, file) in [
+            (Shell::Bash, "ov.bash"),
+            (Shell::Zsh, "_ov"),
+            (Shell::Fish, "ov.fish"),
+            (Shell::PowerShell, "_ov.ps1"),
+        ] {
+            let mut script = Vec::new();
+            write_completions(shell, &mut script).unwrap();
+            let path = dir.join(file);
+            if update {
+                std::fs::create_dir_all(&dir).unwrap();
+                std::fs::write(&path, &script).unwrap();
+                continue;
+            }
+            let expected = std::fs::read(&path)
+                .unwrap_or_else(|e| panic!("missing fixture {}: {}", path.display(), e));
+            assert!(
+                script == expected,
+                "{} completions differ from {}; rerun with OV_UPDATE_SNAPSHOTS=1 if the CLI change is intended",
+                shell,
+                path.display()
+            );
+        }
+    }
+}
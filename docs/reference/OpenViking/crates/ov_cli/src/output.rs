@@ -1,42 +1,814 @@
 use serde::Serialize;
-use serde_json::json;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::Write;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
 
-const MAX_COL_WIDTH: usize = 256;
+/// Default widest table cell, overridable with `--truncate`
+pub const MAX_COL_WIDTH: usize = 256;
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+use crate::error::Result;
+
+/// Rendering options derived from global CLI flags
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Compact JSON envelope / simplified table representation
+    pub compact: bool,
+    /// Keep columns whose values are all empty, even in compact mode
+    pub show_empty: bool,
+    /// Escape control characters in non-JSON output (set when stdout is a TTY)
+    pub escape_control: bool,
+    /// Nesting depth past which table rendering falls back to raw JSON
+    pub max_depth: usize,
+    /// Terminate raw content with a newline (set for a TTY unless `--no-newline`)
+    pub content_newline: bool,
+    /// Print JSON results without the `{"ok":true,"result":...}` wrapper
+    pub no_envelope: bool,
+    /// Exact table widths for named columns (`--col-width`); others auto-size
+    pub col_widths: HashMap<String, usize>,
+    /// Render epoch timestamps and durations in human form in tables
+    pub human_time: bool,
+    /// Emit progress and the final result as `type`-tagged NDJSON on stdout
+    pub events: bool,
+    /// Never cut table cells; columns size to their full content
+    pub no_truncate: bool,
+    /// Widest a table cell may be before it is cut (`--truncate`, default `MAX_COL_WIDTH`)
+    pub max_col_width: usize,
+    /// Omit the header row of array tables (`--no-header`); CSV and Markdown keep theirs
+    pub no_header: bool,
+    /// Only show these columns/fields, in this order (`--columns`/`--fields`); empty shows all
+    pub columns: Vec<String>,
+    /// Colorize tables with ANSI styles (resolved from `--color` and `NO_COLOR`)
+    pub color: bool,
+    /// Requests were only printed (`--dry-run`), so there are no real results
+    pub dry_run: bool,
+    /// Where warnings are recorded for `--fail-on-warning`; clones share it
+    pub warnings: Warnings,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            compact: true,
+            show_empty: false,
+            escape_control: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+            content_newline: false,
+            no_envelope: false,
+            col_widths: HashMap::new(),
+            human_time: false,
+            events: false,
+            no_truncate: false,
+            max_col_width: MAX_COL_WIDTH,
+            no_header: false,
+            columns: Vec::new(),
+            color: false,
+            dry_run: false,
+            warnings: Warnings::default(),
+        }
+    }
+}
+
+/// How a command writes its results: the output format plus the rendering options from
+/// the global flags. Built once into `CliContext` and passed to every command.
+#[derive(Debug, Clone)]
+pub struct Output {
+    pub format: OutputFormat,
+    pub opts: RenderOptions,
+    /// `--abort-on` status values checked after every successful result
+    abort_on: Vec<String>,
+    /// The first result status that matched `abort_on`; clones share it
+    aborted: Arc<Mutex<Option<String>>>,
+    /// Where results are written: stdout or the `--output-file` file
+    sink: ResultSink,
+}
+
+impl Output {
+    pub fn new(format: OutputFormat, opts: RenderOptions) -> Self {
+        Self {
+            format,
+            opts,
+            abort_on: Vec::new(),
+            aborted: Arc::default(),
+            sink: ResultSink::default(),
+        }
+    }
+
+    /// Write results to `sink` instead of stdout
+    pub fn with_sink(mut self, sink: ResultSink) -> Self {
+        self.sink = sink;
+        self
+    }
+
+    /// A writer for raw result text, e.g. streamed content or a diff
+    pub fn writer(&self) -> SinkWriter {
+        SinkWriter { sink: self.sink.clone() }
+    }
+
+    /// Whether results reach a terminal, so screen control (clearing, line rewrites) applies
+    pub fn to_terminal(&self) -> bool {
+        self.sink.file.is_none() && std::io::stdout().is_terminal()
+    }
+
+    /// Keep the results once the command has succeeded (see `ResultSink::commit`)
+    pub fn finish(&self) -> Result<()> {
+        self.sink.commit()
+    }
+
+    /// Drop the results of a failed command, leaving any existing `--output-file` alone
+    pub fn discard(&self) {
+        self.sink.discard();
+    }
+
+    /// Fail the run (see `aborted_status`) when a result's `status`/`state` is one of these
+    pub fn with_abort_on(mut self, statuses: Vec<String>) -> Self {
+        self.abort_on = statuses;
+        self
+    }
+
+    /// The response status that matched `--abort-on`, if any
+    pub fn aborted_status(&self) -> Option<String> {
+        self.aborted.lock().ok().and_then(|s| s.clone())
+    }
+
+    /// Record a top-level `status`/`state` value that matches the `--abort-on` list
+    fn check_abort_on(&self, value: &Value) {
+        if let Some(status) = matching_status(value, &self.abort_on) {
+            if let Ok(mut aborted) = self.aborted.lock() {
+                aborted.get_or_insert(status);
+            }
+        }
+    }
+
+    /// The same options in another format, e.g. for `watch --format`
+    pub fn with_format(&self, format: OutputFormat) -> Self {
+        Self {
+            format,
+            ..self.clone()
+        }
+    }
+
+    /// Print a warning to stderr and record it for `--fail-on-warning`
+    pub fn warn(&self, message: impl std::fmt::Display) {
+        self.opts.warnings.warn(message);
+    }
+}
+
+/// The warnings of one CLI run, carried in `RenderOptions` so commands and renderers
+/// report to the same place and `main` can apply `--fail-on-warning` at the end.
+///
+/// Every CLI-emitted warning goes through here so strict runs can exit non-zero.
+#[derive(Debug, Clone, Default)]
+pub struct Warnings {
+    log: Arc<Mutex<WarningLog>>,
+}
+
+#[derive(Debug, Default)]
+struct WarningLog {
+    emitted: bool,
+    /// Keys already reported by `warn_once`
+    once: Vec<String>,
+}
+
+impl Warnings {
+    /// Print a warning to stderr and record it
+    pub fn warn(&self, message: impl std::fmt::Display) {
+        if let Ok(mut log) = self.log.lock() {
+            log.emitted = true;
+        }
+        eprintln!("Warning: {}", message);
+    }
+
+    /// Like `warn`, but only the first time `key` is reported
+    pub fn warn_once(&self, key: &str, message: impl std::fmt::Display) {
+        {
+            let Ok(mut log) = self.log.lock() else {
+                return;
+            };
+            if log.once.iter().any(|k| k == key) {
+                return;
+            }
+            log.once.push(key.to_string());
+        }
+        self.warn(message);
+    }
+
+    /// Whether any warning has been emitted
+    pub fn emitted(&self) -> bool {
+        self.log.lock().map(|log| log.emitted).unwrap_or(false)
+    }
+}
+
+/// Warn once per run that `old` is deprecated in favour of `new`
+pub fn warn_deprecated(old: &str, new: &str, out: &Output) {
+    out.opts.warnings.warn_once(
+        &format!("deprecated:{}", old),
+        format!("{} is deprecated, use {} instead", old, new),
+    );
+}
+
+fn matching_status(value: &Value, abort_on: &[String]) -> Option<String> {
+    ["status", "state"]
+        .iter()
+        .filter_map(|key| value.get(*key).and_then(|v| v.as_str()))
+        .find(|status| abort_on.iter().any(|a| a.eq_ignore_ascii_case(status)))
+        .map(str::to_string)
+}
+
+/// Print raw content (file bodies, abstracts) byte-for-byte, escaping control characters
+/// when enabled.
+///
+/// Nothing is appended unless `content_newline` is set, and then only when the
+/// content does not already end in a newline, so piped output round-trips exactly.
+pub fn output_content(content: &str, out: &Output) -> Result<()> {
+    let opts = &out.opts;
+    if opts.events {
+        write_event(&out.sink, &json!({ "type": "result", "result": content }));
+        return Ok(());
+    }
+    let mut w = out.writer();
+    write_content(content, opts, &mut w)?;
+    w.flush()?;
+    Ok(())
+}
+
+/// Whether content can go to stdout byte-for-byte as it streams in: no events wrapper,
+/// no control-character escaping and no trailing newline to add
+pub fn content_is_verbatim(out: &Output) -> bool {
+    let opts = &out.opts;
+    !opts.events && !opts.escape_control && !opts.content_newline
+}
+
+fn write_content(content: &str, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+    w.write_all(escape_control(content, opts).as_bytes())?;
+    if opts.content_newline && !content.is_empty() && !content.ends_with('\n') {
+        w.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Parse one `--col-width` entry of the form `column=width`
+pub fn parse_col_width(s: &str) -> std::result::Result<(String, usize), String> {
+    let (name, width) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected COLUMN=WIDTH, got '{}'", s))?;
+    let width: usize = width
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid width '{}' for column '{}'", width, name))?;
+    if name.trim().is_empty() || width == 0 {
+        return Err(format!("expected COLUMN=WIDTH with a positive width, got '{}'", s));
+    }
+    Ok((name.trim().to_string(), width))
+}
+
+/// Print a single line of plain-text output, e.g. one streamed match
+pub fn output_line(line: &str, out: &Output) {
+    let _ = writeln!(out.writer(), "{}", escape_control(line, &out.opts));
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OutputFormat {
     Table,
     Json,
+    /// One JSON value per line (array results are split into elements)
+    Jsonl,
+    /// Like `Jsonl`, but each line keeps the `{"ok": ..}` envelope
+    JsonlWrapped,
+    /// RFC 4180 CSV with a header row
+    Csv,
+    /// GitHub-Flavored Markdown tables, for pasting into documentation
+    Markdown,
+}
+
+impl OutputFormat {
+    /// Whether the format is machine-readable JSON of any flavour
+    pub fn is_json(self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Jsonl | OutputFormat::JsonlWrapped)
+    }
 }
 
 impl From<&str> for OutputFormat {
     fn from(s: &str) -> Self {
         match s {
             "json" => OutputFormat::Json,
+            "jsonl" | "ndjson" => OutputFormat::Jsonl,
+            "jsonl-wrapped" => OutputFormat::JsonlWrapped,
+            "csv" => OutputFormat::Csv,
+            "markdown" | "md" => OutputFormat::Markdown,
             _ => OutputFormat::Table,
         }
     }
 }
 
-pub fn output_success<T: Serialize>(result: T, format: OutputFormat, compact: bool) {
-    if matches!(format, OutputFormat::Json) {
-        if compact {
-            println!("{}", json!({ "ok": true, "result": result }));
+impl std::str::FromStr for OutputFormat {
+    type Err = crate::error::Error;
+
+    /// Like `From<&str>`, but unknown names are a config error instead of `Table`
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "json" | "jsonl" | "ndjson" | "jsonl-wrapped" | "csv" | "markdown" | "md" => Ok(OutputFormat::from(s)),
+            _ => Err(crate::error::Error::Config(format!(
+                "Unknown output format '{}' (expected table, json, jsonl, jsonl-wrapped, csv or markdown)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Client-side sort key for listing results
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum SortKey {
+    /// Last path segment
+    Name,
+    /// Full URI
+    Path,
+    /// Size in bytes
+    Size,
+    /// Modification time
+    Mtime,
+}
+
+/// Sort listing entries (URI strings or objects with `uri`/`size`/`modTime`) in place.
+///
+/// With `natural`, embedded numbers compare by value so `file2` sorts before `file10`.
+/// Entries missing the sort field keep their relative order after the others.
+pub fn sort_entries(items: &mut [serde_json::Value], key: SortKey, natural: bool) {
+    let compare_str = |a: &str, b: &str| {
+        if natural {
+            natural_cmp(a, b)
         } else {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&result).unwrap_or_default()
-            );
+            a.cmp(b)
+        }
+    };
+    items.sort_by(|a, b| match key {
+        SortKey::Size => {
+            match (entry_size(a), entry_size(b)) {
+                (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+        SortKey::Mtime => {
+            match (entry_mtime(a), entry_mtime(b)) {
+                (Some(a), Some(b)) => compare_str(&a, &b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+        SortKey::Path => compare_str(&entry_path(a), &entry_path(b)),
+        SortKey::Name => compare_str(&entry_name(a), &entry_name(b)),
+    });
+}
+
+/// Stable sort of result rows by an arbitrary `field`, descending with `desc`.
+///
+/// Values compare numerically when both are numeric (numbers or numeric strings), as
+/// text otherwise. Rows missing the field (or with null) sort last in either direction.
+pub fn sort_by_field(items: &mut [serde_json::Value], field: &str, desc: bool) {
+    let text = |v: &serde_json::Value| match v {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    items.sort_by(|a, b| {
+        let a = a.get(field).filter(|v| !v.is_null());
+        let b = b.get(field).filter(|v| !v.is_null());
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let ord = if is_numeric_value(a) && is_numeric_value(b) {
+                    let number = |v: &serde_json::Value| v.as_f64().or_else(|| v.as_str()?.parse().ok());
+                    number(a).partial_cmp(&number(b)).unwrap_or(Ordering::Equal)
+                } else {
+                    text(a).cmp(&text(b))
+                };
+                if desc {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    });
+}
+
+fn entry_size(v: &serde_json::Value) -> Option<f64> {
+    v.get("size").and_then(|v| v.as_f64())
+}
+
+fn entry_mtime(v: &serde_json::Value) -> Option<String> {
+    v.get("modTime")
+        .or_else(|| v.get("mtime"))
+        .and_then(|m| m.as_str())
+        .map(str::to_string)
+}
+
+/// `ls --sort`: one of the `SortKey` names, or any other field of the entries
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortSpec {
+    Key(SortKey),
+    Field(String),
+}
+
+impl std::str::FromStr for SortSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("sort field must not be empty".to_string());
+        }
+        Ok(match <SortKey as clap::ValueEnum>::from_str(s, true) {
+            Ok(key) => SortSpec::Key(key),
+            Err(_) => SortSpec::Field(s.to_string()),
+        })
+    }
+}
+
+/// Sort listing entries by `spec`, descending with `reverse`. Entries missing the sort
+/// field stay last either way.
+pub fn sort_listing(items: &mut [serde_json::Value], spec: &SortSpec, natural: bool, reverse: bool) {
+    let key = match spec {
+        SortSpec::Field(field) => return sort_by_field(items, field, reverse),
+        SortSpec::Key(key) => *key,
+    };
+    sort_entries(items, key, natural);
+    if reverse {
+        let present = match key {
+            SortKey::Size => items.iter().take_while(|v| entry_size(v).is_some()).count(),
+            SortKey::Mtime => items.iter().take_while(|v| entry_mtime(v).is_some()).count(),
+            SortKey::Path | SortKey::Name => items.len(),
+        };
+        items[..present].reverse();
+    }
+}
+
+fn entry_path(v: &serde_json::Value) -> String {
+    let path = match v {
+        serde_json::Value::String(s) => Some(s.as_str()),
+        _ => v
+            .get("uri")
+            .or_else(|| v.get("rel_path"))
+            .or_else(|| v.get("name"))
+            .and_then(|p| p.as_str()),
+    };
+    path.unwrap_or_default().to_string()
+}
+
+fn entry_name(v: &serde_json::Value) -> String {
+    if let Some(name) = v.get("name").and_then(|n| n.as_str()) {
+        return name.to_string();
+    }
+    let path = entry_path(v);
+    let trimmed = path.trim_end_matches('/');
+    trimmed.rsplit('/').next().unwrap_or(trimmed).to_string()
+}
+
+/// Natural ordering: runs of ASCII digits compare numerically, everything else by character
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let mut x_num = String::new();
+                while let Some(c) = a_chars.peek().copied().filter(char::is_ascii_digit) {
+                    x_num.push(c);
+                    a_chars.next();
+                }
+                let mut y_num = String::new();
+                while let Some(c) = b_chars.peek().copied().filter(char::is_ascii_digit) {
+                    y_num.push(c);
+                    b_chars.next();
+                }
+                let x_trim = x_num.trim_start_matches('0');
+                let y_trim = y_num.trim_start_matches('0');
+                let ord = x_trim
+                    .len()
+                    .cmp(&y_trim.len())
+                    .then_with(|| x_trim.cmp(y_trim))
+                    .then_with(|| x_num.len().cmp(&y_num.len()));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a_chars.next();
+                b_chars.next();
+            }
         }
+    }
+}
+
+pub fn output_success<T: Serialize>(result: T, out: &Output) {
+    if out.opts.dry_run {
+        output_dry_run(out);
+        return;
+    }
+    write_result(result, out);
+}
+
+/// Print what `--dry-run` would have sent (see `PlannedRequest::preview`) as a regular
+/// result, so it is machine-readable in JSON modes
+pub fn output_preview(preview: Value, out: &Output) {
+    write_result(preview, out);
+}
+
+fn write_result<T: Serialize>(result: T, out: &Output) {
+    let value = match serde_json::to_value(&result) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Error: failed to serialize output: {}", e);
+            return;
+        }
+    };
+    let opts = &out.opts;
+    if opts.events {
+        write_event(&out.sink, &json!({ "type": "result", "result": &value }));
+        out.check_abort_on(&value);
+        return;
+    }
+    let mut w = out.writer();
+    if let Err(e) = render_value(&value, out.format, opts, &mut w).and_then(|_| Ok(w.flush()?)) {
+        eprintln!("Error: {}", e);
+    }
+    out.check_abort_on(&value);
+}
+
+/// Where results go: stdout, or for `--output-file` a temporary file next to the target
+/// that only replaces it in `commit`, so a failing command leaves the old contents alone.
+/// Clones share the file.
+#[derive(Debug, Clone, Default)]
+pub struct ResultSink {
+    file: Option<Arc<Mutex<PendingFile>>>,
+}
+
+#[derive(Debug)]
+struct PendingFile {
+    path: PathBuf,
+    /// `None` once committed or discarded
+    temp: Option<tempfile::NamedTempFile>,
+}
+
+impl ResultSink {
+    /// Collect results for `path`; fails with `Error::Io` if its directory is not writable
+    pub fn file(path: &Path) -> Result<Self> {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mut builder = tempfile::Builder::new();
+        builder.prefix(".ov-output-");
+        // Created like a plain file would be, not with the owner-only default
+        #[cfg(unix)]
+        builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o666));
+        let temp = builder.tempfile_in(dir).map_err(|e| {
+            std::io::Error::new(e.kind(), format!("cannot open {}: {}", path.display(), e))
+        })?;
+        Ok(Self {
+            file: Some(Arc::new(Mutex::new(PendingFile {
+                path: path.to_path_buf(),
+                temp: Some(temp),
+            }))),
+        })
+    }
+
+    fn write_with<R>(&self, write: impl FnOnce(&mut dyn Write) -> std::io::Result<R>) -> std::io::Result<R> {
+        match &self.file {
+            None => write(&mut std::io::stdout().lock()),
+            Some(file) => {
+                let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+                match file.temp.as_mut() {
+                    Some(temp) => write(temp),
+                    None => Err(std::io::Error::other("output file already closed")),
+                }
+            }
+        }
+    }
+
+    /// Move the collected results over the target file; a no-op for stdout
+    pub fn commit(&self) -> Result<()> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+        let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(temp) = file.temp.take() {
+            temp.persist(&file.path).map_err(|e| {
+                std::io::Error::new(e.error.kind(), format!("cannot write {}: {}", file.path.display(), e.error))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Delete the collected results without touching the target file
+    pub fn discard(&self) {
+        if let Some(file) = &self.file {
+            file.lock().unwrap_or_else(|e| e.into_inner()).temp.take();
+        }
+    }
+}
+
+/// `Write` into a `ResultSink`, locking it per call so it can be held across awaits
+pub struct SinkWriter {
+    sink: ResultSink,
+}
+
+impl Write for SinkWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.sink.write_with(|w| w.write(buf))
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.sink.write_with(|w| w.write_all(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.sink.write_with(|w| w.flush())
+    }
+}
+
+/// Stand-in for a result under `--dry-run`: a null result with `"dry_run": true`
+/// for machine formats, a short note otherwise
+pub fn output_dry_run(out: &Output) {
+    if out.opts.events {
+        write_event(&out.sink, &json!({ "type": "result", "result": null, "dry_run": true }));
+    } else if out.format.is_json() {
+        let _ = writeln!(out.writer(), "{}", json!({ "ok": true, "result": null, "dry_run": true }));
     } else {
-        print_table(result, compact);
+        let _ = writeln!(out.writer(), "(dry-run, no results)");
+    }
+}
+
+/// Write one `--events` NDJSON line to the results, flushed so progress shows up immediately
+fn write_event(sink: &ResultSink, event: &Value) {
+    let _ = sink.write_with(|w| writeln!(w, "{}", event).and_then(|_| w.flush()));
+}
+
+/// Reports progress of a long-running operation as `{"type":"progress",...}` events.
+///
+/// Silent unless `--events` is set, so commands can report unconditionally.
+pub struct ProgressReporter {
+    stage: String,
+    total: Option<u64>,
+    /// The results to report to, or `None` without `--events`
+    sink: Option<ResultSink>,
+}
+
+impl ProgressReporter {
+    pub fn new(stage: &str, total: Option<u64>, out: &Output) -> Self {
+        Self {
+            stage: stage.to_string(),
+            total,
+            sink: out.opts.events.then(|| out.sink.clone()),
+        }
+    }
+
+    pub fn update(&self, done: u64, detail: &str) {
+        if let Some(sink) = &self.sink {
+            write_event(sink, &progress_event(&self.stage, done, self.total, detail));
+        }
+    }
+}
+
+fn progress_event(stage: &str, done: u64, total: Option<u64>, detail: &str) -> Value {
+    json!({ "type": "progress", "stage": stage, "done": done, "total": total, "detail": detail })
+}
+
+/// Render a result, printing scalars as bare text where the format allows it.
+///
+/// Strings, numbers and booleans print unquoted in table mode and in JSON mode
+/// with `--no-envelope`, so single values can be captured by the shell directly.
+fn render_value(value: &Value, format: OutputFormat, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+    if format == OutputFormat::Table || opts.no_envelope {
+        if let Some(text) = scalar_text(value) {
+            writeln!(w, "{}", escape_control(&text, opts))?;
+            return Ok(());
+        }
+    }
+    if format.is_json() && !opts.columns.is_empty() {
+        return renderer_for(format).render(&select_fields(value, opts), opts, w);
+    }
+    renderer_for(format).render(value, opts, w)
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Renders a result value in one output format
+pub trait OutputRenderer {
+    fn render(&self, value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()>;
+}
+
+/// Select the renderer for an output format
+pub fn renderer_for(format: OutputFormat) -> Box<dyn OutputRenderer> {
+    match format {
+        OutputFormat::Table => Box::new(TableRenderer),
+        OutputFormat::Json => Box::new(JsonRenderer),
+        OutputFormat::Jsonl => Box::new(JsonlRenderer { wrapped: false }),
+        OutputFormat::JsonlWrapped => Box::new(JsonlRenderer { wrapped: true }),
+        OutputFormat::Csv => Box::new(CsvRenderer),
+        OutputFormat::Markdown => Box::new(MarkdownRenderer),
+    }
+}
+
+/// `{"ok": true, "result": ...}` envelope when compact, pretty-printed result otherwise
+pub struct JsonRenderer;
+
+impl OutputRenderer for JsonRenderer {
+    fn render(&self, value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+        if opts.no_envelope && opts.compact {
+            writeln!(w, "{}", value)?;
+        } else if opts.compact {
+            writeln!(w, "{}", json!({ "ok": true, "result": value }))?;
+        } else {
+            writeln!(w, "{}", serde_json::to_string_pretty(value).unwrap_or_default())?;
+        }
+        Ok(())
+    }
+}
+
+/// Newline-delimited JSON: one line per array element, or a single line otherwise.
+///
+/// With `wrapped`, each line is `{"ok":true,"result":<elem>}` so every record is
+/// self-describing; failures are reported as one `{"ok":false,...}` line by `output_error`.
+pub struct JsonlRenderer {
+    pub wrapped: bool,
+}
+
+impl OutputRenderer for JsonlRenderer {
+    fn render(&self, value: &Value, _opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+        let items = match value {
+            Value::Array(items) => items.iter().collect(),
+            other => vec![other],
+        };
+        for item in items {
+            if self.wrapped {
+                writeln!(w, "{}", json!({ "ok": true, "result": item }))?;
+            } else {
+                writeln!(w, "{}", item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// RFC 4180 CSV for spreadsheets and other tabular tooling
+pub struct CsvRenderer;
+
+impl OutputRenderer for CsvRenderer {
+    fn render(&self, value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+        print_csv(value, opts, w)
+    }
+}
+
+/// GitHub-Flavored Markdown tables; string results become fenced code blocks
+pub struct MarkdownRenderer;
+
+impl OutputRenderer for MarkdownRenderer {
+    fn render(&self, value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+        print_markdown(value, opts, w)
+    }
+}
+
+/// Human-readable aligned tables
+pub struct TableRenderer;
+
+impl OutputRenderer for TableRenderer {
+    fn render(&self, value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+        print_table(value, opts, w)
     }
 }
 
-#[allow(dead_code)]
-pub fn output_error(code: &str, message: &str, format: OutputFormat, compact: bool) {
-    if matches!(format, OutputFormat::Json) && compact {
+pub fn output_error(code: &str, message: &str, out: &Output) {
+    let format = out.format;
+    if out.opts.events {
+        write_event(&out.sink, &json!({ "type": "error", "error": { "code": code, "message": message } }));
+    } else if format == OutputFormat::JsonlWrapped {
+        println!("{}", json!({ "ok": false, "error": { "code": code, "message": message } }));
+    } else if matches!(format, OutputFormat::Json) && out.opts.compact {
         eprintln!(
             "{}",
             json!({
@@ -52,39 +824,180 @@ pub fn output_error(code: &str, message: &str, format: OutputFormat, compact: bo
     }
 }
 
-fn print_table<T: Serialize>(result: T, compact: bool) {
-    // Convert to json Value for processing
-    let value = match serde_json::to_value(&result) {
-        Ok(v) => v,
-        Err(_) => {
-            if compact {
-                println!("{}", serde_json::to_string(&result).unwrap_or_default());
+/// Write a result as CSV with a header row.
+///
+/// Lists of objects become one row per item with a column for every key that appears
+/// in any item, so the header is stable regardless of `--compact`. Result objects
+/// holding such lists are flattened with a `type` column like the table view. Lists of
+/// primitives become a single `value` column; any other object or scalar becomes
+/// `key,value` rows. Records end in CRLF as RFC 4180 specifies.
+fn print_csv(value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+    let rows = tabular_rows(value);
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    if !rows.iter().all(|row| row.is_object()) {
+        write!(w, "value\r\n")?;
+        for row in &rows {
+            write!(w, "{}\r\n", csv_field(&csv_cell(row, opts)))?;
+        }
+        return Ok(());
+    }
+
+    let keys = select_columns(collect_columns(&rows), opts);
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let header: Vec<String> = keys.iter().map(|k| csv_field(&escape_control(k, opts))).collect();
+    write!(w, "{}\r\n", header.join(","))?;
+    for row in &rows {
+        let cells: Vec<String> = keys
+            .iter()
+            .map(|k| csv_field(&row.get(k).map(|v| csv_cell(v, opts)).unwrap_or_default()))
+            .collect();
+        write!(w, "{}\r\n", cells.join(","))?;
+    }
+    Ok(())
+}
+
+/// Rows for the flat tabular formats (CSV, Markdown).
+///
+/// Arrays are used as-is; objects holding lists of objects are flattened with a `type`
+/// column; any other object becomes `{key, value}` rows and a scalar a single `result` row.
+fn tabular_rows(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items.clone(),
+        Value::Object(obj) => {
+            let mut merged = Vec::new();
+            for (key, val) in obj {
+                let Some(items) = val.as_array() else { continue };
+                if items.is_empty() || !items.iter().all(|item| item.is_object()) {
+                    continue;
+                }
+                let type_name = key
+                    .strip_suffix("es")
+                    .or_else(|| key.strip_suffix('s'))
+                    .unwrap_or(key);
+                for item in items {
+                    let mut row = item.as_object().cloned().unwrap_or_default();
+                    row.insert("type".to_string(), Value::String(type_name.to_string()));
+                    merged.push(Value::Object(row));
+                }
+            }
+            if merged.is_empty() {
+                obj.iter().map(|(k, v)| json!({ "key": k, "value": v })).collect()
             } else {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&result).unwrap_or_default()
-                );
+                merged
             }
-            return;
         }
+        scalar => vec![json!({ "key": "result", "value": scalar })],
+    }
+}
+
+/// Text of one CSV cell: strings verbatim, null empty, numbers, booleans, objects and arrays as JSON
+fn csv_cell(v: &Value, opts: &RenderOptions) -> String {
+    match v {
+        Value::Null => String::new(),
+        Value::String(s) => escape_control(s, opts).into_owned(),
+        other => other.to_string(),
+    }
+}
+
+/// Write a result as GitHub-Flavored Markdown.
+///
+/// Rows come from the same flattening as CSV, so lists of objects become a table with
+/// a column per key and other objects a `key | value` table. String results are
+/// wrapped in a fenced code block, with a fence longer than any backtick run inside.
+fn print_markdown(value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+    if let Value::String(text) = value {
+        let longest_run = text
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest_run.max(2) + 1);
+        writeln!(w, "{}", fence)?;
+        writeln!(w, "{}", text.strip_suffix('\n').unwrap_or(text))?;
+        writeln!(w, "{}", fence)?;
+        return Ok(());
+    }
+
+    let rows = tabular_rows(value);
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let keys = if rows.iter().all(|row| row.is_object()) {
+        select_columns(collect_columns(&rows), opts)
+    } else {
+        vec!["value".to_string()]
     };
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    let header: Vec<String> = keys.iter().map(|k| markdown_cell(&Value::String(k.clone()), opts)).collect();
+    writeln!(w, "| {} |", header.join(" | "))?;
+    writeln!(w, "|{}|", vec!["---"; keys.len()].join("|"))?;
+    for row in &rows {
+        let cells: Vec<String> = if row.is_object() {
+            keys.iter()
+                .map(|k| row.get(k).map(|v| markdown_cell(v, opts)).unwrap_or_default())
+                .collect()
+        } else {
+            vec![markdown_cell(row, opts)]
+        };
+        writeln!(w, "| {} |", cells.join(" | "))?;
+    }
+    Ok(())
+}
+
+/// Text of one Markdown table cell: `|` escaped and line breaks as `<br>` so the row stays intact
+fn markdown_cell(v: &Value, opts: &RenderOptions) -> String {
+    csv_cell(v, opts)
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn print_table(value: &Value, opts: &RenderOptions, w: &mut dyn Write) -> Result<()> {
+    let compact = opts.compact;
+
+    // Guard against pathologically nested responses before walking them
+    if nesting_depth(value) > opts.max_depth {
+        opts.warnings.warn(format!(
+            "response nesting exceeds --max-depth {}, printing raw JSON",
+            opts.max_depth
+        ));
+        return print_raw_json(value, compact, w);
+    }
 
     // Handle string result
     if let Some(s) = value.as_str() {
-        println!("{}", s);
-        return;
+        writeln!(w, "{}", escape_control(s, opts))?;
+        return Ok(());
     }
 
     // Handle array of objects
     if let Some(items) = value.as_array() {
         if !items.is_empty() {
-            if let Some(table) = format_array_to_table(items, compact) {
-                println!("{}", table);
-                return;
+            if let Some(table) = format_array_to_table(items, opts) {
+                writeln!(w, "{}", table)?;
+                return Ok(());
             }
         } else {
-            println!("(empty)");
-            return;
+            writeln!(w, "(empty)")?;
+            return Ok(());
         }
     }
 
@@ -96,15 +1009,11 @@ fn print_table<T: Serialize>(result: T, compact: bool) {
                 && obj.contains_key("is_healthy")
                 && obj.contains_key("status")
             {
-                let health = if obj["is_healthy"].as_bool().unwrap_or(false) {
-                    "healthy"
-                } else {
-                    "unhealthy"
-                };
-                let name = obj["name"].as_str().unwrap_or("");
-                let status = obj["status"].as_str().unwrap_or("");
-                println!("[{}] ({})\n{}", name, health, status);
-                return;
+                let health = health_label(obj["is_healthy"].as_bool().unwrap_or(false), opts);
+                let name = escape_control(obj["name"].as_str().unwrap_or(""), opts);
+                let status = escape_control(obj["status"].as_str().unwrap_or(""), opts);
+                writeln!(w, "[{}] ({})\n{}", name, health, status)?;
+                return Ok(());
             }
 
             // Rule 6: SystemStatus (is_healthy + components)
@@ -113,30 +1022,26 @@ fn print_table<T: Serialize>(result: T, compact: bool) {
                 if let Some(components) = obj["components"].as_object() {
                     for (_key, comp) in components {
                         // Try to render each component as table
-                        let comp_table = value_to_table(comp, compact);
+                        let comp_table = value_to_table(comp, opts);
                         if let Some(table) = comp_table {
                             lines.push(table);
                             lines.push("".to_string());
                         }
                     }
                 }
-                let health = if obj["is_healthy"].as_bool().unwrap_or(false) {
-                    "healthy"
-                } else {
-                    "unhealthy"
-                };
+                let health = health_label(obj["is_healthy"].as_bool().unwrap_or(false), opts);
                 lines.push(format!("[system] ({})", health));
                 if let Some(errors) = obj.get("errors") {
                     if let Some(err_list) = errors.as_array() {
                         let error_strs: Vec<&str> =
                             err_list.iter().filter_map(|e| e.as_str()).collect();
                         if !error_strs.is_empty() {
-                            lines.push(format!("Errors: {}", error_strs.join(", ")));
+                            lines.push(format!("Errors: {}", escape_control(&error_strs.join(", "), opts)));
                         }
                     }
                 }
-                println!("{}", lines.join("\n"));
-                return;
+                writeln!(w, "{}", lines.join("\n"))?;
+                return Ok(());
             }
 
             // Extract list fields
@@ -174,18 +1079,18 @@ fn print_table<T: Serialize>(result: T, compact: bool) {
                     row.insert(col.to_string(), item.clone());
                     rows.push(serde_json::Value::Object(row));
                 }
-                if let Some(table) = format_array_to_table(&rows, compact) {
-                    println!("{}", table);
-                    return;
+                if let Some(table) = format_array_to_table(&rows, opts) {
+                    writeln!(w, "{}", table)?;
+                    return Ok(());
                 }
             }
 
             // Rule 3b: single list[dict] -> render directly
             if dict_lists.len() == 1 && prim_lists.is_empty() {
                 let (_key, items) = &dict_lists[0];
-                if let Some(table) = format_array_to_table(items, compact) {
-                    println!("{}", table);
-                    return;
+                if let Some(table) = format_array_to_table(items, opts) {
+                    writeln!(w, "{}", table)?;
+                    return Ok(());
                 }
             }
 
@@ -211,53 +1116,86 @@ fn print_table<T: Serialize>(result: T, compact: bool) {
                     }
                 }
                 if !merged.is_empty() {
-                    if let Some(table) = format_array_to_table(&merged, compact) {
-                        println!("{}", table);
-                        return;
+                    if let Some(table) = format_array_to_table(&merged, opts) {
+                        writeln!(w, "{}", table)?;
+                        return Ok(());
                     }
                 }
             }
 
             // Rule 4: plain dict (no expandable lists) -> single-row horizontal table
             if dict_lists.is_empty() && prim_lists.is_empty() {
+                let keys = select_columns(obj.keys().cloned().collect(), opts);
+
                 // Calculate max key width
-                let max_key_width = obj
-                    .keys()
-                    .map(|k| k.width())
+                let max_key_width = keys
+                    .iter()
+                    .map(|k| display_width(&escape_key(k, opts)))
                     .max()
                     .unwrap_or(0)
-                    .min(MAX_COL_WIDTH);
+                    .min(width_cap(opts));
 
                 let mut output = String::new();
-                for (k, v) in obj {
+                for k in &keys {
+                    let v = &obj[k];
                     let is_uri = k == "uri";
-                    let formatted_value = format_value(v);
-                    let (content, _) = truncate_string(&formatted_value, is_uri, MAX_COL_WIDTH);
-                    let padded_key = pad_cell(k, max_key_width, false);
-                    output.push_str(&format!("{}  {}\n", padded_key, content));
+                    let formatted_value = format_field(k, v, opts);
+                    let (content, _) = truncate_string(&formatted_value, is_uri, opts.max_col_width, opts);
+                    let style = if v.is_null() {
+                        CellStyle::Null
+                    } else if is_uri {
+                        CellStyle::Uri
+                    } else if v.is_number() {
+                        CellStyle::Number
+                    } else {
+                        CellStyle::Plain
+                    };
+                    let padded_key = pad_styled(&escape_key(k, opts), max_key_width, false, CellStyle::Header, opts);
+                    output.push_str(&format!("{}  {}\n", padded_key, paint(&content, style, opts)));
                 }
-                println!("{}", output);
-                return;
+                writeln!(w, "{}", output)?;
+                return Ok(());
             }
         }
     }
 
     // Default: JSON output
+    print_raw_json(value, compact, w)
+}
+
+fn print_raw_json(value: &Value, compact: bool, w: &mut dyn Write) -> Result<()> {
     if compact {
-        println!("{}", serde_json::to_string(&result).unwrap_or_default());
+        writeln!(w, "{}", serde_json::to_string(value).unwrap_or_default())?;
     } else {
-        println!(
+        writeln!(
+            w,
             "{}",
-            serde_json::to_string_pretty(&result).unwrap_or_default()
-        );
+            serde_json::to_string_pretty(value).unwrap_or_default()
+        )?;
+    }
+    Ok(())
+}
+
+/// Maximum nesting depth of arrays/objects, computed iteratively so it cannot overflow the stack
+fn nesting_depth(value: &serde_json::Value) -> usize {
+    let mut max_depth = 0;
+    let mut stack = vec![(value, 0usize)];
+    while let Some((v, depth)) = stack.pop() {
+        max_depth = max_depth.max(depth);
+        match v {
+            serde_json::Value::Array(items) => stack.extend(items.iter().map(|i| (i, depth + 1))),
+            serde_json::Value::Object(obj) => stack.extend(obj.values().map(|i| (i, depth + 1))),
+            _ => {}
+        }
     }
+    max_depth
 }
 
-fn value_to_table(value: &serde_json::Value, compact: bool) -> Option<String> {
+fn value_to_table(value: &serde_json::Value, opts: &RenderOptions) -> Option<String> {
     // Rule 1: list[dict] -> multi-row table
     if let Some(items) = value.as_array() {
         if !items.is_empty() && items.iter().all(|i| i.is_object()) {
-            return format_array_to_table(items, compact);
+            return format_array_to_table(items, opts);
         }
     }
 
@@ -265,13 +1203,9 @@ fn value_to_table(value: &serde_json::Value, compact: bool) -> Option<String> {
         // ComponentStatus (name + is_healthy + status)
         if obj.contains_key("name") && obj.contains_key("is_healthy") && obj.contains_key("status")
         {
-            let health = if obj["is_healthy"].as_bool().unwrap_or(false) {
-                "healthy"
-            } else {
-                "unhealthy"
-            };
-            let name = obj["name"].as_str().unwrap_or("");
-            let status = obj["status"].as_str().unwrap_or("");
+            let health = health_label(obj["is_healthy"].as_bool().unwrap_or(false), opts);
+            let name = escape_control(obj["name"].as_str().unwrap_or(""), opts);
+            let status = escape_control(obj["status"].as_str().unwrap_or(""), opts);
             return Some(format!("[{}] ({})\n{}", name, health, status));
         }
 
@@ -310,13 +1244,13 @@ fn value_to_table(value: &serde_json::Value, compact: bool) -> Option<String> {
                 row.insert(col.to_string(), item.clone());
                 rows.push(serde_json::Value::Object(row));
             }
-            return format_array_to_table(&rows, compact);
+            return format_array_to_table(&rows, opts);
         }
 
         // Rule 3b: single list[dict] -> render directly
         if dict_lists.len() == 1 && prim_lists.is_empty() {
             let (_key, items) = &dict_lists[0];
-            return format_array_to_table(items, compact);
+            return format_array_to_table(items, opts);
         }
 
         // Rule 2: multiple list[dict] -> flatten with type column
@@ -341,7 +1275,7 @@ fn value_to_table(value: &serde_json::Value, compact: bool) -> Option<String> {
                 }
             }
             if !merged.is_empty() {
-                return format_array_to_table(&merged, compact);
+                return format_array_to_table(&merged, opts);
             }
         }
     }
@@ -353,9 +1287,10 @@ struct ColumnInfo {
     max_width: usize,    // Max width for alignment (capped at 120)
     is_numeric: bool,    // True if all values in column are numeric
     is_uri_column: bool, // True if column name is "uri"
+    pinned: bool,        // Width set by --col-width: cells are cut or padded to exactly max_width
 }
 
-fn format_array_to_table(items: &Vec<serde_json::Value>, compact: bool) -> Option<String> {
+fn format_array_to_table(items: &Vec<serde_json::Value>, opts: &RenderOptions) -> Option<String> {
     if items.is_empty() {
         return None;
     }
@@ -365,72 +1300,38 @@ fn format_array_to_table(items: &Vec<serde_json::Value>, compact: bool) -> Optio
         // Handle list of primitives
         let mut output = String::new();
         for item in items {
-            let (content, _) = truncate_string(&format_value(item), false, MAX_COL_WIDTH);
+            let (content, _) = truncate_string(&format_value(item, opts), false, opts.max_col_width, opts);
             output.push_str(&format!("{}\n", content));
         }
         return Some(output);
     }
 
-    // Collect all unique keys
-    let mut keys: Vec<String> = Vec::new();
-    let mut key_set = std::collections::HashSet::new();
+    let mut keys = select_columns(collect_columns(items), opts);
 
-    for item in items {
-        if let Some(obj) = item.as_object() {
-            for k in obj.keys() {
-                if key_set.insert(k.clone()) {
-                    keys.push(k.clone());
-                }
-            }
-        }
+    // Filter out empty columns when compact is true, unless --show-empty is set
+    if opts.compact && !opts.show_empty {
+        drop_empty_columns(&mut keys, items);
     }
-
     if keys.is_empty() {
         return None;
     }
 
-    // Filter out empty columns when compact is true
-    let filtered_keys: Vec<String> = if compact {
-        keys.iter()
-            .filter(|key| {
-                items.iter().any(|item| {
-                    if let Some(obj) = item.as_object() {
-                        if let Some(value) = obj.get(*key) {
-                            return !value.is_null()
-                                && value != ""
-                                && !(value.is_array() && value.as_array().unwrap().is_empty());
-                        }
-                    }
-                    false
-                })
-            })
-            .cloned()
-            .collect()
-    } else {
-        keys.clone()
-    };
-
-    if filtered_keys.is_empty() {
-        return None;
-    }
-
-    let keys = filtered_keys;
-
     // First pass: analyze columns
     let mut column_info: Vec<ColumnInfo> = Vec::new();
 
     for key in &keys {
         let is_uri_column = key == "uri";
         let mut is_numeric = true;
-        let mut max_width = key.width(); // Start with header width
+        // Start with header width, unless the header is hidden
+        let mut max_width = if opts.no_header { 0 } else { display_width(&escape_key(key, opts)) };
 
         for item in items {
             if let Some(obj) = item.as_object() {
                 if let Some(value) = obj.get(key) {
-                    let formatted = format_value(value);
-                    let display_width = formatted.width();
+                    let formatted = format_field(key, value, opts);
+                    let width = display_width(&formatted);
 
-                    max_width = max_width.max(display_width.min(MAX_COL_WIDTH));
+                    max_width = max_width.max(width.min(width_cap(opts)));
 
                     // Check if numeric
                     if is_numeric && !is_numeric_value(value) {
@@ -440,74 +1341,420 @@ fn format_array_to_table(items: &Vec<serde_json::Value>, compact: bool) -> Optio
             }
         }
 
+        let pinned = opts.col_widths.get(key).copied();
         column_info.push(ColumnInfo {
-            max_width,
+            max_width: pinned.unwrap_or(max_width),
             is_numeric,
             is_uri_column,
+            pinned: pinned.is_some(),
         });
     }
 
-    // Second pass: format rows
-    let mut output = String::new();
+    // Second pass: format rows
+    let mut output = String::new();
+
+    // Header row
+    if !opts.no_header {
+        let header_cells: Vec<String> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| {
+                let info = &column_info[i];
+                let k = escape_key(k, opts);
+                if info.pinned {
+                    pad_styled(&truncate_to_width(&k, info.max_width), info.max_width, false, CellStyle::Header, opts)
+                } else {
+                    pad_styled(&k, info.max_width, false, CellStyle::Header, opts)
+                }
+            })
+            .collect();
+        output.push_str(&header_cells.join("  "));
+        output.push('\n');
+    }
+
+    // Data rows
+    for item in items {
+        if let Some(obj) = item.as_object() {
+            let row_cells: Vec<String> = keys
+                .iter()
+                .enumerate()
+                .map(|(i, k)| {
+                    let info = &column_info[i];
+                    let raw = obj.get(k);
+                    let value = raw.map(|v| format_field(k, v, opts)).unwrap_or_default();
+                    let style = if raw.is_some_and(|v| v.is_null()) {
+                        CellStyle::Null
+                    } else if info.is_uri_column {
+                        CellStyle::Uri
+                    } else if info.is_numeric {
+                        CellStyle::Number
+                    } else {
+                        CellStyle::Plain
+                    };
+
+                    if info.pinned {
+                        let content = truncate_to_width(&value, info.max_width);
+                        return pad_styled(&content, info.max_width, info.is_numeric, style, opts);
+                    }
+
+                    let (content, skip_padding) =
+                        truncate_string(&value, info.is_uri_column, info.max_width, opts);
+
+                    if skip_padding {
+                        // Long URI, output as-is without padding
+                        paint(&content, style, opts)
+                    } else {
+                        // Normal cell, apply padding and alignment
+                        pad_styled(&content, info.max_width, info.is_numeric, style, opts)
+                    }
+                })
+                .collect();
+
+            output.push_str(&row_cells.join("  "));
+            output.push('\n');
+        }
+    }
+
+    Some(output)
+}
+
+/// Union of the keys of all object rows, in first-seen order
+fn collect_columns(items: &[serde_json::Value]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut key_set = std::collections::HashSet::new();
+
+    for item in items {
+        if let Some(obj) = item.as_object() {
+            for k in obj.keys() {
+                if key_set.insert(k.clone()) {
+                    keys.push(k.clone());
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// Restrict `keys` to the `--columns` selection, in the order given there.
+/// Requested names that are not present are skipped with a one-time warning.
+fn select_columns(keys: Vec<String>, opts: &RenderOptions) -> Vec<String> {
+    if opts.columns.is_empty() {
+        return keys;
+    }
+    for missing in opts.columns.iter().filter(|c| !keys.contains(c)) {
+        warn_missing_field(missing, opts);
+    }
+    opts.columns
+        .iter()
+        .filter(|c| keys.contains(c))
+        .cloned()
+        .collect()
+}
+
+fn warn_missing_field(field: &str, opts: &RenderOptions) {
+    opts.warnings.warn_once(&format!("field:{}", field), format!("field '{}' not found in output", field));
+}
+
+/// Value at a `--fields` name: a literal key, else a dotted path like `meta.size`
+fn field_at<'a>(obj: &'a serde_json::Map<String, Value>, field: &str) -> Option<&'a Value> {
+    obj.get(field).or_else(|| {
+        let (head, rest) = field.split_once('.')?;
+        field_at(obj.get(head)?.as_object()?, rest)
+    })
+}
+
+/// Apply `--columns`/`--fields` to a JSON result: rows of an array (or of list fields of
+/// an object) keep only the selected keys, in order; a plain object is filtered itself.
+/// Dotted paths keep the nesting, so `meta.size` yields `{"meta": {"size": ...}}`.
+fn select_fields(value: &Value, opts: &RenderOptions) -> Value {
+    fn pick(obj: &serde_json::Map<String, Value>, fields: &[String]) -> Value {
+        fn insert(out: &mut serde_json::Map<String, Value>, obj: &serde_json::Map<String, Value>, field: &str) {
+            if let Some(value) = obj.get(field) {
+                out.insert(field.to_string(), value.clone());
+            } else if let Some((head, rest)) = field.split_once('.') {
+                if let Some(inner) = obj.get(head).and_then(Value::as_object) {
+                    let slot = out.entry(head.to_string()).or_insert_with(|| json!({}));
+                    if let Some(slot) = slot.as_object_mut() {
+                        insert(slot, inner, rest);
+                    }
+                }
+            }
+        }
+
+        let mut out = serde_json::Map::new();
+        for field in fields {
+            insert(&mut out, obj, field);
+        }
+        Value::Object(out)
+    }
+    fn warn_missing(objects: &[&serde_json::Map<String, Value>], opts: &RenderOptions) {
+        for field in &opts.columns {
+            if !objects.iter().any(|obj| field_at(obj, field).is_some()) {
+                warn_missing_field(field, opts);
+            }
+        }
+    }
+
+    match value {
+        Value::Array(items) if items.iter().any(Value::is_object) => {
+            warn_missing(&items.iter().filter_map(Value::as_object).collect::<Vec<_>>(), opts);
+            Value::Array(
+                items
+                    .iter()
+                    .map(|item| match item.as_object() {
+                        Some(obj) => pick(obj, &opts.columns),
+                        None => item.clone(),
+                    })
+                    .collect(),
+            )
+        }
+        Value::Object(obj) => {
+            let has_rows = obj
+                .values()
+                .any(|v| v.as_array().is_some_and(|a| a.iter().any(Value::is_object)));
+            if has_rows {
+                Value::Object(
+                    obj.iter()
+                        .map(|(k, v)| (k.clone(), if v.is_array() { select_fields(v, opts) } else { v.clone() }))
+                        .collect(),
+                )
+            } else {
+                warn_missing(&[obj], opts);
+                pick(obj, &opts.columns)
+            }
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Drop columns that are null, empty or missing in every row
+fn drop_empty_columns(keys: &mut Vec<String>, items: &[serde_json::Value]) {
+    keys.retain(|key| {
+        items.iter().any(|item| {
+            if let Some(obj) = item.as_object() {
+                if let Some(value) = obj.get(key) {
+                    return !value.is_null()
+                        && value != ""
+                        && !(value.is_array() && value.as_array().unwrap().is_empty());
+                }
+            }
+            false
+        })
+    });
+}
+
+/// Format a named table cell, applying `--human-time` to timestamp and duration fields
+fn format_field(key: &str, v: &serde_json::Value, opts: &RenderOptions) -> String {
+    if opts.human_time {
+        if let Some(n) = v.as_f64() {
+            let key = key.to_ascii_lowercase();
+            if key.ends_with("_ms") {
+                return human_duration(n / 1000.0);
+            }
+            if key.starts_with("duration") {
+                return human_duration(n);
+            }
+            if key.ends_with("_at") || key.ends_with("time") || key == "created" || key == "updated" {
+                if let Some(ts) = human_timestamp(n) {
+                    return ts;
+                }
+            }
+        }
+    }
+    format_value(v, opts)
+}
+
+/// Local datetime for an epoch timestamp in seconds, or milliseconds when too large for seconds
+fn human_timestamp(epoch: f64) -> Option<String> {
+    let millis = if epoch.abs() >= 1e12 { epoch } else { epoch * 1000.0 };
+    let utc = chrono::DateTime::from_timestamp_millis(millis as i64)?;
+    Some(
+        utc.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+    )
+}
+
+/// Compact duration like `850ms`, `1.2s`, `3m 5s` or `2h 10m`
+fn human_duration(secs: f64) -> String {
+    if secs < 1.0 {
+        return format!("{}ms", (secs * 1000.0).round() as i64);
+    }
+    if secs < 60.0 {
+        return format!("{:.1}s", secs);
+    }
+    let total = secs.round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+    match (hours, minutes, seconds) {
+        (0, m, 0) => format!("{}m", m),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, 0, _) => format!("{}h", h),
+        (h, m, _) => format!("{}h {}m", h, m),
+    }
+}
+
+/// Byte count in binary units, like `512 B`, `1.5 KB` or `2.0 GB`
+pub fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+fn format_value(v: &serde_json::Value, opts: &RenderOptions) -> String {
+    match v {
+        serde_json::Value::String(s) => escape_control(s, opts).into_owned(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Null => "null".to_string(),
+        _ => v.to_string(),
+    }
+}
+
+/// Replace control characters (ANSI escapes, NUL, CR, C1 codes) with visible `\xNN` escapes
+/// so server-provided text cannot drive the terminal. Newlines and tabs are kept.
+fn escape_control<'a>(s: &'a str, opts: &RenderOptions) -> Cow<'a, str> {
+    let is_unsafe = |c: char| c.is_control() && c != '\n' && c != '\t';
+    if !opts.escape_control || !s.chars().any(is_unsafe) {
+        return Cow::Borrowed(s);
+    }
+    let mut escaped = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if is_unsafe(c) {
+            escaped.push_str(&format!("\\x{:02x}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Escape a key shown as a column header or row label. Unlike values, newlines and tabs
+/// are escaped as well so the label stays on one line and the columns stay aligned.
+fn escape_key<'a>(key: &'a str, opts: &RenderOptions) -> Cow<'a, str> {
+    if !opts.escape_control || !key.chars().any(char::is_control) {
+        return Cow::Borrowed(key);
+    }
+    Cow::Owned(
+        key.chars()
+            .map(|c| if c.is_control() { format!("\\x{:02x}", c as u32) } else { c.to_string() })
+            .collect(),
+    )
+}
+
+/// Display width of a single grapheme cluster.
+///
+/// A cluster renders as one glyph, so ZWJ emoji sequences and base + combining
+/// marks take the width of their widest code point instead of the sum. Code
+/// points without width data (control characters) count as zero.
+fn grapheme_width(g: &str) -> usize {
+    let width = g.chars().map(|c| c.width().unwrap_or(0)).max().unwrap_or(0);
+    if g.contains('\u{FE0F}') {
+        // Emoji presentation selector forces a wide glyph
+        width.max(2)
+    } else {
+        width
+    }
+}
+
+/// Display width of a string, measured per grapheme cluster
+fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
 
-    // Header row
-    let header_cells: Vec<String> = keys
-        .iter()
-        .enumerate()
-        .map(|(i, k)| pad_cell(k, column_info[i].max_width, false))
-        .collect();
-    output.push_str(&header_cells.join("  "));
-    output.push('\n');
+/// How a table cell is colored when `--color` is in effect
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CellStyle {
+    Plain,
+    Header,
+    Number,
+    Uri,
+    Null,
+    Healthy,
+    Unhealthy,
+}
 
-    // Data rows
-    for item in items {
-        if let Some(obj) = item.as_object() {
-            let row_cells: Vec<String> = keys
-                .iter()
-                .enumerate()
-                .map(|(i, k)| {
-                    let info = &column_info[i];
-                    let value = obj.get(k).map(|v| format_value(v)).unwrap_or_default();
+/// Wrap `content` in the ANSI style for `style`, or return it unchanged without `--color`
+fn paint(content: &str, style: CellStyle, opts: &RenderOptions) -> String {
+    use colored::Colorize;
 
-                    let (content, skip_padding) =
-                        truncate_string(&value, info.is_uri_column, info.max_width);
+    if !opts.color || content.is_empty() {
+        return content.to_string();
+    }
+    match style {
+        CellStyle::Plain => content.to_string(),
+        CellStyle::Header => content.bold().cyan().to_string(),
+        CellStyle::Number => content.yellow().to_string(),
+        CellStyle::Uri => content.blue().underline().to_string(),
+        CellStyle::Null => content.dimmed().to_string(),
+        CellStyle::Healthy => content.green().to_string(),
+        CellStyle::Unhealthy => content.red().to_string(),
+    }
+}
 
-                    if skip_padding {
-                        // Long URI, output as-is without padding
-                        content
-                    } else {
-                        // Normal cell, apply padding and alignment
-                        pad_cell(&content, info.max_width, info.is_numeric)
-                    }
-                })
-                .collect();
+/// "healthy" in green or "unhealthy" in red for ComponentStatus/SystemStatus headers
+fn health_label(healthy: bool, opts: &RenderOptions) -> String {
+    if healthy {
+        paint("healthy", CellStyle::Healthy, opts)
+    } else {
+        paint("unhealthy", CellStyle::Unhealthy, opts)
+    }
+}
 
-            output.push_str(&row_cells.join("  "));
-            output.push('\n');
-        }
+/// Color a bare health status (e.g. `ov health`) green when `healthy`, red otherwise
+pub fn paint_health(status: &str, healthy: bool, out: &Output) -> String {
+    let style = if healthy { CellStyle::Healthy } else { CellStyle::Unhealthy };
+    paint(status, style, &out.opts)
+}
+
+/// Pad by the display width of the raw `content`, then color only the content so
+/// escape codes never count towards alignment and padding stays unstyled
+fn pad_styled(content: &str, width: usize, align_right: bool, style: CellStyle, opts: &RenderOptions) -> String {
+    if !opts.color || style == CellStyle::Plain {
+        return pad_cell(content, width, align_right);
     }
+    let padding = " ".repeat(width.saturating_sub(display_width(content)));
+    let painted = paint(content, style, opts);
+    if align_right {
+        format!("{}{}", padding, painted)
+    } else {
+        format!("{}{}", painted, padding)
+    }
+}
 
-    Some(output)
+/// Color choice for `--color`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is unset
+    Auto,
+    Always,
+    Never,
 }
 
-fn format_value(v: &serde_json::Value) -> String {
-    match v {
-        serde_json::Value::String(s) => s.clone(),
-        serde_json::Value::Number(n) => n.to_string(),
-        serde_json::Value::Bool(b) => b.to_string(),
-        serde_json::Value::Null => "null".to_string(),
-        _ => v.to_string(),
+/// Decide whether to color output; `NO_COLOR` (any non-empty value) disables `auto`
+pub fn resolve_color(choice: ColorChoice, is_terminal: bool, no_color: Option<&str>) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => is_terminal && no_color.is_none_or(str::is_empty),
     }
 }
 
 fn pad_cell(content: &str, width: usize, align_right: bool) -> String {
-    let display_width = content.width();
+    let content_width = display_width(content);
 
-    if display_width >= width {
+    if content_width >= width {
         return content.to_string();
     }
 
-    let padding_needed = width - display_width;
+    let padding_needed = width - content_width;
     if align_right {
         format!("{}{}", " ".repeat(padding_needed), content)
     } else {
@@ -523,41 +1770,97 @@ fn is_numeric_value(v: &serde_json::Value) -> bool {
     }
 }
 
-fn truncate_string(s: &str, is_uri: bool, max_width: usize) -> (String, bool) {
-    let display_width = s.width();
+fn truncate_string(s: &str, is_uri: bool, max_width: usize, opts: &RenderOptions) -> (String, bool) {
+    let width = display_width(s);
 
     // URI columns: never truncate
     if is_uri {
-        if display_width > max_width {
+        if width > max_width {
             return (s.to_string(), true); // true = skip padding
         } else {
             return (s.to_string(), false);
         }
     }
 
-    // Normal truncation - truncate by display width
-    if display_width > MAX_COL_WIDTH {
-        let mut current_width = 0;
-        let mut truncated = String::new();
-        for ch in s.chars() {
-            let ch_width = ch.width().unwrap_or(0);
-            if current_width + ch_width > MAX_COL_WIDTH - 3 {
-                break;
-            }
-            current_width += ch_width;
-            truncated.push(ch);
-        }
-        (format!("{}...", truncated), false)
+    if opts.no_truncate {
+        return (s.to_string(), false);
+    }
+
+    // Normal truncation
+    (truncate_to_width(s, opts.max_col_width), false)
+}
+
+/// Widest a column auto-sizes to: `--truncate` (default `MAX_COL_WIDTH`), or unbounded
+/// with `--no-truncate`
+fn width_cap(opts: &RenderOptions) -> usize {
+    if opts.no_truncate {
+        usize::MAX
     } else {
-        (s.to_string(), false)
+        opts.max_col_width
+    }
+}
+
+/// Cut `s` to at most `max_width` display columns, never splitting a grapheme cluster.
+/// An ellipsis marks the cut when there is room for one.
+fn truncate_to_width(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
     }
+    let ellipsis = if max_width > 3 { "..." } else { "" };
+    let budget = max_width - ellipsis.len();
+    let mut current_width = 0;
+    let mut truncated = String::new();
+    for g in s.graphemes(true) {
+        let g_width = grapheme_width(g);
+        if current_width + g_width > budget {
+            break;
+        }
+        current_width += g_width;
+        truncated.push_str(g);
+    }
+    truncated.push_str(ellipsis);
+    truncated
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use serde_json::json;
 
+    /// Default rendering options in `format`, for command tests
+    pub(crate) fn output_for(format: OutputFormat) -> Output {
+        Output::new(format, RenderOptions::default())
+    }
+
+    #[test]
+    fn test_warnings_are_shared_and_recorded() {
+        let out = output_for(OutputFormat::Table);
+        assert!(!out.opts.warnings.emitted());
+        let json = out.with_format(OutputFormat::Json);
+        json.opts.warnings.warn_once("key", "first");
+        json.opts.warnings.warn_once("key", "again");
+        assert!(out.opts.warnings.emitted());
+        assert_eq!(out.opts.warnings.log.lock().unwrap().once, vec!["key"]);
+
+        // Render-time warnings are recorded too
+        let opts = RenderOptions { columns: vec!["missing".to_string()], ..RenderOptions::default() };
+        select_fields(&json!([{ "a": 1 }]), &opts);
+        assert!(opts.warnings.emitted());
+        assert!(!output_for(OutputFormat::Table).opts.warnings.emitted());
+    }
+
+    #[test]
+    fn test_output_carries_its_own_options() {
+        let plain = output_for(OutputFormat::Table);
+        let escaped = Output::new(OutputFormat::Table, RenderOptions { escape_control: true, ..RenderOptions::default() });
+        assert!(content_is_verbatim(&plain));
+        assert!(!content_is_verbatim(&escaped));
+
+        let json = escaped.with_format(OutputFormat::Json);
+        assert_eq!(json.format, OutputFormat::Json);
+        assert!(json.opts.escape_control);
+    }
+
     #[test]
     fn test_object_formatting_with_alignment() {
         // Test object with keys of different lengths
@@ -568,9 +1871,10 @@ mod tests {
             "type": "document"
         });
 
-        // This should not panic and should produce aligned output
-        // We can't easily capture stdout, but at least verify it doesn't crash
-        print_table(obj, true);
+        let mut out = Vec::new();
+        print_table(&obj, &RenderOptions::default(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("id    123\nname  Test Resource\n"));
     }
 
     #[test]
@@ -581,12 +1885,738 @@ mod tests {
             "uri": "viking://resources/very/long/path/that/exceeds/normal/width/limits/and/should/not/be/truncated/because/it/is/a/uri"
         });
 
-        print_table(obj, true);
+        let mut out = Vec::new();
+        print_table(&obj, &RenderOptions::default(), &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("/because/it/is/a/uri"));
     }
 
     #[test]
     fn test_empty_object() {
         let obj = json!({});
-        print_table(obj, true);
+        let mut out = Vec::new();
+        print_table(&obj, &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "{}\n");
+    }
+
+    #[test]
+    fn test_compact_hides_empty_columns() {
+        let items = vec![
+            json!({"uri": "viking://a", "abstract": ""}),
+            json!({"uri": "viking://b", "abstract": null}),
+        ];
+        let table = format_array_to_table(&items, &RenderOptions::default()).unwrap();
+        assert!(!table.contains("abstract"));
+    }
+
+    #[test]
+    fn test_show_empty_keeps_empty_columns() {
+        let items = vec![
+            json!({"uri": "viking://a", "abstract": ""}),
+            json!({"uri": "viking://b", "abstract": null}),
+        ];
+        let opts = RenderOptions {
+            show_empty: true,
+            ..Default::default()
+        };
+        let table = format_array_to_table(&items, &opts).unwrap();
+        assert!(table.lines().next().unwrap().contains("abstract"));
+    }
+
+    #[test]
+    fn test_display_width_cjk() {
+        assert_eq!(display_width("资源"), 4);
+        assert_eq!(pad_cell("资源", 6, false), "资源  ");
+    }
+
+    #[test]
+    fn test_display_width_combining_accent() {
+        // "e" + COMBINING ACUTE ACCENT renders as a single column
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(pad_cell("cafe\u{301}", 5, true), " cafe\u{301}");
+    }
+
+    #[test]
+    fn test_display_width_emoji_sequences() {
+        // Family emoji joined with ZWJ is one wide glyph
+        assert_eq!(display_width("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"), 2);
+        // Heart with emoji presentation selector
+        assert_eq!(display_width("\u{2764}\u{FE0F}"), 2);
+    }
+
+    #[test]
+    fn test_truncate_does_not_split_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let long = family.repeat(200);
+        let (truncated, _) = truncate_string(&long, false, MAX_COL_WIDTH, &RenderOptions::default());
+        let body = truncated.strip_suffix("...").unwrap();
+        assert!(body.graphemes(true).all(|g| g == family));
+        assert!(display_width(&truncated) <= MAX_COL_WIDTH);
+    }
+
+    #[test]
+    fn test_truncate_width_override() {
+        let items = vec![json!({"abstract": "a".repeat(40), "size": 1})];
+        let opts = RenderOptions { max_col_width: 12, ..RenderOptions::default() };
+        let table = format_array_to_table(&items, &opts).unwrap();
+        let row = table.lines().nth(1).unwrap();
+        assert!(row.starts_with("aaaaaaaaa...  "), "{}", row);
+        assert_eq!(display_width(row), 12 + 2 + 4);
+    }
+
+    #[test]
+    fn test_no_header_omits_header_row() {
+        let items = vec![json!({"uri": "viking://a", "size": 10}), json!({"uri": "viking://bb", "size": 2})];
+        let opts = RenderOptions { no_header: true, ..RenderOptions::default() };
+        let table = format_array_to_table(&items, &opts).unwrap();
+        assert_eq!(table, "viking://a   10\nviking://bb   2\n");
+
+        let mut csv = Vec::new();
+        CsvRenderer.render(&Value::Array(items), &opts, &mut csv).unwrap();
+        assert!(String::from_utf8(csv).unwrap().starts_with("uri,size\r\n"));
+    }
+
+    #[test]
+    fn test_no_truncate_sizes_columns_to_content() {
+        let long = "\u{4e2d}\u{6587}".repeat(150);
+        let items = vec![
+            json!({"abstract": long.clone(), "size": 1}),
+            json!({"abstract": "short", "size": 2}),
+        ];
+        let opts = RenderOptions { no_truncate: true, ..RenderOptions::default() };
+        let table = format_array_to_table(&items, &opts).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[1].starts_with(&long));
+        // CJK characters are two columns wide, so the short row pads to 600 columns
+        assert_eq!(display_width(lines[2]), display_width(lines[1]));
+        assert_eq!(display_width(lines[1]), 600 + 2 + 4);
+
+        let truncated = format_array_to_table(&items, &RenderOptions::default()).unwrap();
+        assert!(truncated.lines().nth(1).unwrap().contains("..."));
+    }
+
+    #[test]
+    fn test_columns_selects_and_orders_keys() {
+        let items = vec![
+            json!({"uri": "viking://a", "size": 1, "abstract": "", "name": "a"}),
+            json!({"uri": "viking://b", "size": 2, "abstract": "", "name": "b"}),
+        ];
+        let opts = RenderOptions {
+            columns: vec!["size".to_string(), "missing".to_string(), "abstract".to_string(), "uri".to_string()],
+            ..RenderOptions::default()
+        };
+        let table = format_array_to_table(&items, &opts).unwrap();
+        let header: Vec<&str> = table.lines().next().unwrap().split_whitespace().collect();
+        // `abstract` is empty everywhere, so compact mode still drops it
+        assert_eq!(header, vec!["size", "uri"]);
+    }
+
+    #[test]
+    fn test_columns_filter_json_rows() {
+        let opts = RenderOptions {
+            columns: vec!["size".to_string(), "uri".to_string()],
+            ..RenderOptions::default()
+        };
+        let listing = json!([
+            {"uri": "viking://a", "size": 1, "abstract": "x"},
+            {"uri": "viking://b", "size": 2, "abstract": "y"},
+        ]);
+        let filtered = select_fields(&listing, &opts);
+        assert_eq!(filtered, json!([{"size": 1, "uri": "viking://a"}, {"size": 2, "uri": "viking://b"}]));
+        let keys: Vec<&String> = filtered[0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["size", "uri"]);
+
+        let found = json!({"resources": [{"uri": "viking://a", "score": 0.5}], "total": 1});
+        assert_eq!(select_fields(&found, &opts), json!({"resources": [{"uri": "viking://a"}], "total": 1}));
+        assert_eq!(select_fields(&json!({"uri": "viking://a", "size": 3, "x": 1}), &opts), json!({"size": 3, "uri": "viking://a"}));
+
+        let nested = RenderOptions {
+            columns: vec!["uri".to_string(), "meta.size".to_string(), "meta.kind".to_string(), "a.b".to_string()],
+            ..RenderOptions::default()
+        };
+        let item = json!({"uri": "viking://a", "meta": {"size": 3, "kind": "file", "x": 1}, "a.b": true, "y": 2});
+        assert_eq!(
+            select_fields(&json!([item]), &nested),
+            json!([{"uri": "viking://a", "meta": {"size": 3, "kind": "file"}, "a.b": true}])
+        );
+    }
+
+    #[test]
+    fn test_sort_by_field_numeric_desc_missing_last() {
+        let mut items = vec![
+            json!({"uri": "a", "score": 0.5}),
+            json!({"uri": "b"}),
+            json!({"uri": "c", "score": "0.9"}),
+            json!({"uri": "d", "score": 0.10}),
+        ];
+        sort_by_field(&mut items, "score", true);
+        let uris: Vec<&str> = items.iter().map(|i| i["uri"].as_str().unwrap()).collect();
+        assert_eq!(uris, vec!["c", "a", "d", "b"]);
+        sort_by_field(&mut items, "score", false);
+        let uris: Vec<&str> = items.iter().map(|i| i["uri"].as_str().unwrap()).collect();
+        assert_eq!(uris, vec!["d", "a", "c", "b"]);
+        sort_by_field(&mut items, "uri", true);
+        assert_eq!(items[0]["uri"], "d");
+    }
+
+    #[test]
+    fn test_sort_listing_numeric_string_and_reverse() {
+        let uris = |items: &[Value]| -> Vec<String> {
+            items.iter().map(|i| i["uri"].as_str().unwrap().to_string()).collect()
+        };
+        let mut items = vec![
+            json!({"uri": "viking://r/b", "size": 10, "owner": "zed"}),
+            json!({"uri": "viking://r/c"}),
+            json!({"uri": "viking://r/a", "size": 9, "owner": "amy"}),
+        ];
+        let size: SortSpec = "size".parse().unwrap();
+        assert_eq!(size, SortSpec::Key(SortKey::Size));
+        sort_listing(&mut items, &size, false, false);
+        assert_eq!(uris(&items), vec!["viking://r/a", "viking://r/b", "viking://r/c"]);
+        sort_listing(&mut items, &size, false, true);
+        assert_eq!(uris(&items), vec!["viking://r/b", "viking://r/a", "viking://r/c"]);
+
+        let owner: SortSpec = "owner".parse().unwrap();
+        assert_eq!(owner, SortSpec::Field("owner".to_string()));
+        sort_listing(&mut items, &owner, false, true);
+        assert_eq!(uris(&items), vec!["viking://r/b", "viking://r/a", "viking://r/c"]);
+        sort_listing(&mut items, &owner, false, false);
+        assert_eq!(uris(&items), vec!["viking://r/a", "viking://r/b", "viking://r/c"]);
+    }
+
+    #[test]
+    fn test_health_labels_colored_only_with_color() {
+        colored::control::set_override(true);
+        let status = json!({
+            "is_healthy": false,
+            "components": {
+                "queue": {"name": "queue", "is_healthy": true, "status": "idle"},
+            },
+        });
+        let mut plain = Vec::new();
+        print_table(&status, &RenderOptions { compact: false, ..RenderOptions::default() }, &mut plain).unwrap();
+        let plain = String::from_utf8(plain).unwrap();
+        assert!(plain.contains("[queue] (healthy)"));
+        assert!(plain.contains("[system] (unhealthy)"));
+        assert!(!plain.contains('\x1b'));
+
+        let mut colored = Vec::new();
+        print_table(
+            &status,
+            &RenderOptions { compact: false, color: true, ..RenderOptions::default() },
+            &mut colored,
+        )
+        .unwrap();
+        let colored = String::from_utf8(colored).unwrap();
+        assert!(colored.contains("\x1b[32mhealthy\x1b[0m"));
+        assert!(colored.contains("\x1b[31munhealthy\x1b[0m"));
+    }
+
+    #[test]
+    fn test_color_keeps_alignment() {
+        colored::control::set_override(true);
+        let items = vec![
+            json!({"uri": "viking://a", "size": 5, "note": null}),
+            json!({"uri": "viking://bb", "size": 100, "note": "x"}),
+        ];
+        let plain = format_array_to_table(&items, &RenderOptions { compact: false, ..RenderOptions::default() }).unwrap();
+        let colored = format_array_to_table(
+            &items,
+            &RenderOptions { compact: false, color: true, ..RenderOptions::default() },
+        )
+        .unwrap();
+        assert_ne!(plain, colored);
+        let strip = |s: &str| {
+            let mut out = String::new();
+            let mut chars = s.chars();
+            while let Some(c) = chars.next() {
+                if c == '\x1b' {
+                    for c in chars.by_ref() {
+                        if c == 'm' {
+                            break;
+                        }
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        };
+        assert_eq!(strip(&colored), plain);
+    }
+
+    #[test]
+    fn test_output_file_open_error_is_io() {
+        let dir = std::env::temp_dir().join(format!("ov-output-file-missing-{}", std::process::id()));
+        let err = ResultSink::file(&dir.join("out.json")).unwrap_err();
+        assert!(matches!(err, crate::error::Error::Io(e) if e.to_string().contains("out.json")));
+    }
+
+    #[test]
+    fn test_output_file_is_replaced_only_on_commit() {
+        let dir = std::env::temp_dir().join(format!("ov-output-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("out.txt");
+        std::fs::write(&path, "previous").unwrap();
+
+        let failed = output_for(OutputFormat::Table).with_sink(ResultSink::file(&path).unwrap());
+        output_line("partial", &failed);
+        failed.discard();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous");
+
+        let out = output_for(OutputFormat::Table).with_sink(ResultSink::file(&path).unwrap());
+        output_line("Removed: viking://a", &out);
+        output_content("body", &out).unwrap();
+        output_dry_run(&out);
+        out.finish().unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        let leftovers = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(written, "Removed: viking://a\nbody(dry-run, no results)\n");
+        assert_eq!(leftovers, 1);
+    }
+
+    #[test]
+    fn test_resolve_color() {
+        assert!(resolve_color(ColorChoice::Auto, true, None));
+        assert!(resolve_color(ColorChoice::Auto, true, Some("")));
+        assert!(!resolve_color(ColorChoice::Auto, true, Some("1")));
+        assert!(!resolve_color(ColorChoice::Auto, false, None));
+        assert!(resolve_color(ColorChoice::Always, false, Some("1")));
+        assert!(!resolve_color(ColorChoice::Never, true, None));
+    }
+
+    #[test]
+    fn test_escape_control_characters() {
+        let opts = RenderOptions {
+            escape_control: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            escape_control("evil\u{1b}[2Jname\0", &opts),
+            "evil\\x1b[2Jname\\x00"
+        );
+        assert_eq!(escape_control("csi\u{9b}31m", &opts), "csi\\x9b31m");
+        assert_eq!(escape_control("line1\n\tline2", &opts), "line1\n\tline2");
+    }
+
+    #[test]
+    fn test_escape_control_in_keys() {
+        let opts = RenderOptions {
+            escape_control: true,
+            ..Default::default()
+        };
+        assert_eq!(escape_key("a\nb\u{1b}", &opts), "a\\x0ab\\x1b");
+
+        let value = json!([{ "evil\u{1b}[2J": 1, "name": "x" }]);
+        let mut buf = Vec::new();
+        print_table(&value, &opts, &mut buf).unwrap();
+        let table = String::from_utf8(buf).unwrap();
+        assert!(table.contains("evil\\x1b[2J"));
+        assert!(!table.contains('\u{1b}'));
+
+        let value = json!({ "k\u{1b}]0;x\u{7}": "v" });
+        let mut buf = Vec::new();
+        print_table(&value, &opts, &mut buf).unwrap();
+        assert!(!String::from_utf8(buf).unwrap().contains('\u{1b}'));
+
+        let mut buf = Vec::new();
+        print_csv(&json!([{ "h\u{1b}": 1 }]), &opts, &mut buf).unwrap();
+        assert!(String::from_utf8(buf).unwrap().starts_with("h\\x1b\r\n"));
+    }
+
+    #[test]
+    fn test_raw_control_passthrough() {
+        let opts = RenderOptions::default();
+        assert_eq!(escape_control("a\u{1b}b", &opts), "a\u{1b}b");
+    }
+
+    #[test]
+    fn test_content_written_verbatim() {
+        let mut out = Vec::new();
+        write_content("line1\nline2\n", &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(out, b"line1\nline2\n");
+
+        let mut out = Vec::new();
+        write_content("no newline", &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(out, b"no newline");
+    }
+
+    #[test]
+    fn test_content_newline_only_when_missing() {
+        let opts = RenderOptions {
+            content_newline: true,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        write_content("text", &opts, &mut out).unwrap();
+        assert_eq!(out, b"text\n");
+
+        let mut out = Vec::new();
+        write_content("text\n", &opts, &mut out).unwrap();
+        assert_eq!(out, b"text\n");
+    }
+
+    #[test]
+    fn test_scalars_render_unquoted() {
+        let render = |value: Value, format: OutputFormat, opts: RenderOptions| {
+            let mut out = Vec::new();
+            render_value(&value, format, &opts, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let no_envelope = RenderOptions {
+            no_envelope: true,
+            ..Default::default()
+        };
+
+        assert_eq!(render(json!("viking://a"), OutputFormat::Table, RenderOptions::default()), "viking://a\n");
+        assert_eq!(render(json!(42), OutputFormat::Table, RenderOptions::default()), "42\n");
+        assert_eq!(render(json!("tok"), OutputFormat::Json, no_envelope.clone()), "tok\n");
+        assert_eq!(
+            render(json!("tok"), OutputFormat::Json, RenderOptions::default()),
+            "{\"ok\":true,\"result\":\"tok\"}\n"
+        );
+        assert_eq!(render(json!({"a": 1}), OutputFormat::Json, no_envelope), "{\"a\":1}\n");
+    }
+
+    #[test]
+    fn test_col_width_pins_columns() {
+        let mut opts = RenderOptions::default();
+        opts.col_widths.insert("uri".to_string(), 12);
+        opts.col_widths.insert("size".to_string(), 8);
+        let items = vec![
+            json!({"uri": "viking://resources/long/path.md", "size": 1, "name": "a"}),
+            json!({"uri": "viking://x", "size": 22, "name": "bb"}),
+        ];
+        let table = format_array_to_table(&items, &opts).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "uri           size      name");
+        assert_eq!(lines[1], "viking://...         1  a   ");
+        assert_eq!(lines[2], "viking://x          22  bb  ");
+    }
+
+    #[test]
+    fn test_parse_col_width() {
+        assert_eq!(parse_col_width("uri=60").unwrap(), ("uri".to_string(), 60));
+        assert!(parse_col_width("uri").is_err());
+        assert!(parse_col_width("uri=wide").is_err());
+        assert!(parse_col_width("uri=0").is_err());
+    }
+
+    #[test]
+    fn test_human_time_fields() {
+        let opts = RenderOptions {
+            human_time: true,
+            ..Default::default()
+        };
+        assert_eq!(format_field("latency_ms", &json!(850), &opts), "850ms");
+        assert_eq!(format_field("latency_ms", &json!(1200), &opts), "1.2s");
+        assert_eq!(format_field("duration", &json!(180), &opts), "3m");
+        assert_eq!(format_field("duration_secs", &json!(185), &opts), "3m 5s");
+        assert_eq!(format_field("duration", &json!(7800), &opts), "2h 10m");
+        // Seconds and milliseconds epochs render the same instant
+        let secs = format_field("created_at", &json!(1_700_000_000), &opts);
+        assert_eq!(secs, format_field("modTime", &json!(1_700_000_000_000_i64), &opts));
+        assert_eq!(secs.len(), "2023-11-14 22:13:20".len());
+        // Non-numeric values and other fields are untouched
+        assert_eq!(format_field("created_at", &json!("yesterday"), &opts), "yesterday");
+        assert_eq!(format_field("size", &json!(1200), &opts), "1200");
+        assert_eq!(format_field("latency_ms", &json!(850), &RenderOptions::default()), "850");
+    }
+
+    #[test]
+    fn test_progress_event_shape() {
+        assert_eq!(
+            progress_event("grep", 3, Some(10), "viking://a.md"),
+            json!({"type": "progress", "stage": "grep", "done": 3, "total": 10, "detail": "viking://a.md"})
+        );
+        assert_eq!(progress_event("wait", 1, None, "")["total"], Value::Null);
+    }
+
+    #[test]
+    fn test_escaped_cells_align() {
+        let opts = RenderOptions {
+            escape_control: true,
+            ..Default::default()
+        };
+        let items = vec![json!({"name": "a\u{7}b"}), json!({"name": "abcdef"})];
+        let table = format_array_to_table(&items, &opts).unwrap();
+        let widths: Vec<usize> = table.lines().map(display_width).collect();
+        assert_eq!(widths, vec![6, 6, 6]);
+    }
+
+    #[test]
+    fn test_nesting_depth() {
+        assert_eq!(nesting_depth(&json!("scalar")), 0);
+        assert_eq!(nesting_depth(&json!({"a": [1, 2]})), 2);
+        assert_eq!(nesting_depth(&json!([{"a": {"b": {}}}])), 3);
+    }
+
+    #[test]
+    fn test_deeply_nested_falls_back_to_json() {
+        let mut value = json!("leaf");
+        for _ in 0..100 {
+            value = json!({ "child": value });
+        }
+        let opts = RenderOptions {
+            max_depth: 10,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        print_table(&value, &opts, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().starts_with("{\"child\":"));
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file10"), Ordering::Equal);
+        assert_eq!(natural_cmp("v1.9", "v1.10"), Ordering::Less);
+        assert_eq!(natural_cmp("a", "b1"), Ordering::Less);
+        assert_eq!(natural_cmp("file02", "file2"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_sort_entries_natural_paths() {
+        let mut items = vec![
+            json!("viking://docs/file10.md"),
+            json!("viking://docs/file2.md"),
+            json!("viking://docs/file1.md"),
+        ];
+        sort_entries(&mut items, SortKey::Path, true);
+        assert_eq!(
+            items,
+            vec![
+                json!("viking://docs/file1.md"),
+                json!("viking://docs/file2.md"),
+                json!("viking://docs/file10.md"),
+            ]
+        );
+        sort_entries(&mut items, SortKey::Path, false);
+        assert_eq!(items[1], json!("viking://docs/file10.md"));
+    }
+
+    #[test]
+    fn test_sort_entries_by_size_missing_last() {
+        let mut items = vec![
+            json!({"uri": "viking://a", "size": 30}),
+            json!({"uri": "viking://b"}),
+            json!({"uri": "viking://c", "size": 5}),
+        ];
+        sort_entries(&mut items, SortKey::Size, false);
+        let uris: Vec<&str> = items.iter().map(|i| i["uri"].as_str().unwrap()).collect();
+        assert_eq!(uris, vec!["viking://c", "viking://a", "viking://b"]);
+    }
+
+    #[test]
+    fn test_sort_entries_by_name() {
+        let mut items = vec![
+            json!({"uri": "viking://z/alpha/"}),
+            json!({"uri": "viking://a/beta"}),
+        ];
+        sort_entries(&mut items, SortKey::Name, false);
+        assert_eq!(items[0]["uri"], "viking://z/alpha/");
+    }
+
+    #[test]
+    fn test_renderer_for_json() {
+        let value = json!({"uri": "viking://a"});
+        let mut out = Vec::new();
+        renderer_for(OutputFormat::Json)
+            .render(&value, &RenderOptions::default(), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"ok\":true,\"result\":{\"uri\":\"viking://a\"}}\n"
+        );
+    }
+
+    #[test]
+    fn test_jsonl_recursive_listing_one_entry_per_line() {
+        // `ls -r` returns a flat array, so nested entries still get a line each
+        let value = json!([
+            {"uri": "viking://r/docs/", "rel_path": "docs", "isDir": true},
+            {"uri": "viking://r/docs/a.md", "rel_path": "docs/a.md", "isDir": false},
+        ]);
+        let mut out = Vec::new();
+        renderer_for(OutputFormat::from("ndjson"))
+            .render(&value, &RenderOptions::default(), &mut out)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<Value> = text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines, value.as_array().unwrap().clone());
+    }
+
+    #[test]
+    fn test_renderer_for_jsonl_wrapped() {
+        let value = json!([{"uri": "viking://a"}, {"uri": "viking://b"}]);
+        let mut out = Vec::new();
+        renderer_for(OutputFormat::JsonlWrapped)
+            .render(&value, &RenderOptions::default(), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"ok\":true,\"result\":{\"uri\":\"viking://a\"}}\n{\"ok\":true,\"result\":{\"uri\":\"viking://b\"}}\n"
+        );
+
+        let mut out = Vec::new();
+        renderer_for(OutputFormat::Jsonl)
+            .render(&value, &RenderOptions::default(), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"uri\":\"viking://a\"}\n{\"uri\":\"viking://b\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_renderer_for_table_string() {
+        let mut out = Vec::new();
+        renderer_for(OutputFormat::Table)
+            .render(&json!("plain"), &RenderOptions::default(), &mut out)
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "plain\n");
+    }
+
+    #[test]
+    fn test_matching_status() {
+        let abort_on = vec!["degraded".to_string(), "failed".to_string()];
+        assert_eq!(
+            matching_status(&json!({"status": "Degraded"}), &abort_on),
+            Some("Degraded".to_string())
+        );
+        assert_eq!(
+            matching_status(&json!({"state": "failed"}), &abort_on),
+            Some("failed".to_string())
+        );
+        assert_eq!(matching_status(&json!({"status": "ok"}), &abort_on), None);
+        assert_eq!(matching_status(&json!(["degraded"]), &abort_on), None);
+
+        let out = output_for(OutputFormat::Json).with_abort_on(abort_on);
+        out.with_format(OutputFormat::Table).check_abort_on(&json!({"status": "failed"}));
+        out.check_abort_on(&json!({"status": "degraded"}));
+        assert_eq!(out.aborted_status(), Some("failed".to_string()));
+        let unset = output_for(OutputFormat::Json);
+        unset.check_abort_on(&json!({"status": "failed"}));
+        assert_eq!(unset.aborted_status(), None);
+    }
+
+    #[test]
+    fn test_csv_quotes_special_fields() {
+        let value = json!([
+            {"uri": "viking://a", "abstract": "one, two"},
+            {"uri": "viking://b", "abstract": "say \"hi\"\nbye"}
+        ]);
+        let mut out = Vec::new();
+        print_csv(&value, &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "uri,abstract\r\nviking://a,\"one, two\"\r\nviking://b,\"say \"\"hi\"\"\nbye\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_object_as_key_value() {
+        let mut out = Vec::new();
+        print_csv(&json!({"status": "ok", "count": 2}), &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "key,value\r\nstatus,ok\r\ncount,2\r\n");
+    }
+
+    #[test]
+    fn test_csv_empty_array() {
+        let mut out = Vec::new();
+        print_csv(&json!([]), &RenderOptions::default(), &mut out).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_csv_heterogeneous_keys() {
+        let value = json!([
+            {"uri": "viking://a", "size": 3, "note": null},
+            {"uri": "viking://b", "tags": ["x", "y"], "meta": {"k": 1}}
+        ]);
+        let opts = RenderOptions { compact: true, ..RenderOptions::default() };
+        let mut out = Vec::new();
+        print_csv(&value, &opts, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "uri,size,note,tags,meta\r\nviking://a,3,,,\r\nviking://b,,,\"[\"\"x\"\",\"\"y\"\"]\",\"{\"\"k\"\":1}\"\r\n"
+        );
+    }
+
+    /// Split a GFM table row into cells, honouring `\\|` escapes
+    fn markdown_row_cells(line: &str) -> Vec<String> {
+        let inner = line
+            .strip_prefix('|')
+            .and_then(|l| l.strip_suffix('|'))
+            .expect("table row must start and end with a pipe");
+        let mut cells = vec![String::new()];
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some(next) = chars.next() {
+                        cells.last_mut().unwrap().push(next);
+                    }
+                }
+                '|' => cells.push(String::new()),
+                _ => cells.last_mut().unwrap().push(c),
+            }
+        }
+        cells.iter().map(|c| c.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn test_markdown_table_roundtrip() {
+        let value = json!([
+            {"uri": "viking://a|b", "size": 3, "note": "two\nlines"},
+            {"uri": "viking://c", "size": null},
+        ]);
+        let mut out = Vec::new();
+        print_markdown(&value, &RenderOptions::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "|---|---|---|");
+        let rows: Vec<Vec<String>> = [0, 2, 3].iter().map(|&i| markdown_row_cells(lines[i])).collect();
+        assert!(rows.iter().all(|r| r.len() == 3));
+        assert_eq!(rows[0], vec!["uri", "size", "note"]);
+        assert_eq!(rows[1], vec!["viking://a|b", "3", "two<br>lines"]);
+        assert_eq!(rows[2], vec!["viking://c", "", ""]);
+    }
+
+    #[test]
+    fn test_markdown_object_as_key_value() {
+        let mut out = Vec::new();
+        print_markdown(&json!({"status": "ok", "count": 2}), &RenderOptions::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let rows: Vec<Vec<String>> = text
+            .lines()
+            .filter(|l| !l.starts_with("|---"))
+            .map(markdown_row_cells)
+            .collect();
+        assert_eq!(rows, vec![vec!["key", "value"], vec!["status", "ok"], vec!["count", "2"]]);
+    }
+
+    #[test]
+    fn test_markdown_string_fenced() {
+        let mut out = Vec::new();
+        print_markdown(&json!("use ```rust\n"), &RenderOptions::default(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "````\nuse ```rust\n````\n");
+    }
+
+    #[test]
+    fn test_human_size() {
+        assert_eq!(human_size(0), "0 B");
+        assert_eq!(human_size(1023), "1023 B");
+        assert_eq!(human_size(1536), "1.5 KB");
+        assert_eq!(human_size(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(human_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn test_csv_embedded_commas() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("plain"), "plain");
     }
 }
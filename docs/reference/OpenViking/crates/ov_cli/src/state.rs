@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// CLI state kept between invocations in `~/.openviking/state.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateFile {
+    /// Session used when `--session-id` is omitted, set by `session use`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_session_id: Option<String>,
+}
+
+impl StateFile {
+    /// Load state from its default location; a missing file is empty state
+    pub fn load() -> Result<Self> {
+        Self::load_from(&default_state_path()?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        serde_json::from_str(&content)
+            .map_err(|e| Error::Parse(format!("Failed to parse {}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        let path = default_state_path()?;
+        self.save_to(&path)?;
+        Ok(path)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// `explicit` if given, else the persisted current session
+pub fn resolve_session_id(explicit: Option<String>) -> Result<String> {
+    if let Some(id) = explicit {
+        return Ok(id);
+    }
+    StateFile::load()?.current_session_id.ok_or_else(|| {
+        Error::Client("No session ID given and no current session; run `ov session use <id>`".to_string())
+    })
+}
+
+pub fn default_state_path() -> Result<PathBuf> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+    Ok(home.join(".openviking").join("state.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ov-state-test-{}", std::process::id()));
+        let path = dir.join("state.json");
+        assert_eq!(StateFile::load_from(&path).unwrap(), StateFile::default());
+
+        let state = StateFile { current_session_id: Some("s-123".to_string()) };
+        state.save_to(&path).unwrap();
+        assert_eq!(StateFile::load_from(&path).unwrap(), state);
+
+        StateFile::default().save_to(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_explicit_session_id_wins() {
+        assert_eq!(resolve_session_id(Some("s-1".to_string())).unwrap(), "s-1");
+    }
+}
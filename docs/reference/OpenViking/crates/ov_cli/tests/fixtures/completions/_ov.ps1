@@ -0,0 +1,3342 @@
+
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName 'ov' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $commandElements = $commandAst.CommandElements
+    $command = @(
+        'ov'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value.StartsWith('-') -or
+                $element.Value -eq $wordToComplete) {
+                break
+        }
+        $element.Value
+    }) -join ';'
+
+    $completions = @(switch ($command) {
+        'ov' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Per-request HTTP timeout in seconds, e.g. 0.5 or 120; 0 for none (config: timeout_secs, default 60). Given before the command, as `wait`/`add-resource --timeout` is the server-side processing timeout')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('add-resource', 'add-resource', [CompletionResultType]::ParameterValue, 'Add resources into OpenViking')
+            [CompletionResult]::new('update-resource', 'update-resource', [CompletionResultType]::ParameterValue, 'Replace a resource''s content and/or update its reason and instruction (needs a server with PUT /api/v1/content and PATCH /api/v1/resources)')
+            [CompletionResult]::new('add-skill', 'add-skill', [CompletionResultType]::ParameterValue, 'Add a skill into OpenViking')
+            [CompletionResult]::new('relations', 'relations', [CompletionResultType]::ParameterValue, 'List relations of a resource')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Create relation links from one URI to one or more targets')
+            [CompletionResult]::new('unlink', 'unlink', [CompletionResultType]::ParameterValue, 'Remove a relation link')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export context as .ovpack')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import .ovpack into target URI')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Wait for queued async processing to complete')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show OpenViking component status')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Quick health check')
+            [CompletionResult]::new('system', 'system', [CompletionResultType]::ParameterValue, 'System utility commands')
+            [CompletionResult]::new('observer', 'observer', [CompletionResultType]::ParameterValue, 'Observer status commands')
+            [CompletionResult]::new('session', 'session', [CompletionResultType]::ParameterValue, 'Session management commands')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List directory contents')
+            [CompletionResult]::new('tree', 'tree', [CompletionResultType]::ParameterValue, 'Get directory tree')
+            [CompletionResult]::new('mkdir', 'mkdir', [CompletionResultType]::ParameterValue, 'Create directory')
+            [CompletionResult]::new('touch', 'touch', [CompletionResultType]::ParameterValue, 'Create an empty resource, e.g. as a placeholder for `add-resource --to`')
+            [CompletionResult]::new('rm', 'rm', [CompletionResultType]::ParameterValue, 'Remove resource')
+            [CompletionResult]::new('mv', 'mv', [CompletionResultType]::ParameterValue, 'Move or rename resource')
+            [CompletionResult]::new('cp', 'cp', [CompletionResultType]::ParameterValue, 'Copy a resource to a new URI')
+            [CompletionResult]::new('watch', 'watch', [CompletionResultType]::ParameterValue, 'Poll a URI''s metadata and show what changed, like watch(1)')
+            [CompletionResult]::new('du', 'du', [CompletionResultType]::ParameterValue, 'Report the aggregate size of a subtree')
+            [CompletionResult]::new('diff', 'diff', [CompletionResultType]::ParameterValue, 'Compare the content of two URIs')
+            [CompletionResult]::new('stat', 'stat', [CompletionResultType]::ParameterValue, 'Get resource metadata')
+            [CompletionResult]::new('read', 'read', [CompletionResultType]::ParameterValue, 'Read file content (L2)')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit file content in $EDITOR and write it back if changed')
+            [CompletionResult]::new('abstract', 'abstract', [CompletionResultType]::ParameterValue, 'Read abstract content (L0)')
+            [CompletionResult]::new('overview', 'overview', [CompletionResultType]::ParameterValue, 'Read overview content (L1)')
+            [CompletionResult]::new('find', 'find', [CompletionResultType]::ParameterValue, 'Run semantic retrieval')
+            [CompletionResult]::new('search', 'search', [CompletionResultType]::ParameterValue, 'Run context-aware retrieval')
+            [CompletionResult]::new('grep', 'grep', [CompletionResultType]::ParameterValue, 'Run content pattern search')
+            [CompletionResult]::new('glob', 'glob', [CompletionResultType]::ParameterValue, 'Run file glob pattern search')
+            [CompletionResult]::new('add-memory', 'add-memory', [CompletionResultType]::ParameterValue, 'Add memory in one shot (creates session, adds messages, commits)')
+            [CompletionResult]::new('selftest', 'selftest', [CompletionResultType]::ParameterValue, 'Run an end-to-end round-trip against a scratch URI and report each step')
+            [CompletionResult]::new('index', 'index', [CompletionResultType]::ParameterValue, 'Local index for offline ls/glob')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Configuration management')
+            [CompletionResult]::new('api', 'api', [CompletionResultType]::ParameterValue, 'Call any server endpoint directly, e.g. `api GET /api/v1/system/status`')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Show CLI version')
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Print a shell completion script to stdout')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;add-resource' {
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Target URI (only with a single path)')
+            [CompletionResult]::new('--reason', '--reason', [CompletionResultType]::ParameterName, 'Reason for import')
+            [CompletionResult]::new('--instruction', '--instruction', [CompletionResultType]::ParameterName, 'Additional instruction')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Wait timeout in seconds')
+            [CompletionResult]::new('--wait-timeout', '--wait-timeout', [CompletionResultType]::ParameterName, 'Deprecated: use --timeout')
+            [CompletionResult]::new('--poll-interval', '--poll-interval', [CompletionResultType]::ParameterName, 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'Content type of stdin input, as an extension (md) or MIME type (text/markdown)')
+            [CompletionResult]::new('--stdin-name', '--stdin-name', [CompletionResultType]::ParameterName, 'File name for stdin input, used as the resource''s display name')
+            [CompletionResult]::new('--concurrency', '--concurrency', [CompletionResultType]::ParameterName, 'Imports to run at once when several paths are given')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait until processing is complete')
+            [CompletionResult]::new('--upload', '--upload', [CompletionResultType]::ParameterName, 'Upload PATH''s bytes even if it looks like a URL, with no fallback to sending the path')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;update-resource' {
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Local file with the new content, or `-` to read it from stdin')
+            [CompletionResult]::new('--reason', '--reason', [CompletionResultType]::ParameterName, 'New reason for the resource')
+            [CompletionResult]::new('--instruction', '--instruction', [CompletionResultType]::ParameterName, 'New processing instruction')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;add-skill' {
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Wait timeout in seconds')
+            [CompletionResult]::new('--wait-timeout', '--wait-timeout', [CompletionResultType]::ParameterName, 'Deprecated: use --timeout')
+            [CompletionResult]::new('--poll-interval', '--poll-interval', [CompletionResultType]::ParameterName, 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait until processing is complete')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;relations' {
+            [CompletionResult]::new('--depth', '--depth', [CompletionResultType]::ParameterName, 'Follow relations up to N hops out, listing each edge with its `hop`')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;link' {
+            [CompletionResult]::new('--reason', '--reason', [CompletionResultType]::ParameterName, 'Reason for linking')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;unlink' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;export' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--relations-only', '--relations-only', [CompletionResultType]::ParameterName, 'Export only the relation graph under URI, without content, to a local file')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;import' {
+            [CompletionResult]::new('--into-new', '--into-new', [CompletionResultType]::ParameterName, 'Import into a new child directory of the target (named after the pack if omitted)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite when conflicts exist')
+            [CompletionResult]::new('--no-vectorize', '--no-vectorize', [CompletionResultType]::ParameterName, 'Disable vectorization after import')
+            [CompletionResult]::new('--relations-only', '--relations-only', [CompletionResultType]::ParameterName, 'Recreate links from a graph file made by `export --relations-only`, mapping its root onto the target')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;wait' {
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Wait timeout in seconds')
+            [CompletionResult]::new('--poll-interval', '--poll-interval', [CompletionResultType]::ParameterName, 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;status' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;health' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;system' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Wait for queued async processing to complete')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show component status')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Quick health check')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;system;wait' {
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Wait timeout in seconds')
+            [CompletionResult]::new('--poll-interval', '--poll-interval', [CompletionResultType]::ParameterName, 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;system;status' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;system;health' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;system;help' {
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Wait for queued async processing to complete')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show component status')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Quick health check')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;system;help;wait' {
+            break
+        }
+        'ov;system;help;status' {
+            break
+        }
+        'ov;system;help;health' {
+            break
+        }
+        'ov;system;help;help' {
+            break
+        }
+        'ov;observer' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('queue', 'queue', [CompletionResultType]::ParameterValue, 'Get queue status')
+            [CompletionResult]::new('vikingdb', 'vikingdb', [CompletionResultType]::ParameterValue, 'Get VikingDB status')
+            [CompletionResult]::new('vlm', 'vlm', [CompletionResultType]::ParameterValue, 'Get VLM status')
+            [CompletionResult]::new('system', 'system', [CompletionResultType]::ParameterValue, 'Get overall system status')
+            [CompletionResult]::new('poll', 'poll', [CompletionResultType]::ParameterValue, 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;observer;queue' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;observer;vikingdb' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;observer;vlm' {
+            [CompletionResult]::new('--image', '--image', [CompletionResultType]::ParameterName, 'Image to probe with instead of the bundled sample')
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--probe', '--probe', [CompletionResultType]::ParameterName, 'Send a sample image to the VLM and report the round-trip result and latency')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;observer;system' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between refreshes with --watch')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--watch', '--watch', [CompletionResultType]::ParameterName, 'Refresh every --interval seconds until Ctrl-C, like watch(1)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;observer;poll' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between polls')
+            [CompletionResult]::new('--count', '--count', [CompletionResultType]::ParameterName, 'Stop after this many polls; 0 runs until Ctrl-C')
+            [CompletionResult]::new('--threshold', '--threshold', [CompletionResultType]::ParameterName, 'Exit with an error as soon as the queue depth (pending + in progress) exceeds this')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;observer;help' {
+            [CompletionResult]::new('queue', 'queue', [CompletionResultType]::ParameterValue, 'Get queue status')
+            [CompletionResult]::new('vikingdb', 'vikingdb', [CompletionResultType]::ParameterValue, 'Get VikingDB status')
+            [CompletionResult]::new('vlm', 'vlm', [CompletionResultType]::ParameterValue, 'Get VLM status')
+            [CompletionResult]::new('system', 'system', [CompletionResultType]::ParameterValue, 'Get overall system status')
+            [CompletionResult]::new('poll', 'poll', [CompletionResultType]::ParameterValue, 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;observer;help;queue' {
+            break
+        }
+        'ov;observer;help;vikingdb' {
+            break
+        }
+        'ov;observer;help;vlm' {
+            break
+        }
+        'ov;observer;help;system' {
+            break
+        }
+        'ov;observer;help;poll' {
+            break
+        }
+        'ov;observer;help;help' {
+            break
+        }
+        'ov;session' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('new', 'new', [CompletionResultType]::ParameterValue, 'Create a new session')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List sessions')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Get session details')
+            [CompletionResult]::new('delete', 'delete', [CompletionResultType]::ParameterValue, 'Delete a session')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Write a session''s messages to a file or stdout')
+            [CompletionResult]::new('add-message', 'add-message', [CompletionResultType]::ParameterValue, 'Add one message to a session')
+            [CompletionResult]::new('commit', 'commit', [CompletionResultType]::ParameterValue, 'Commit a session (archive messages and extract memories)')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Remember a session as current, for commands run without a session ID')
+            [CompletionResult]::new('current', 'current', [CompletionResultType]::ParameterValue, 'Print the current session ID')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Forget the current session')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;session;new' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;list' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;get' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;delete' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;export' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'json writes one array; ndjson writes one message per line')
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Destination file; `-` or omitted writes to stdout')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite the destination file if it already exists')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;add-message' {
+            [CompletionResult]::new('--role', '--role', [CompletionResultType]::ParameterName, 'Message role, e.g. user/assistant')
+            [CompletionResult]::new('--content', '--content', [CompletionResultType]::ParameterName, 'Message content; `@path` reads it from a file and `-` from stdin')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;commit' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;use' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;current' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;clear' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;session;help' {
+            [CompletionResult]::new('new', 'new', [CompletionResultType]::ParameterValue, 'Create a new session')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List sessions')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Get session details')
+            [CompletionResult]::new('delete', 'delete', [CompletionResultType]::ParameterValue, 'Delete a session')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Write a session''s messages to a file or stdout')
+            [CompletionResult]::new('add-message', 'add-message', [CompletionResultType]::ParameterValue, 'Add one message to a session')
+            [CompletionResult]::new('commit', 'commit', [CompletionResultType]::ParameterValue, 'Commit a session (archive messages and extract memories)')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Remember a session as current, for commands run without a session ID')
+            [CompletionResult]::new('current', 'current', [CompletionResultType]::ParameterValue, 'Print the current session ID')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Forget the current session')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;session;help;new' {
+            break
+        }
+        'ov;session;help;list' {
+            break
+        }
+        'ov;session;help;get' {
+            break
+        }
+        'ov;session;help;delete' {
+            break
+        }
+        'ov;session;help;export' {
+            break
+        }
+        'ov;session;help;add-message' {
+            break
+        }
+        'ov;session;help;commit' {
+            break
+        }
+        'ov;session;help;use' {
+            break
+        }
+        'ov;session;help;current' {
+            break
+        }
+        'ov;session;help;clear' {
+            break
+        }
+        'ov;session;help;help' {
+            break
+        }
+        'ov;ls' {
+            [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'Abstract content limit (only for agent output)')
+            [CompletionResult]::new('--abs-limit', '--abs-limit', [CompletionResultType]::ParameterName, 'Abstract content limit (only for agent output)')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('--node-limit', '--node-limit', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('--limit', '--limit', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('--offset', '--offset', [CompletionResultType]::ParameterName, 'Skip this many entries client-side, showing the next --limit (prints "showing A-B of N")')
+            [CompletionResult]::new('--sort', '--sort', [CompletionResultType]::ParameterName, 'Sort entries client-side: name, path, size, mtime or any other field (numbers sort numerically; rows without it go last)')
+            [CompletionResult]::new('--page', '--page', [CompletionResultType]::ParameterName, 'Fetch only this page of results (1-based)')
+            [CompletionResult]::new('--page-size', '--page-size', [CompletionResultType]::ParameterName, 'Entries per page for --page and --all-pages')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Simple path output (just paths, no table)')
+            [CompletionResult]::new('--simple', '--simple', [CompletionResultType]::ParameterName, 'Simple path output (just paths, no table)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'List all subdirectories recursively')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'List all subdirectories recursively')
+            [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'Show all hidden files')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Show all hidden files')
+            [CompletionResult]::new('--reverse', '--reverse', [CompletionResultType]::ParameterName, 'Reverse the sort order (sorts by path unless --sort is given)')
+            [CompletionResult]::new('--natural', '--natural', [CompletionResultType]::ParameterName, 'Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)')
+            [CompletionResult]::new('--all-pages', '--all-pages', [CompletionResultType]::ParameterName, 'Fetch page after page until the listing is exhausted')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;tree' {
+            [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'Abstract content limit (only for agent output)')
+            [CompletionResult]::new('--abs-limit', '--abs-limit', [CompletionResultType]::ParameterName, 'Abstract content limit (only for agent output)')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('--node-limit', '--node-limit', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'Show all hidden files')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Show all hidden files')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;mkdir' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;touch' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;rm' {
+            [CompletionResult]::new('--glob', '--glob', [CompletionResultType]::ParameterName, 'Remove every resource under URI matching this glob pattern')
+            [CompletionResult]::new('--concurrency', '--concurrency', [CompletionResultType]::ParameterName, 'Parallel removals for --glob')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Remove recursively')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Remove recursively')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Skip the confirmation prompt for --glob')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Skip the confirmation prompt for --glob')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Skip the confirmation prompt for --recursive (required when stdout is not a terminal)')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Skip the confirmation prompt for --recursive (required when stdout is not a terminal)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;mv' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--no-overwrite', '--no-overwrite', [CompletionResultType]::ParameterName, 'Abort if the target already exists (checked with stat first; not atomic)')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Ask before overwriting an existing target (fails without a terminal)')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Ask before overwriting an existing target (fails without a terminal)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;cp' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Copy directories and everything under them')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Copy directories and everything under them')
+            [CompletionResult]::new('--no-vectorize', '--no-vectorize', [CompletionResultType]::ParameterName, 'Do not build vectors for the copy')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Overwrite the target if it already exists')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite the target if it already exists')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;watch' {
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between polls')
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Output format for each poll (defaults to --output)')
+            [CompletionResult]::new('--count', '--count', [CompletionResultType]::ParameterName, 'Stop after this many polls (default: until Ctrl-C)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;du' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Report every directory below URI, not just the total')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Report every directory below URI, not just the total')
+            [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'Include files as well as directories')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Include files as well as directories')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;diff' {
+            [CompletionResult]::new('-U', '-U ', [CompletionResultType]::ParameterName, 'Context lines around each change; 0 shows only changed lines')
+            [CompletionResult]::new('--unified', '--unified', [CompletionResultType]::ParameterName, 'Context lines around each change; 0 shows only changed lines')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;stat' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;read' {
+            [CompletionResult]::new('--rate-limit', '--rate-limit', [CompletionResultType]::ParameterName, 'Cap the content download speed in bytes/s (suffixes K, M, G)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;edit' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Edit even if the server sends no version (ETag), so a concurrent change may be overwritten')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;abstract' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;overview' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;find' {
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Target URI')
+            [CompletionResult]::new('--uri', '--uri', [CompletionResultType]::ParameterName, 'Target URI')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of results')
+            [CompletionResult]::new('--limit', '--limit', [CompletionResultType]::ParameterName, 'Maximum number of results')
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Score threshold')
+            [CompletionResult]::new('--threshold', '--threshold', [CompletionResultType]::ParameterName, 'Score threshold')
+            [CompletionResult]::new('--sort', '--sort', [CompletionResultType]::ParameterName, 'Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--with-relations', '--with-relations', [CompletionResultType]::ParameterName, 'Attach each hit''s relations (top 10 hits)')
+            [CompletionResult]::new('--desc', '--desc', [CompletionResultType]::ParameterName, 'Sort descending (with --sort)')
+            [CompletionResult]::new('--explain', '--explain', [CompletionResultType]::ParameterName, 'Show each hit''s relevance score as the first column; JSON output also keeps the server''s `debug` breakdown, which only servers that accept `explain` send')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;search' {
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Target URI')
+            [CompletionResult]::new('--uri', '--uri', [CompletionResultType]::ParameterName, 'Target URI')
+            [CompletionResult]::new('--session-id', '--session-id', [CompletionResultType]::ParameterName, 'Session ID for context-aware search (default: the current session from `session use`)')
+            [CompletionResult]::new('--context', '--context', [CompletionResultType]::ParameterName, 'Message to seed the --session-auto session with')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of results')
+            [CompletionResult]::new('--limit', '--limit', [CompletionResultType]::ParameterName, 'Maximum number of results')
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Score threshold')
+            [CompletionResult]::new('--threshold', '--threshold', [CompletionResultType]::ParameterName, 'Score threshold')
+            [CompletionResult]::new('--sort', '--sort', [CompletionResultType]::ParameterName, 'Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--session-auto', '--session-auto', [CompletionResultType]::ParameterName, 'Search in a throwaway session that is deleted afterwards')
+            [CompletionResult]::new('--with-relations', '--with-relations', [CompletionResultType]::ParameterName, 'Attach each hit''s relations (top 10 hits)')
+            [CompletionResult]::new('--desc', '--desc', [CompletionResultType]::ParameterName, 'Sort descending (with --sort)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;grep' {
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list when recursive')
+            [CompletionResult]::new('--node-limit', '--node-limit', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list when recursive')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Case insensitive')
+            [CompletionResult]::new('--ignore-case', '--ignore-case', [CompletionResultType]::ParameterName, 'Case insensitive')
+            [CompletionResult]::new('-F', '-F ', [CompletionResultType]::ParameterName, 'Match the pattern literally, escaping regex metacharacters (like grep -F)')
+            [CompletionResult]::new('--fixed-strings', '--fixed-strings', [CompletionResultType]::ParameterName, 'Match the pattern literally, escaping regex metacharacters (like grep -F)')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Grep every file under the URI, streaming matches as uri:line:text')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Grep every file under the URI, streaming matches as uri:line:text')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;glob' {
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Search root URI')
+            [CompletionResult]::new('--uri', '--uri', [CompletionResultType]::ParameterName, 'Search root URI')
+            [CompletionResult]::new('--sort', '--sort', [CompletionResultType]::ParameterName, 'Sort matches client-side (glob results only carry paths, so name and path apply)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--natural', '--natural', [CompletionResultType]::ParameterName, 'Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;add-memory' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;selftest' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;index' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('refresh', 'refresh', [CompletionResultType]::ParameterValue, 'Rebuild the local index from a recursive listing')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;index;refresh' {
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('--node-limit', '--node-limit', [CompletionResultType]::ParameterName, 'Maximum number of nodes to list')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;index;help' {
+            [CompletionResult]::new('refresh', 'refresh', [CompletionResultType]::ParameterValue, 'Rebuild the local index from a recursive listing')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;index;help;refresh' {
+            break
+        }
+        'ov;index;help;help' {
+            break
+        }
+        'ov;config' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the effective configuration, after layering and profile selection')
+            [CompletionResult]::new('validate', 'validate', [CompletionResultType]::ParameterValue, 'Validate configuration file')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Write a skeleton user config file')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set url, api_key, user or output in the user config file (or the --config file)')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Remove a setting from the user config file (or the --config file) so its default applies')
+            [CompletionResult]::new('profile', 'profile', [CompletionResultType]::ParameterValue, 'Manage named server profiles')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;config;show' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--sources', '--sources', [CompletionResultType]::ParameterName, 'List each setting with where it came from: a file, an env var, a profile or the default')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;validate' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;init' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'File format; also picks the extension (ovcli.conf, ovcli.toml or ovcli.yaml)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite an existing config file of the same format')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;set' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;unset' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;profile' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add or replace a profile in the user config file')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a profile from the user config file')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;config;profile;add' {
+            [CompletionResult]::new('--url', '--url', [CompletionResultType]::ParameterName, 'Server URL')
+            [CompletionResult]::new('--key', '--key', [CompletionResultType]::ParameterName, 'API key')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'User to send with every request')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;profile;remove' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;config;profile;help' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add or replace a profile in the user config file')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a profile from the user config file')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;config;profile;help;add' {
+            break
+        }
+        'ov;config;profile;help;remove' {
+            break
+        }
+        'ov;config;profile;help;help' {
+            break
+        }
+        'ov;config;help' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the effective configuration, after layering and profile selection')
+            [CompletionResult]::new('validate', 'validate', [CompletionResultType]::ParameterValue, 'Validate configuration file')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Write a skeleton user config file')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set url, api_key, user or output in the user config file (or the --config file)')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Remove a setting from the user config file (or the --config file) so its default applies')
+            [CompletionResult]::new('profile', 'profile', [CompletionResultType]::ParameterValue, 'Manage named server profiles')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;config;help;show' {
+            break
+        }
+        'ov;config;help;validate' {
+            break
+        }
+        'ov;config;help;init' {
+            break
+        }
+        'ov;config;help;set' {
+            break
+        }
+        'ov;config;help;unset' {
+            break
+        }
+        'ov;config;help;profile' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add or replace a profile in the user config file')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a profile from the user config file')
+            break
+        }
+        'ov;config;help;profile;add' {
+            break
+        }
+        'ov;config;help;profile;remove' {
+            break
+        }
+        'ov;config;help;help' {
+            break
+        }
+        'ov;api' {
+            [CompletionResult]::new('-b', '-b', [CompletionResultType]::ParameterName, 'JSON request body (POST defaults to `{}`; not allowed for GET)')
+            [CompletionResult]::new('--body', '--body', [CompletionResultType]::ParameterName, 'JSON request body (POST defaults to `{}`; not allowed for GET)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;version' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Also query the server and print its version ("unavailable" if unreachable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Also query the server and print its version ("unavailable" if unreachable)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;completions' {
+            [CompletionResult]::new('--shell', '--shell', [CompletionResultType]::ParameterName, 'Shell to generate completions for, as a flag')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Config file to use instead of the default layers (relative to the current directory)')
+            [CompletionResult]::new('--max-depth', '--max-depth', [CompletionResultType]::ParameterName, 'Maximum response nesting depth rendered as a table before falling back to raw JSON')
+            [CompletionResult]::new('--col-width', '--col-width', [CompletionResultType]::ParameterName, 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)')
+            [CompletionResult]::new('--timeout-secs', '--timeout-secs', [CompletionResultType]::ParameterName, 'Same as the top-level --timeout, but accepted anywhere on the command line')
+            [CompletionResult]::new('--connect-timeout', '--connect-timeout', [CompletionResultType]::ParameterName, 'Time allowed to connect to the server in seconds; 0 for none (default 10)')
+            [CompletionResult]::new('--read-timeout', '--read-timeout', [CompletionResultType]::ParameterName, 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--write-timeout', '--write-timeout', [CompletionResultType]::ParameterName, 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none')
+            [CompletionResult]::new('--retries', '--retries', [CompletionResultType]::ParameterName, 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)')
+            [CompletionResult]::new('--output-file', '--output-file', [CompletionResultType]::ParameterName, 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the server settings of a named profile from the config file (env: OV_PROFILE)')
+            [CompletionResult]::new('--columns', '--columns', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--fields', '--fields', [CompletionResultType]::ParameterName, 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never')
+            [CompletionResult]::new('--truncate', '--truncate', [CompletionResultType]::ParameterName, 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate')
+            [CompletionResult]::new('--abort-on', '--abort-on', [CompletionResultType]::ParameterName, 'Exit non-zero when a successful response has one of these status/state values (comma-separated)')
+            [CompletionResult]::new('--json-lines', '--json-lines', [CompletionResultType]::ParameterName, 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--compact', '--compact', [CompletionResultType]::ParameterName, 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output')
+            [CompletionResult]::new('--show-empty', '--show-empty', [CompletionResultType]::ParameterName, 'Show columns whose values are all empty (disables compact column filtering in tables)')
+            [CompletionResult]::new('--raw-control', '--raw-control', [CompletionResultType]::ParameterName, 'Print control characters verbatim instead of escaping them on a terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Serve ls/glob from the local index (see `index refresh`)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result')
+            [CompletionResult]::new('--bearer', '--bearer', [CompletionResultType]::ParameterName, 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`')
+            [CompletionResult]::new('--no-truncate', '--no-truncate', [CompletionResultType]::ParameterName, 'Show full cell values in tables instead of cutting them at 256 columns')
+            [CompletionResult]::new('--no-header', '--no-header', [CompletionResultType]::ParameterName, 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)')
+            [CompletionResult]::new('--human-time', '--human-time', [CompletionResultType]::ParameterName, 'Show epoch timestamps and durations in human form in tables (config: human_time)')
+            [CompletionResult]::new('--no-envelope', '--no-envelope', [CompletionResultType]::ParameterName, 'Print JSON results without the ok/result envelope (scalars print bare)')
+            [CompletionResult]::new('--no-newline', '--no-newline', [CompletionResultType]::ParameterName, 'Never append a newline to raw content, even on a terminal')
+            [CompletionResult]::new('--raw', '--raw', [CompletionResultType]::ParameterName, 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping')
+            [CompletionResult]::new('--events', '--events', [CompletionResultType]::ParameterName, 'Emit progress events and the final result as type-tagged NDJSON on stdout')
+            [CompletionResult]::new('--fail-on-warning', '--fail-on-warning', [CompletionResultType]::ParameterName, 'Exit non-zero if any warning was printed')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'ov;help' {
+            [CompletionResult]::new('add-resource', 'add-resource', [CompletionResultType]::ParameterValue, 'Add resources into OpenViking')
+            [CompletionResult]::new('update-resource', 'update-resource', [CompletionResultType]::ParameterValue, 'Replace a resource''s content and/or update its reason and instruction (needs a server with PUT /api/v1/content and PATCH /api/v1/resources)')
+            [CompletionResult]::new('add-skill', 'add-skill', [CompletionResultType]::ParameterValue, 'Add a skill into OpenViking')
+            [CompletionResult]::new('relations', 'relations', [CompletionResultType]::ParameterValue, 'List relations of a resource')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Create relation links from one URI to one or more targets')
+            [CompletionResult]::new('unlink', 'unlink', [CompletionResultType]::ParameterValue, 'Remove a relation link')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export context as .ovpack')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import .ovpack into target URI')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Wait for queued async processing to complete')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show OpenViking component status')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Quick health check')
+            [CompletionResult]::new('system', 'system', [CompletionResultType]::ParameterValue, 'System utility commands')
+            [CompletionResult]::new('observer', 'observer', [CompletionResultType]::ParameterValue, 'Observer status commands')
+            [CompletionResult]::new('session', 'session', [CompletionResultType]::ParameterValue, 'Session management commands')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List directory contents')
+            [CompletionResult]::new('tree', 'tree', [CompletionResultType]::ParameterValue, 'Get directory tree')
+            [CompletionResult]::new('mkdir', 'mkdir', [CompletionResultType]::ParameterValue, 'Create directory')
+            [CompletionResult]::new('touch', 'touch', [CompletionResultType]::ParameterValue, 'Create an empty resource, e.g. as a placeholder for `add-resource --to`')
+            [CompletionResult]::new('rm', 'rm', [CompletionResultType]::ParameterValue, 'Remove resource')
+            [CompletionResult]::new('mv', 'mv', [CompletionResultType]::ParameterValue, 'Move or rename resource')
+            [CompletionResult]::new('cp', 'cp', [CompletionResultType]::ParameterValue, 'Copy a resource to a new URI')
+            [CompletionResult]::new('watch', 'watch', [CompletionResultType]::ParameterValue, 'Poll a URI''s metadata and show what changed, like watch(1)')
+            [CompletionResult]::new('du', 'du', [CompletionResultType]::ParameterValue, 'Report the aggregate size of a subtree')
+            [CompletionResult]::new('diff', 'diff', [CompletionResultType]::ParameterValue, 'Compare the content of two URIs')
+            [CompletionResult]::new('stat', 'stat', [CompletionResultType]::ParameterValue, 'Get resource metadata')
+            [CompletionResult]::new('read', 'read', [CompletionResultType]::ParameterValue, 'Read file content (L2)')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit file content in $EDITOR and write it back if changed')
+            [CompletionResult]::new('abstract', 'abstract', [CompletionResultType]::ParameterValue, 'Read abstract content (L0)')
+            [CompletionResult]::new('overview', 'overview', [CompletionResultType]::ParameterValue, 'Read overview content (L1)')
+            [CompletionResult]::new('find', 'find', [CompletionResultType]::ParameterValue, 'Run semantic retrieval')
+            [CompletionResult]::new('search', 'search', [CompletionResultType]::ParameterValue, 'Run context-aware retrieval')
+            [CompletionResult]::new('grep', 'grep', [CompletionResultType]::ParameterValue, 'Run content pattern search')
+            [CompletionResult]::new('glob', 'glob', [CompletionResultType]::ParameterValue, 'Run file glob pattern search')
+            [CompletionResult]::new('add-memory', 'add-memory', [CompletionResultType]::ParameterValue, 'Add memory in one shot (creates session, adds messages, commits)')
+            [CompletionResult]::new('selftest', 'selftest', [CompletionResultType]::ParameterValue, 'Run an end-to-end round-trip against a scratch URI and report each step')
+            [CompletionResult]::new('index', 'index', [CompletionResultType]::ParameterValue, 'Local index for offline ls/glob')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Configuration management')
+            [CompletionResult]::new('api', 'api', [CompletionResultType]::ParameterValue, 'Call any server endpoint directly, e.g. `api GET /api/v1/system/status`')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Show CLI version')
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Print a shell completion script to stdout')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'ov;help;add-resource' {
+            break
+        }
+        'ov;help;update-resource' {
+            break
+        }
+        'ov;help;add-skill' {
+            break
+        }
+        'ov;help;relations' {
+            break
+        }
+        'ov;help;link' {
+            break
+        }
+        'ov;help;unlink' {
+            break
+        }
+        'ov;help;export' {
+            break
+        }
+        'ov;help;import' {
+            break
+        }
+        'ov;help;wait' {
+            break
+        }
+        'ov;help;status' {
+            break
+        }
+        'ov;help;health' {
+            break
+        }
+        'ov;help;system' {
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Wait for queued async processing to complete')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show component status')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Quick health check')
+            break
+        }
+        'ov;help;system;wait' {
+            break
+        }
+        'ov;help;system;status' {
+            break
+        }
+        'ov;help;system;health' {
+            break
+        }
+        'ov;help;observer' {
+            [CompletionResult]::new('queue', 'queue', [CompletionResultType]::ParameterValue, 'Get queue status')
+            [CompletionResult]::new('vikingdb', 'vikingdb', [CompletionResultType]::ParameterValue, 'Get VikingDB status')
+            [CompletionResult]::new('vlm', 'vlm', [CompletionResultType]::ParameterValue, 'Get VLM status')
+            [CompletionResult]::new('system', 'system', [CompletionResultType]::ParameterValue, 'Get overall system status')
+            [CompletionResult]::new('poll', 'poll', [CompletionResultType]::ParameterValue, 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)')
+            break
+        }
+        'ov;help;observer;queue' {
+            break
+        }
+        'ov;help;observer;vikingdb' {
+            break
+        }
+        'ov;help;observer;vlm' {
+            break
+        }
+        'ov;help;observer;system' {
+            break
+        }
+        'ov;help;observer;poll' {
+            break
+        }
+        'ov;help;session' {
+            [CompletionResult]::new('new', 'new', [CompletionResultType]::ParameterValue, 'Create a new session')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List sessions')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Get session details')
+            [CompletionResult]::new('delete', 'delete', [CompletionResultType]::ParameterValue, 'Delete a session')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Write a session''s messages to a file or stdout')
+            [CompletionResult]::new('add-message', 'add-message', [CompletionResultType]::ParameterValue, 'Add one message to a session')
+            [CompletionResult]::new('commit', 'commit', [CompletionResultType]::ParameterValue, 'Commit a session (archive messages and extract memories)')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Remember a session as current, for commands run without a session ID')
+            [CompletionResult]::new('current', 'current', [CompletionResultType]::ParameterValue, 'Print the current session ID')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Forget the current session')
+            break
+        }
+        'ov;help;session;new' {
+            break
+        }
+        'ov;help;session;list' {
+            break
+        }
+        'ov;help;session;get' {
+            break
+        }
+        'ov;help;session;delete' {
+            break
+        }
+        'ov;help;session;export' {
+            break
+        }
+        'ov;help;session;add-message' {
+            break
+        }
+        'ov;help;session;commit' {
+            break
+        }
+        'ov;help;session;use' {
+            break
+        }
+        'ov;help;session;current' {
+            break
+        }
+        'ov;help;session;clear' {
+            break
+        }
+        'ov;help;ls' {
+            break
+        }
+        'ov;help;tree' {
+            break
+        }
+        'ov;help;mkdir' {
+            break
+        }
+        'ov;help;touch' {
+            break
+        }
+        'ov;help;rm' {
+            break
+        }
+        'ov;help;mv' {
+            break
+        }
+        'ov;help;cp' {
+            break
+        }
+        'ov;help;watch' {
+            break
+        }
+        'ov;help;du' {
+            break
+        }
+        'ov;help;diff' {
+            break
+        }
+        'ov;help;stat' {
+            break
+        }
+        'ov;help;read' {
+            break
+        }
+        'ov;help;edit' {
+            break
+        }
+        'ov;help;abstract' {
+            break
+        }
+        'ov;help;overview' {
+            break
+        }
+        'ov;help;find' {
+            break
+        }
+        'ov;help;search' {
+            break
+        }
+        'ov;help;grep' {
+            break
+        }
+        'ov;help;glob' {
+            break
+        }
+        'ov;help;add-memory' {
+            break
+        }
+        'ov;help;selftest' {
+            break
+        }
+        'ov;help;index' {
+            [CompletionResult]::new('refresh', 'refresh', [CompletionResultType]::ParameterValue, 'Rebuild the local index from a recursive listing')
+            break
+        }
+        'ov;help;index;refresh' {
+            break
+        }
+        'ov;help;config' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the effective configuration, after layering and profile selection')
+            [CompletionResult]::new('validate', 'validate', [CompletionResultType]::ParameterValue, 'Validate configuration file')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Write a skeleton user config file')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set url, api_key, user or output in the user config file (or the --config file)')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Remove a setting from the user config file (or the --config file) so its default applies')
+            [CompletionResult]::new('profile', 'profile', [CompletionResultType]::ParameterValue, 'Manage named server profiles')
+            break
+        }
+        'ov;help;config;show' {
+            break
+        }
+        'ov;help;config;validate' {
+            break
+        }
+        'ov;help;config;init' {
+            break
+        }
+        'ov;help;config;set' {
+            break
+        }
+        'ov;help;config;unset' {
+            break
+        }
+        'ov;help;config;profile' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add or replace a profile in the user config file')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a profile from the user config file')
+            break
+        }
+        'ov;help;config;profile;add' {
+            break
+        }
+        'ov;help;config;profile;remove' {
+            break
+        }
+        'ov;help;api' {
+            break
+        }
+        'ov;help;version' {
+            break
+        }
+        'ov;help;completions' {
+            break
+        }
+        'ov;help;help' {
+            break
+        }
+    })
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
@@ -0,0 +1,2571 @@
+# Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
+function __fish_ov_global_optspecs
+    string join \n o/output= config= json-lines c/compact show-empty raw-control max-depth= offline col-width= timeout= timeout-secs= connect-timeout= read-timeout= write-timeout= retries= output-file= debug dry-run bearer profile= columns= color= no-truncate truncate= no-header human-time no-envelope no-newline raw events fail-on-warning abort-on= h/help V/version
+end
+
+function __fish_ov_needs_command
+    # Figure out if the current invocation already has a command.
+    set -l cmd (commandline -opc)
+    set -e cmd[1]
+    argparse -s (__fish_ov_global_optspecs) -- $cmd 2>/dev/null
+    or return
+    if set -q argv[1]
+        # Also print the command, so this can be used to figure out what it is.
+        echo $argv[1]
+        return 1
+    end
+    return 0
+end
+
+function __fish_ov_using_subcommand
+    set -l cmd (__fish_ov_needs_command)
+    test -z "$cmd"
+    and return 1
+    contains -- $cmd[1] $argv
+end
+
+complete -c ov -n "__fish_ov_needs_command" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_needs_command" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_needs_command" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_needs_command" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_needs_command" -l timeout -d 'Per-request HTTP timeout in seconds, e.g. 0.5 or 120; 0 for none (config: timeout_secs, default 60). Given before the command, as `wait`/`add-resource --timeout` is the server-side processing timeout' -r
+complete -c ov -n "__fish_ov_needs_command" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_needs_command" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_needs_command" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_needs_command" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_needs_command" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_needs_command" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_needs_command" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_needs_command" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_needs_command" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_needs_command" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_needs_command" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_needs_command" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_needs_command" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_needs_command" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_needs_command" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_needs_command" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_needs_command" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_needs_command" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_needs_command" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_needs_command" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_needs_command" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_needs_command" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_needs_command" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_needs_command" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_needs_command" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_needs_command" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_needs_command" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_needs_command" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_needs_command" -s V -l version -d 'Print version'
+complete -c ov -n "__fish_ov_needs_command" -f -a "add-resource" -d 'Add resources into OpenViking'
+complete -c ov -n "__fish_ov_needs_command" -f -a "update-resource" -d 'Replace a resource\'s content and/or update its reason and instruction (needs a server with PUT /api/v1/content and PATCH /api/v1/resources)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "add-skill" -d 'Add a skill into OpenViking'
+complete -c ov -n "__fish_ov_needs_command" -f -a "relations" -d 'List relations of a resource'
+complete -c ov -n "__fish_ov_needs_command" -f -a "link" -d 'Create relation links from one URI to one or more targets'
+complete -c ov -n "__fish_ov_needs_command" -f -a "unlink" -d 'Remove a relation link'
+complete -c ov -n "__fish_ov_needs_command" -f -a "export" -d 'Export context as .ovpack'
+complete -c ov -n "__fish_ov_needs_command" -f -a "import" -d 'Import .ovpack into target URI'
+complete -c ov -n "__fish_ov_needs_command" -f -a "wait" -d 'Wait for queued async processing to complete'
+complete -c ov -n "__fish_ov_needs_command" -f -a "status" -d 'Show OpenViking component status'
+complete -c ov -n "__fish_ov_needs_command" -f -a "health" -d 'Quick health check'
+complete -c ov -n "__fish_ov_needs_command" -f -a "system" -d 'System utility commands'
+complete -c ov -n "__fish_ov_needs_command" -f -a "observer" -d 'Observer status commands'
+complete -c ov -n "__fish_ov_needs_command" -f -a "session" -d 'Session management commands'
+complete -c ov -n "__fish_ov_needs_command" -f -a "ls" -d 'List directory contents'
+complete -c ov -n "__fish_ov_needs_command" -f -a "tree" -d 'Get directory tree'
+complete -c ov -n "__fish_ov_needs_command" -f -a "mkdir" -d 'Create directory'
+complete -c ov -n "__fish_ov_needs_command" -f -a "touch" -d 'Create an empty resource, e.g. as a placeholder for `add-resource --to`'
+complete -c ov -n "__fish_ov_needs_command" -f -a "rm" -d 'Remove resource'
+complete -c ov -n "__fish_ov_needs_command" -f -a "mv" -d 'Move or rename resource'
+complete -c ov -n "__fish_ov_needs_command" -f -a "cp" -d 'Copy a resource to a new URI'
+complete -c ov -n "__fish_ov_needs_command" -f -a "watch" -d 'Poll a URI\'s metadata and show what changed, like watch(1)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "du" -d 'Report the aggregate size of a subtree'
+complete -c ov -n "__fish_ov_needs_command" -f -a "diff" -d 'Compare the content of two URIs'
+complete -c ov -n "__fish_ov_needs_command" -f -a "stat" -d 'Get resource metadata'
+complete -c ov -n "__fish_ov_needs_command" -f -a "read" -d 'Read file content (L2)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "edit" -d 'Edit file content in $EDITOR and write it back if changed'
+complete -c ov -n "__fish_ov_needs_command" -f -a "abstract" -d 'Read abstract content (L0)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "overview" -d 'Read overview content (L1)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "find" -d 'Run semantic retrieval'
+complete -c ov -n "__fish_ov_needs_command" -f -a "search" -d 'Run context-aware retrieval'
+complete -c ov -n "__fish_ov_needs_command" -f -a "grep" -d 'Run content pattern search'
+complete -c ov -n "__fish_ov_needs_command" -f -a "glob" -d 'Run file glob pattern search'
+complete -c ov -n "__fish_ov_needs_command" -f -a "add-memory" -d 'Add memory in one shot (creates session, adds messages, commits)'
+complete -c ov -n "__fish_ov_needs_command" -f -a "selftest" -d 'Run an end-to-end round-trip against a scratch URI and report each step'
+complete -c ov -n "__fish_ov_needs_command" -f -a "index" -d 'Local index for offline ls/glob'
+complete -c ov -n "__fish_ov_needs_command" -f -a "config" -d 'Configuration management'
+complete -c ov -n "__fish_ov_needs_command" -f -a "api" -d 'Call any server endpoint directly, e.g. `api GET /api/v1/system/status`'
+complete -c ov -n "__fish_ov_needs_command" -f -a "version" -d 'Show CLI version'
+complete -c ov -n "__fish_ov_needs_command" -f -a "completions" -d 'Print a shell completion script to stdout'
+complete -c ov -n "__fish_ov_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l to -d 'Target URI (only with a single path)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l reason -d 'Reason for import' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l instruction -d 'Additional instruction' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l timeout -d 'Wait timeout in seconds' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l wait-timeout -d 'Deprecated: use --timeout' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l poll-interval -d 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l type -d 'Content type of stdin input, as an extension (md) or MIME type (text/markdown)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l stdin-name -d 'File name for stdin input, used as the resource\'s display name' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l concurrency -d 'Imports to run at once when several paths are given' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l wait -d 'Wait until processing is complete'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l upload -d 'Upload PATH\'s bytes even if it looks like a URL, with no fallback to sending the path'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand add-resource" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l file -d 'Local file with the new content, or `-` to read it from stdin' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l reason -d 'New reason for the resource' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l instruction -d 'New processing instruction' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand update-resource" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l timeout -d 'Wait timeout in seconds' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l wait-timeout -d 'Deprecated: use --timeout' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l poll-interval -d 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l wait -d 'Wait until processing is complete'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand add-skill" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l depth -d 'Follow relations up to N hops out, listing each edge with its `hop`' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand relations" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand relations" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand relations" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand relations" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand relations" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand relations" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand relations" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand link" -l reason -d 'Reason for linking' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand link" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand link" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand link" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand link" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand link" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand link" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand link" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand link" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand link" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand link" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand link" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand link" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand link" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand link" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand link" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand link" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand link" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand link" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand link" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand link" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand unlink" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand export" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand export" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand export" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand export" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand export" -l relations-only -d 'Export only the relation graph under URI, without content, to a local file'
+complete -c ov -n "__fish_ov_using_subcommand export" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand export" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand export" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand export" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand export" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand export" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand export" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand export" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand export" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand export" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand export" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand export" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand export" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand export" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand export" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand export" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand export" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand import" -l into-new -d 'Import into a new child directory of the target (named after the pack if omitted)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand import" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand import" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand import" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand import" -l force -d 'Overwrite when conflicts exist'
+complete -c ov -n "__fish_ov_using_subcommand import" -l no-vectorize -d 'Disable vectorization after import'
+complete -c ov -n "__fish_ov_using_subcommand import" -l relations-only -d 'Recreate links from a graph file made by `export --relations-only`, mapping its root onto the target'
+complete -c ov -n "__fish_ov_using_subcommand import" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand import" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand import" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand import" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand import" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand import" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand import" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand import" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand import" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand import" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand import" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand import" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand import" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand import" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand import" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand import" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand import" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l timeout -d 'Wait timeout in seconds' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l poll-interval -d 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand wait" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand wait" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand wait" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand wait" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand wait" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand wait" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand wait" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand status" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand status" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand status" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand status" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand status" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand status" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand status" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand status" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand status" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand status" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand status" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand status" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand status" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand status" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand status" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand status" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand health" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand health" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand health" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand health" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand health" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand health" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand health" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand health" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand health" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand health" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand health" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand health" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand health" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand health" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand health" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand health" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand health" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -f -a "wait" -d 'Wait for queued async processing to complete'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -f -a "status" -d 'Show component status'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -f -a "health" -d 'Quick health check'
+complete -c ov -n "__fish_ov_using_subcommand system; and not __fish_seen_subcommand_from wait status health help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l timeout -d 'Wait timeout in seconds' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l poll-interval -d 'Poll for completion every N seconds instead of one long server-side wait (min 0.5)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from wait" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from health" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from help" -f -a "wait" -d 'Wait for queued async processing to complete'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from help" -f -a "status" -d 'Show component status'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from help" -f -a "health" -d 'Quick health check'
+complete -c ov -n "__fish_ov_using_subcommand system; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "queue" -d 'Get queue status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "vikingdb" -d 'Get VikingDB status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "vlm" -d 'Get VLM status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "system" -d 'Get overall system status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "poll" -d 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and not __fish_seen_subcommand_from queue vikingdb vlm system poll help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from queue" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vikingdb" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l image -d 'Image to probe with instead of the bundled sample' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l probe -d 'Send a sample image to the VLM and report the round-trip result and latency'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from vlm" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l interval -d 'Seconds between refreshes with --watch' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l watch -d 'Refresh every --interval seconds until Ctrl-C, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from system" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l interval -d 'Seconds between polls' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l count -d 'Stop after this many polls; 0 runs until Ctrl-C' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l threshold -d 'Exit with an error as soon as the queue depth (pending + in progress) exceeds this' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from poll" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "queue" -d 'Get queue status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "vikingdb" -d 'Get VikingDB status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "vlm" -d 'Get VLM status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "system" -d 'Get overall system status'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "poll" -d 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)'
+complete -c ov -n "__fish_ov_using_subcommand observer; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "new" -d 'Create a new session'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "list" -d 'List sessions'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "get" -d 'Get session details'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "delete" -d 'Delete a session'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "export" -d 'Write a session\'s messages to a file or stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "add-message" -d 'Add one message to a session'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "commit" -d 'Commit a session (archive messages and extract memories)'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "use" -d 'Remember a session as current, for commands run without a session ID'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "current" -d 'Print the current session ID'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "clear" -d 'Forget the current session'
+complete -c ov -n "__fish_ov_using_subcommand session; and not __fish_seen_subcommand_from new list get delete export add-message commit use current clear help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from new" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from get" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from delete" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l format -d 'json writes one array; ndjson writes one message per line' -r -f -a "json\t'One JSON array of messages'
+ndjson\t'One message object per line'"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l to -d 'Destination file; `-` or omitted writes to stdout' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l force -d 'Overwrite the destination file if it already exists'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from export" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l role -d 'Message role, e.g. user/assistant' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l content -d 'Message content; `@path` reads it from a file and `-` from stdin' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from add-message" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from commit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from use" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from current" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from clear" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "new" -d 'Create a new session'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "list" -d 'List sessions'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "get" -d 'Get session details'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "delete" -d 'Delete a session'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "export" -d 'Write a session\'s messages to a file or stdout'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "add-message" -d 'Add one message to a session'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "commit" -d 'Commit a session (archive messages and extract memories)'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "use" -d 'Remember a session as current, for commands run without a session ID'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "current" -d 'Print the current session ID'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "clear" -d 'Forget the current session'
+complete -c ov -n "__fish_ov_using_subcommand session; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -s l -l abs-limit -d 'Abstract content limit (only for agent output)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -s n -l node-limit -l limit -d 'Maximum number of nodes to list' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l offset -d 'Skip this many entries client-side, showing the next --limit (prints "showing A-B of N")' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l sort -d 'Sort entries client-side: name, path, size, mtime or any other field (numbers sort numerically; rows without it go last)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l page -d 'Fetch only this page of results (1-based)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l page-size -d 'Entries per page for --page and --all-pages' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand ls" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand ls" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand ls" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand ls" -s s -l simple -d 'Simple path output (just paths, no table)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -s r -l recursive -d 'List all subdirectories recursively'
+complete -c ov -n "__fish_ov_using_subcommand ls" -s a -l all -d 'Show all hidden files'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l reverse -d 'Reverse the sort order (sorts by path unless --sort is given)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l natural -d 'Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l all-pages -d 'Fetch page after page until the listing is exhausted'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand ls" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand ls" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand ls" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand tree" -s l -l abs-limit -d 'Abstract content limit (only for agent output)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -s n -l node-limit -d 'Maximum number of nodes to list' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand tree" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand tree" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand tree" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand tree" -s a -l all -d 'Show all hidden files'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand tree" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand tree" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand tree" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand mkdir" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand touch" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand touch" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand touch" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand touch" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand touch" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand touch" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand touch" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand touch" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l glob -d 'Remove every resource under URI matching this glob pattern' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l concurrency -d 'Parallel removals for --glob' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand rm" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand rm" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand rm" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand rm" -s r -l recursive -d 'Remove recursively'
+complete -c ov -n "__fish_ov_using_subcommand rm" -s f -l force -d 'Skip the confirmation prompt for --glob'
+complete -c ov -n "__fish_ov_using_subcommand rm" -s y -l yes -d 'Skip the confirmation prompt for --recursive (required when stdout is not a terminal)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand rm" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand rm" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand rm" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand mv" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand mv" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand mv" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand mv" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand mv" -l no-overwrite -d 'Abort if the target already exists (checked with stat first; not atomic)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -s i -l interactive -d 'Ask before overwriting an existing target (fails without a terminal)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand mv" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand mv" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand mv" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand cp" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand cp" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand cp" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand cp" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand cp" -s r -l recursive -d 'Copy directories and everything under them'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l no-vectorize -d 'Do not build vectors for the copy'
+complete -c ov -n "__fish_ov_using_subcommand cp" -s f -l force -d 'Overwrite the target if it already exists'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand cp" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand cp" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand cp" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l interval -d 'Seconds between polls' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l format -d 'Output format for each poll (defaults to --output)' -r -f -a "table\t'Redraw the stat table each poll, like `watch(1)`'
+json\t'One `{poll, changed, result}` object per poll'"
+complete -c ov -n "__fish_ov_using_subcommand watch" -l count -d 'Stop after this many polls (default: until Ctrl-C)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand watch" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand watch" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand watch" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand watch" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand watch" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand watch" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand watch" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand du" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand du" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand du" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand du" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand du" -s r -l recursive -d 'Report every directory below URI, not just the total'
+complete -c ov -n "__fish_ov_using_subcommand du" -s a -l all -d 'Include files as well as directories'
+complete -c ov -n "__fish_ov_using_subcommand du" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand du" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand du" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand du" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand du" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand du" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand du" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand du" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand du" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand du" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand du" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand du" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand du" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand du" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand du" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand du" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand du" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand diff" -s U -l unified -d 'Context lines around each change; 0 shows only changed lines' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand diff" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand diff" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand diff" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand diff" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand diff" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand diff" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand diff" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand stat" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand stat" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand stat" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand stat" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand stat" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand stat" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand stat" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand stat" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand read" -l rate-limit -d 'Cap the content download speed in bytes/s (suffixes K, M, G)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand read" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand read" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand read" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand read" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand read" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand read" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand read" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand read" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand read" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand read" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand read" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand read" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand read" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand read" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand read" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand read" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand read" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand read" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand read" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand read" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand edit" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand edit" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand edit" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand edit" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand edit" -l force -d 'Edit even if the server sends no version (ETag), so a concurrent change may be overwritten'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand edit" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand edit" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand edit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand abstract" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand overview" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand overview" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand overview" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand overview" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand overview" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand overview" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand overview" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand overview" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand find" -s u -l uri -d 'Target URI' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -s n -l limit -d 'Maximum number of results' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -s t -l threshold -d 'Score threshold' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l sort -d 'Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand find" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand find" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand find" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand find" -l with-relations -d 'Attach each hit\'s relations (top 10 hits)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l desc -d 'Sort descending (with --sort)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l explain -d 'Show each hit\'s relevance score as the first column; JSON output also keeps the server\'s `debug` breakdown, which only servers that accept `explain` send'
+complete -c ov -n "__fish_ov_using_subcommand find" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand find" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand find" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand find" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand find" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand find" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand find" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand find" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand find" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand find" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand find" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand find" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand find" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand search" -s u -l uri -d 'Target URI' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l session-id -d 'Session ID for context-aware search (default: the current session from `session use`)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l context -d 'Message to seed the --session-auto session with' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -s n -l limit -d 'Maximum number of results' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -s t -l threshold -d 'Score threshold' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l sort -d 'Order hits client-side by this field, e.g. `score` (numbers compare numerically; rows without it go last)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand search" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand search" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand search" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand search" -l session-auto -d 'Search in a throwaway session that is deleted afterwards'
+complete -c ov -n "__fish_ov_using_subcommand search" -l with-relations -d 'Attach each hit\'s relations (top 10 hits)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l desc -d 'Sort descending (with --sort)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand search" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand search" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand search" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand search" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand search" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand search" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand search" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand search" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand search" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand search" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand search" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand search" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand grep" -s n -l node-limit -d 'Maximum number of nodes to list when recursive' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand grep" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand grep" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand grep" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand grep" -s i -l ignore-case -d 'Case insensitive'
+complete -c ov -n "__fish_ov_using_subcommand grep" -s F -l fixed-strings -d 'Match the pattern literally, escaping regex metacharacters (like grep -F)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -s r -l recursive -d 'Grep every file under the URI, streaming matches as uri:line:text'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand grep" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand grep" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand grep" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand glob" -s u -l uri -d 'Search root URI' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l sort -d 'Sort matches client-side (glob results only carry paths, so name and path apply)' -r -f -a "name\t'Last path segment'
+path\t'Full URI'
+size\t'Size in bytes'
+mtime\t'Modification time'"
+complete -c ov -n "__fish_ov_using_subcommand glob" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand glob" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand glob" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand glob" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand glob" -l natural -d 'Natural ordering, so file2 sorts before file10 (sorts by path unless --sort is given)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand glob" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand glob" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand glob" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand add-memory" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand selftest" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -f -a "refresh" -d 'Rebuild the local index from a recursive listing'
+complete -c ov -n "__fish_ov_using_subcommand index; and not __fish_seen_subcommand_from refresh help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -s n -l node-limit -d 'Maximum number of nodes to list' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from refresh" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from help" -f -a "refresh" -d 'Rebuild the local index from a recursive listing'
+complete -c ov -n "__fish_ov_using_subcommand index; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "show" -d 'Show the effective configuration, after layering and profile selection'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "validate" -d 'Validate configuration file'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "init" -d 'Write a skeleton user config file'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "set" -d 'Set url, api_key, user or output in the user config file (or the --config file)'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "unset" -d 'Remove a setting from the user config file (or the --config file) so its default applies'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "profile" -d 'Manage named server profiles'
+complete -c ov -n "__fish_ov_using_subcommand config; and not __fish_seen_subcommand_from show validate init set unset profile help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l sources -d 'List each setting with where it came from: a file, an env var, a profile or the default'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from validate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l format -d 'File format; also picks the extension (ovcli.conf, ovcli.toml or ovcli.yaml)' -r -f -a "json\t''
+toml\t''
+yaml\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l force -d 'Overwrite an existing config file of the same format'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from set" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from unset" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -f -a "add" -d 'Add or replace a profile in the user config file'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -f -a "remove" -d 'Remove a profile from the user config file'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from profile" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show the effective configuration, after layering and profile selection'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "validate" -d 'Validate configuration file'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "init" -d 'Write a skeleton user config file'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "set" -d 'Set url, api_key, user or output in the user config file (or the --config file)'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "unset" -d 'Remove a setting from the user config file (or the --config file) so its default applies'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "profile" -d 'Manage named server profiles'
+complete -c ov -n "__fish_ov_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand api" -s b -l body -d 'JSON request body (POST defaults to `{}`; not allowed for GET)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand api" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand api" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand api" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand api" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand api" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand api" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand api" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand api" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand api" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand api" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand api" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand api" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand api" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand api" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand api" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand api" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand api" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand api" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand api" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand api" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand version" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand version" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand version" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand version" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand version" -s v -l verbose -d 'Also query the server and print its version ("unavailable" if unreachable)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand version" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand version" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand version" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand version" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand version" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand version" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand version" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand version" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand version" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand version" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand version" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand version" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l shell -d 'Shell to generate completions for, as a flag' -r -f -a "bash\t''
+elvish\t''
+fish\t''
+powershell\t''
+zsh\t''"
+complete -c ov -n "__fish_ov_using_subcommand completions" -s o -l output -d 'Output format: table, json, jsonl (alias ndjson), jsonl-wrapped, csv, markdown (default: $OPENVIKING_OUTPUT, else config output, else table)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l config -d 'Config file to use instead of the default layers (relative to the current directory)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand completions" -l max-depth -d 'Maximum response nesting depth rendered as a table before falling back to raw JSON' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l col-width -d 'Fixed table width per column, e.g. `uri=60,abstract=40` (repeatable)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l timeout-secs -d 'Same as the top-level --timeout, but accepted anywhere on the command line' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l connect-timeout -d 'Time allowed to connect to the server in seconds; 0 for none (default 10)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l read-timeout -d 'Timeout for GET and DELETE requests in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l write-timeout -d 'Timeout for POST, PUT and PATCH requests (e.g. uploads) in seconds, overriding --timeout; 0 for none' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l retries -d 'Retries for failed requests (connection errors, timeouts, HTTP 429/5xx; POSTs only when the connection failed)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l output-file -d 'Write results to this file instead of stdout; it is only replaced if the command succeeds (warnings and errors stay on stderr)' -r -F
+complete -c ov -n "__fish_ov_using_subcommand completions" -l profile -d 'Use the server settings of a named profile from the config file (env: OV_PROFILE)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l columns -l fields -d 'Only show these columns/fields, in this order, e.g. `uri,size` (JSON rows are filtered too, and accept dotted paths like `meta.size`)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l color -d 'Colorize tables: auto (terminal only, honours NO_COLOR), always or never' -r -f -a "auto\t'Color when stdout is a terminal and `NO_COLOR` is unset'
+always\t''
+never\t''"
+complete -c ov -n "__fish_ov_using_subcommand completions" -l truncate -d 'Cut table cells at N columns instead of 256; 0 never cuts, like --no-truncate' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l abort-on -d 'Exit non-zero when a successful response has one of these status/state values (comma-separated)' -r
+complete -c ov -n "__fish_ov_using_subcommand completions" -l json-lines -d 'Shorthand for --output jsonl: one compact JSON value per line, for piping into jq'
+complete -c ov -n "__fish_ov_using_subcommand completions" -s c -l compact -d 'Compact representation, defaults to true - compacts JSON output or uses simplified representation for Table output'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l show-empty -d 'Show columns whose values are all empty (disables compact column filtering in tables)'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l raw-control -d 'Print control characters verbatim instead of escaping them on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l offline -d 'Serve ls/glob from the local index (see `index refresh`)'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l debug -d 'Log each HTTP request and response (method, URL, bodies; secrets redacted) to stderr'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l dry-run -d 'Print each HTTP request (API key redacted) to stderr instead of sending it; rm, mv and unlink instead print the request they would send as their result'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l bearer -d 'Send the API key as `Authorization: Bearer <key>` instead of `X-API-Key`'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l no-truncate -d 'Show full cell values in tables instead of cutting them at 256 columns'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l no-header -d 'Omit the header row of table output, for awk/cut pipelines (CSV keeps its header)'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l human-time -d 'Show epoch timestamps and durations in human form in tables (config: human_time)'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l no-envelope -d 'Print JSON results without the ok/result envelope (scalars print bare)'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l no-newline -d 'Never append a newline to raw content, even on a terminal'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l raw -d 'Write read/abstract/overview content byte-for-byte: no added newline, no escaping'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l events -d 'Emit progress events and the final result as type-tagged NDJSON on stdout'
+complete -c ov -n "__fish_ov_using_subcommand completions" -l fail-on-warning -d 'Exit non-zero if any warning was printed'
+complete -c ov -n "__fish_ov_using_subcommand completions" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "add-resource" -d 'Add resources into OpenViking'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "update-resource" -d 'Replace a resource\'s content and/or update its reason and instruction (needs a server with PUT /api/v1/content and PATCH /api/v1/resources)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "add-skill" -d 'Add a skill into OpenViking'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "relations" -d 'List relations of a resource'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "link" -d 'Create relation links from one URI to one or more targets'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "unlink" -d 'Remove a relation link'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "export" -d 'Export context as .ovpack'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "import" -d 'Import .ovpack into target URI'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "wait" -d 'Wait for queued async processing to complete'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "status" -d 'Show OpenViking component status'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "health" -d 'Quick health check'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "system" -d 'System utility commands'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "observer" -d 'Observer status commands'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "session" -d 'Session management commands'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "ls" -d 'List directory contents'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "tree" -d 'Get directory tree'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "mkdir" -d 'Create directory'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "touch" -d 'Create an empty resource, e.g. as a placeholder for `add-resource --to`'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "rm" -d 'Remove resource'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "mv" -d 'Move or rename resource'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "cp" -d 'Copy a resource to a new URI'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "watch" -d 'Poll a URI\'s metadata and show what changed, like watch(1)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "du" -d 'Report the aggregate size of a subtree'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "diff" -d 'Compare the content of two URIs'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "stat" -d 'Get resource metadata'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "read" -d 'Read file content (L2)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "edit" -d 'Edit file content in $EDITOR and write it back if changed'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "abstract" -d 'Read abstract content (L0)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "overview" -d 'Read overview content (L1)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "find" -d 'Run semantic retrieval'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "search" -d 'Run context-aware retrieval'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "grep" -d 'Run content pattern search'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "glob" -d 'Run file glob pattern search'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "add-memory" -d 'Add memory in one shot (creates session, adds messages, commits)'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "selftest" -d 'Run an end-to-end round-trip against a scratch URI and report each step'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "index" -d 'Local index for offline ls/glob'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "config" -d 'Configuration management'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "api" -d 'Call any server endpoint directly, e.g. `api GET /api/v1/system/status`'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "version" -d 'Show CLI version'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "completions" -d 'Print a shell completion script to stdout'
+complete -c ov -n "__fish_ov_using_subcommand help; and not __fish_seen_subcommand_from add-resource update-resource add-skill relations link unlink export import wait status health system observer session ls tree mkdir touch rm mv cp watch du diff stat read edit abstract overview find search grep glob add-memory selftest index config api version completions help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from system" -f -a "wait" -d 'Wait for queued async processing to complete'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from system" -f -a "status" -d 'Show component status'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from system" -f -a "health" -d 'Quick health check'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from observer" -f -a "queue" -d 'Get queue status'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from observer" -f -a "vikingdb" -d 'Get VikingDB status'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from observer" -f -a "vlm" -d 'Get VLM status'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from observer" -f -a "system" -d 'Get overall system status'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from observer" -f -a "poll" -d 'Print queue depth every interval, rewriting the line in table mode (NDJSON with -o json)'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "new" -d 'Create a new session'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "list" -d 'List sessions'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "get" -d 'Get session details'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "delete" -d 'Delete a session'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "export" -d 'Write a session\'s messages to a file or stdout'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "add-message" -d 'Add one message to a session'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "commit" -d 'Commit a session (archive messages and extract memories)'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "use" -d 'Remember a session as current, for commands run without a session ID'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "current" -d 'Print the current session ID'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from session" -f -a "clear" -d 'Forget the current session'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from index" -f -a "refresh" -d 'Rebuild the local index from a recursive listing'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "show" -d 'Show the effective configuration, after layering and profile selection'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "validate" -d 'Validate configuration file'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "init" -d 'Write a skeleton user config file'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "set" -d 'Set url, api_key, user or output in the user config file (or the --config file)'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "unset" -d 'Remove a setting from the user config file (or the --config file) so its default applies'
+complete -c ov -n "__fish_ov_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "profile" -d 'Manage named server profiles'